@@ -0,0 +1,54 @@
+//! Compares Eytzinger-layout search against binary search on a sorted array.
+//!
+//! The largest sizes exceed a typical L2 cache, where the layout's locality
+//! starts to pay off. Run with `cargo bench --bench eytzinger_search`.
+
+use arrays::algorithms::BinarySearchable;
+use arrays::dynamic_array::DynamicArray;
+use arrays::eytzinger::EytzingerArray;
+use std::hint::black_box;
+use std::time::Instant;
+
+// 64 KiB, 1 MiB, 16 MiB and 64 MiB of u32 keys
+const SIZES: [usize; 4] = [1 << 14, 1 << 18, 1 << 22, 1 << 24];
+const QUERIES: usize = 1 << 20;
+
+// xorshift64 keeps the query sequence reproducible without extra crates
+fn queries(max: u32) -> Vec<u32> {
+    let mut state = 0x9E37_79B9_7F4A_7C15u64;
+    (0..QUERIES)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state % u64::from(max)) as u32
+        })
+        .collect()
+}
+
+fn main() {
+    println!("{:>10} {:>14} {:>14}", "size", "binary", "eytzinger");
+
+    for size in SIZES {
+        let mut sorted = DynamicArray::with_capacity(size);
+        for i in 0..size as u32 {
+            sorted.push(i * 2);
+        }
+        let tree = EytzingerArray::from_sorted(&sorted);
+        let targets = queries(2 * size as u32);
+
+        let start = Instant::now();
+        for target in &targets {
+            black_box(sorted.binary_search(black_box(target)));
+        }
+        let binary = start.elapsed() / QUERIES as u32;
+
+        let start = Instant::now();
+        for target in &targets {
+            black_box(tree.search(black_box(target)));
+        }
+        let eytzinger = start.elapsed() / QUERIES as u32;
+
+        println!("{size:>10} {binary:>14.2?} {eytzinger:>14.2?}");
+    }
+}
@@ -0,0 +1,49 @@
+//! Compares chunked linear search against the element-by-element loop.
+//!
+//! Run with `cargo bench --bench linear_search_fast`.
+
+use arrays::algorithms::{ChunkedElement, FastLinearSearchable, LinearSearchable};
+use arrays::dynamic_array::DynamicArray;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const SIZES: [usize; 4] = [100, 10_000, 1_000_000, 10_000_000];
+
+fn time_per_search<F: FnMut() -> Option<usize>>(mut search: F) -> Duration {
+    let budget = Duration::from_millis(200);
+    let start = Instant::now();
+    let mut runs = 0u32;
+
+    while start.elapsed() < budget {
+        black_box(search());
+        runs += 1;
+    }
+
+    start.elapsed() / runs
+}
+
+// Fills the array with `filler` and searches for `missing`, forcing a full scan
+fn bench_type<T: ChunkedElement>(name: &str, filler: T, missing: T) {
+    for size in SIZES {
+        let mut arr = DynamicArray::with_capacity(size);
+        for _ in 0..size {
+            arr.push(filler);
+        }
+
+        let plain = time_per_search(|| black_box(&arr).linear_search(black_box(&missing)));
+        let fast = time_per_search(|| black_box(&arr).linear_search_fast(black_box(&missing)));
+
+        println!("{name:>6} {size:>10} {plain:>14.2?} {fast:>14.2?}");
+    }
+}
+
+fn main() {
+    println!(
+        "{:>6} {:>10} {:>14} {:>14}",
+        "type", "size", "linear", "fast"
+    );
+
+    bench_type::<u8>("u8", 1, 2);
+    bench_type::<i32>("i32", 1, 2);
+    bench_type::<u64>("u64", 1, 2);
+}
@@ -0,0 +1,40 @@
+//! Compares sentinel linear search against the bounds-checked loop.
+//!
+//! Run with `cargo bench --bench sentinel_search`.
+
+use arrays::algorithms::{LinearSearchable, SentinelSearchable};
+use arrays::dynamic_array::DynamicArray;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const SIZES: [usize; 4] = [1_000, 10_000, 100_000, 1_000_000];
+
+fn time_per_search<F: FnMut() -> Option<usize>>(mut search: F) -> Duration {
+    let budget = Duration::from_millis(200);
+    let start = Instant::now();
+    let mut runs = 0u32;
+
+    while start.elapsed() < budget {
+        black_box(search());
+        runs += 1;
+    }
+
+    start.elapsed() / runs
+}
+
+fn main() {
+    println!("{:>10} {:>14} {:>14}", "size", "linear", "sentinel");
+
+    for size in SIZES {
+        let mut arr = DynamicArray::with_capacity(size + 1);
+        arr.extend(0..size as u64);
+
+        // Searching for a missing value forces a full scan
+        let missing = size as u64;
+
+        let linear = time_per_search(|| black_box(&arr).linear_search(black_box(&missing)));
+        let sentinel = time_per_search(|| black_box(&mut arr).sentinel_search(black_box(&missing)));
+
+        println!("{size:>10} {linear:>14.2?} {sentinel:>14.2?}");
+    }
+}
@@ -1,15 +1,51 @@
 //! Binary search implementations for arrays.
 //!
-//! This module provides binary search functionality for both fixed-size
-//! arrays (Array<T>) and dynamic arrays (DynamicArray<T>).
+//! This module provides binary search functionality for any `Sequence<T>`,
+//! including fixed-size arrays (Array<T>), dynamic arrays (DynamicArray<T>),
+//! slices, `Vec<T>` and `VecDeque<T>`.
 //!
 //! For theory and visual explanations, see:
 //! - Binary Search Theory: `algorithms/searching/binary-search/`
 //! - Array-Specific Details: `structures/01-arrays/algorithms/binary-search.md`
 
-use crate::core::Array;
-use crate::dynamic_array::DynamicArray;
+use super::precondition::{debug_assert_sorted_by, enforce, PreconditionError, PreconditionMode};
+use super::search_trace::SearchTrace;
+use crate::sequence::Sequence;
 use std::cmp::Ordering;
+use std::fmt;
+use std::marker::PhantomData;
+
+/// Array length at or below which the hybrid strategy switches to a linear scan.
+pub const DEFAULT_LINEAR_THRESHOLD: usize = 16;
+
+/// Loop strategies for binary search.
+///
+/// All strategies agree on whether the target is present. `Branchless` and
+/// `Hybrid` always return the leftmost match, exactly like
+/// `binary_search_first`; `Classic` may return any matching index when the
+/// array contains duplicates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BinarySearchStrategy {
+    /// The three-way comparison loop used by `binary_search`.
+    #[default]
+    Classic,
+    /// Halving without data-dependent branches, see `binary_search_branchless`.
+    Branchless,
+    /// Branchless halving down to `threshold` candidates, then a linear scan.
+    Hybrid {
+        /// Window size at which to switch to the linear scan.
+        threshold: usize,
+    },
+}
+
+impl BinarySearchStrategy {
+    /// Hybrid strategy with the default linear-scan threshold.
+    pub fn hybrid() -> Self {
+        Self::Hybrid {
+            threshold: DEFAULT_LINEAR_THRESHOLD,
+        }
+    }
+}
 
 /// Trait for types that support binary search operations.
 pub trait BinarySearchable<T> {
@@ -21,6 +57,11 @@ pub trait BinarySearchable<T> {
     /// - The array must be sorted in ascending order
     /// - Elements must implement `Ord` for comparison
     ///
+    /// # Panics
+    /// Panics in debug builds if the array is not sorted, following
+    /// `PreconditionMode::DebugAssert`. The other `Ord`-based searches in
+    /// this trait check the same way.
+    ///
     /// # Examples
     /// ```
     /// use arrays::core::Array;
@@ -47,6 +88,31 @@ pub trait BinarySearchable<T> {
     where
         F: FnMut(&T) -> Ordering;
 
+    /// Like `binary_search`, but applies `mode` to the sortedness check
+    /// instead of the default debug assertion.
+    ///
+    /// # Errors
+    /// Returns `NotSorted` in `AlwaysCheck` mode if the array is unsorted.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::core::Array;
+    /// use arrays::algorithms::{BinarySearchable, PreconditionMode};
+    ///
+    /// let arr = Array::from_slice(&[1, 3, 5, 7, 9], 10).unwrap();
+    /// assert_eq!(arr.binary_search_checked(&7, PreconditionMode::AlwaysCheck), Ok(Some(3)));
+    ///
+    /// let unsorted = Array::from_slice(&[9, 1, 5], 10).unwrap();
+    /// assert!(unsorted.binary_search_checked(&5, PreconditionMode::AlwaysCheck).is_err());
+    /// ```
+    fn binary_search_checked(
+        &self,
+        target: &T,
+        mode: PreconditionMode,
+    ) -> Result<Option<usize>, PreconditionError>
+    where
+        T: Ord;
+
     /// Finds the leftmost (first) occurrence of the target.
     ///
     /// Useful when the array contains duplicates.
@@ -61,6 +127,26 @@ pub trait BinarySearchable<T> {
     where
         T: Ord;
 
+    /// Like `binary_search_first`, but applies `mode` to the sortedness
+    /// check, see `binary_search_checked`.
+    fn binary_search_first_checked(
+        &self,
+        target: &T,
+        mode: PreconditionMode,
+    ) -> Result<Option<usize>, PreconditionError>
+    where
+        T: Ord;
+
+    /// Like `binary_search_last`, but applies `mode` to the sortedness
+    /// check, see `binary_search_checked`.
+    fn binary_search_last_checked(
+        &self,
+        target: &T,
+        mode: PreconditionMode,
+    ) -> Result<Option<usize>, PreconditionError>
+    where
+        T: Ord;
+
     /// Finds the insertion point for the target to maintain sorted order.
     ///
     /// Returns the index where the target should be inserted.
@@ -68,206 +154,711 @@ pub trait BinarySearchable<T> {
     fn binary_search_insertion_point(&self, target: &T) -> usize
     where
         T: Ord;
-}
 
-// Helper macro to implement binary search for both array types
-macro_rules! impl_binary_search {
-    ($type:ty) => {
-        impl<T> BinarySearchable<T> for $type {
-            fn binary_search(&self, target: &T) -> Option<usize>
-            where
-                T: Ord,
-            {
-                if self.is_empty() {
-                    return None;
-                }
+    /// Like `binary_search_insertion_point`, but orders elements with `f`
+    /// the way `binary_search_by` does.
+    ///
+    /// Returns the first index whose element `f` does not report as
+    /// `Ordering::Less`. Reporting equal elements as `Less` as well gives
+    /// the slot after the last of them instead of before the first.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::core::Array;
+    /// use arrays::algorithms::BinarySearchable;
+    /// use std::cmp::Ordering;
+    ///
+    /// let arr = Array::from_slice(&[1, 2, 2, 2, 5], 10).unwrap();
+    /// assert_eq!(arr.binary_search_insertion_point_by(|x| x.cmp(&2)), 1);
+    ///
+    /// let after = arr.binary_search_insertion_point_by(|x| {
+    ///     if *x > 2 { Ordering::Greater } else { Ordering::Less }
+    /// });
+    /// assert_eq!(after, 4);
+    /// ```
+    fn binary_search_insertion_point_by<F>(&self, f: F) -> usize
+    where
+        F: FnMut(&T) -> Ordering;
 
-                let mut low = 0;
-                let mut high = self.len() - 1;
-
-                while low <= high {
-                    // Prevent overflow with large indices
-                    let mid = low + (high - low) / 2;
-
-                    match self.get(mid)?.cmp(target) {
-                        Ordering::Equal => return Some(mid),
-                        Ordering::Less => low = mid + 1,
-                        Ordering::Greater => {
-                            // Prevent underflow when mid is 0
-                            if mid == 0 {
-                                break;
-                            }
-                            high = mid - 1;
-                        }
-                    }
-                }
+    /// Like `binary_search`, but records every probe in a `SearchTrace`.
+    ///
+    /// The trace's `result` is always identical to `binary_search`, and the
+    /// number of comparisons never exceeds ⌊log₂ n⌋ + 1.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::core::Array;
+    /// use arrays::algorithms::BinarySearchable;
+    ///
+    /// let arr = Array::from_slice(&[1, 3, 5, 7, 9, 11, 13], 10).unwrap();
+    /// let trace = arr.binary_search_traced(&11);
+    /// assert_eq!(trace.result, Some(5));
+    /// assert_eq!(trace.probes, vec![3, 5]);
+    /// ```
+    fn binary_search_traced(&self, target: &T) -> SearchTrace
+    where
+        T: Ord;
+
+    /// Like `binary_search_by`, but records every probe in a `SearchTrace`.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::core::Array;
+    /// use arrays::algorithms::BinarySearchable;
+    ///
+    /// let arr = Array::from_slice(&[(1, 'a'), (4, 'b'), (9, 'c')], 3).unwrap();
+    /// let trace = arr.binary_search_by_traced(|&(key, _)| key.cmp(&9));
+    /// assert_eq!(trace.result, Some(2));
+    /// assert_eq!(trace.probes, vec![1, 2]);
+    /// ```
+    fn binary_search_by_traced<F>(&self, f: F) -> SearchTrace
+    where
+        F: FnMut(&T) -> Ordering;
+
+    /// Like `binary_search_first`, but records every probe in a `SearchTrace`.
+    fn binary_search_first_traced(&self, target: &T) -> SearchTrace
+    where
+        T: Ord;
 
-                None
+    /// Like `binary_search_last`, but records every probe in a `SearchTrace`.
+    fn binary_search_last_traced(&self, target: &T) -> SearchTrace
+    where
+        T: Ord;
+
+    /// Binary search whose loop has no data-dependent branches.
+    ///
+    /// Each step halves the candidate window and moves its base with a
+    /// conditional select instead of a jump, so the CPU never mispredicts.
+    /// The loop always runs ⌈log₂ n⌉ times, and there is no `mid == 0`
+    /// underflow case to check. Returns the leftmost match.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::core::Array;
+    /// use arrays::algorithms::BinarySearchable;
+    ///
+    /// let arr = Array::from_slice(&[1, 2, 2, 2, 5, 8], 10).unwrap();
+    /// assert_eq!(arr.binary_search_branchless(&2), Some(1));
+    /// assert_eq!(arr.binary_search_branchless(&3), None);
+    /// ```
+    fn binary_search_branchless(&self, target: &T) -> Option<usize>
+    where
+        T: Ord;
+
+    /// Branchless halving until at most `threshold` candidates remain,
+    /// then a linear scan of that window.
+    ///
+    /// Small windows fit in a cache line or two, where a sequential scan
+    /// beats further halving. Returns the leftmost match.
+    fn binary_search_hybrid(&self, target: &T, threshold: usize) -> Option<usize>
+    where
+        T: Ord;
+
+    /// Searches for `target` using the given loop strategy.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::core::Array;
+    /// use arrays::algorithms::binary_search::{BinarySearchStrategy, BinarySearchable};
+    ///
+    /// let arr = Array::from_slice(&[1, 3, 5, 7, 9], 10).unwrap();
+    /// let strategy = BinarySearchStrategy::hybrid();
+    /// assert_eq!(arr.binary_search_with_strategy(&7, strategy), Some(3));
+    /// ```
+    fn binary_search_with_strategy(
+        &self,
+        target: &T,
+        strategy: BinarySearchStrategy,
+    ) -> Option<usize>
+    where
+        T: Ord,
+    {
+        match strategy {
+            BinarySearchStrategy::Classic => self.binary_search(target),
+            BinarySearchStrategy::Branchless => self.binary_search_branchless(target),
+            BinarySearchStrategy::Hybrid { threshold } => {
+                self.binary_search_hybrid(target, threshold)
             }
+        }
+    }
 
-            fn binary_search_by<F>(&self, mut f: F) -> Option<usize>
-            where
-                F: FnMut(&T) -> Ordering,
-            {
-                if self.is_empty() {
-                    return None;
-                }
+    /// Searches for `target` in a sorted array that has been rotated by an
+    /// unknown amount, such as `[4, 5, 6, 7, 0, 1, 2]`.
+    ///
+    /// Returns the physical index of the target if found. Runs in O(log n)
+    /// on distinct elements; with duplicates the rotation point can hide
+    /// behind equal values and the search degrades to O(n).
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::core::Array;
+    /// use arrays::algorithms::BinarySearchable;
+    ///
+    /// let arr = Array::from_slice(&[4, 5, 6, 7, 0, 1, 2], 10).unwrap();
+    /// assert_eq!(arr.search_rotated(&5), Some(1));
+    /// assert_eq!(arr.search_rotated(&1), Some(5));
+    /// assert_eq!(arr.search_rotated(&3), None);
+    /// ```
+    fn search_rotated(&self, target: &T) -> Option<usize>
+    where
+        T: Ord;
 
-                let mut low = 0;
-                let mut high = self.len() - 1;
-
-                while low <= high {
-                    let mid = low + (high - low) / 2;
-
-                    match f(self.get(mid)?) {
-                        Ordering::Equal => return Some(mid),
-                        Ordering::Less => low = mid + 1,
-                        Ordering::Greater => {
-                            if mid == 0 {
-                                break;
-                            }
-                            high = mid - 1;
-                        }
+    /// Finds the index of the smallest element of a rotated sorted array,
+    /// which is also how far the array was rotated.
+    ///
+    /// Returns 0 for arrays that are not rotated, including empty ones.
+    /// Degrades to O(n) with duplicates, like `search_rotated`.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::core::Array;
+    /// use arrays::algorithms::BinarySearchable;
+    ///
+    /// let arr = Array::from_slice(&[4, 5, 6, 7, 0, 1, 2], 10).unwrap();
+    /// assert_eq!(arr.find_rotation_point(), 4);
+    /// ```
+    fn find_rotation_point(&self) -> usize
+    where
+        T: Ord;
+
+    /// Returns the smallest element of a rotated sorted array, or `None`
+    /// if it is empty.
+    fn min_in_rotated(&self) -> Option<&T>
+    where
+        T: Ord;
+}
+
+// Every `Sequence` gets binary search through this blanket implementation
+impl<T, S> BinarySearchable<T> for S
+where
+    S: Sequence<T> + ?Sized,
+{
+    fn binary_search(&self, target: &T) -> Option<usize>
+    where
+        T: Ord,
+    {
+        debug_assert_sorted_by(self, T::cmp);
+        if self.is_empty() {
+            return None;
+        }
+
+        let mut low = 0;
+        let mut high = self.len() - 1;
+
+        while low <= high {
+            // Prevent overflow with large indices
+            let mid = low + (high - low) / 2;
+
+            match self.get(mid)?.cmp(target) {
+                Ordering::Equal => return Some(mid),
+                Ordering::Less => low = mid + 1,
+                Ordering::Greater => {
+                    // Prevent underflow when mid is 0
+                    if mid == 0 {
+                        break;
                     }
+                    high = mid - 1;
                 }
-
-                None
             }
+        }
 
-            fn binary_search_first(&self, target: &T) -> Option<usize>
-            where
-                T: Ord,
-            {
-                if self.is_empty() {
-                    return None;
-                }
+        None
+    }
+
+    fn binary_search_by<F>(&self, mut f: F) -> Option<usize>
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        if self.is_empty() {
+            return None;
+        }
+
+        let mut low = 0;
+        let mut high = self.len() - 1;
+
+        while low <= high {
+            let mid = low + (high - low) / 2;
 
-                let mut low = 0;
-                let mut high = self.len() - 1;
-                let mut result = None;
-
-                while low <= high {
-                    let mid = low + (high - low) / 2;
-
-                    match self.get(mid)?.cmp(target) {
-                        Ordering::Equal => {
-                            result = Some(mid);
-                            // Continue searching in the left half
-                            if mid == 0 {
-                                break;
-                            }
-                            high = mid - 1;
-                        }
-                        Ordering::Less => low = mid + 1,
-                        Ordering::Greater => {
-                            if mid == 0 {
-                                break;
-                            }
-                            high = mid - 1;
-                        }
+            match f(self.get(mid)?) {
+                Ordering::Equal => return Some(mid),
+                Ordering::Less => low = mid + 1,
+                Ordering::Greater => {
+                    if mid == 0 {
+                        break;
                     }
+                    high = mid - 1;
                 }
+            }
+        }
+
+        None
+    }
+
+    fn binary_search_checked(
+        &self,
+        target: &T,
+        mode: PreconditionMode,
+    ) -> Result<Option<usize>, PreconditionError>
+    where
+        T: Ord,
+    {
+        enforce(self, mode, T::cmp)?;
+        Ok(self.binary_search_by(|elem| elem.cmp(target)))
+    }
+
+    fn binary_search_first(&self, target: &T) -> Option<usize>
+    where
+        T: Ord,
+    {
+        debug_assert_sorted_by(self, T::cmp);
+        bounded_search(self, target, true)
+    }
+
+    fn binary_search_last(&self, target: &T) -> Option<usize>
+    where
+        T: Ord,
+    {
+        debug_assert_sorted_by(self, T::cmp);
+        bounded_search(self, target, false)
+    }
+
+    fn binary_search_first_checked(
+        &self,
+        target: &T,
+        mode: PreconditionMode,
+    ) -> Result<Option<usize>, PreconditionError>
+    where
+        T: Ord,
+    {
+        enforce(self, mode, T::cmp)?;
+        Ok(bounded_search(self, target, true))
+    }
+
+    fn binary_search_last_checked(
+        &self,
+        target: &T,
+        mode: PreconditionMode,
+    ) -> Result<Option<usize>, PreconditionError>
+    where
+        T: Ord,
+    {
+        enforce(self, mode, T::cmp)?;
+        Ok(bounded_search(self, target, false))
+    }
+
+    fn binary_search_insertion_point(&self, target: &T) -> usize
+    where
+        T: Ord,
+    {
+        debug_assert_sorted_by(self, T::cmp);
+        self.binary_search_insertion_point_by(|elem| elem.cmp(target))
+    }
+
+    fn binary_search_insertion_point_by<F>(&self, mut f: F) -> usize
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        let mut low = 0;
+        let mut high = self.len();
 
-                result
+        while low < high {
+            let mid = low + (high - low) / 2;
+
+            match self.get(mid).map(&mut f) {
+                Some(Ordering::Less) => low = mid + 1,
+                _ => high = mid,
             }
+        }
 
-            fn binary_search_last(&self, target: &T) -> Option<usize>
-            where
-                T: Ord,
-            {
-                if self.is_empty() {
-                    return None;
-                }
+        low
+    }
+
+    fn binary_search_traced(&self, target: &T) -> SearchTrace
+    where
+        T: Ord,
+    {
+        debug_assert_sorted_by(self, T::cmp);
+        self.binary_search_by_traced(|elem| elem.cmp(target))
+    }
+
+    fn binary_search_by_traced<F>(&self, mut f: F) -> SearchTrace
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        let mut trace = SearchTrace::new();
+        if self.is_empty() {
+            return trace.finish(None);
+        }
+
+        let mut low = 0;
+        let mut high = self.len() - 1;
 
-                let mut low = 0;
-                let mut high = self.len() - 1;
-                let mut result = None;
-
-                while low <= high {
-                    let mid = low + (high - low) / 2;
-
-                    match self.get(mid)?.cmp(target) {
-                        Ordering::Equal => {
-                            result = Some(mid);
-                            // Continue searching in the right half
-                            low = mid + 1;
-                        }
-                        Ordering::Less => low = mid + 1,
-                        Ordering::Greater => {
-                            if mid == 0 {
-                                break;
-                            }
-                            high = mid - 1;
-                        }
+        while low <= high {
+            let mid = low + (high - low) / 2;
+            trace.probe(low, high, mid);
+
+            match self.get(mid).map(&mut f) {
+                Some(Ordering::Equal) => return trace.finish(Some(mid)),
+                Some(Ordering::Less) => low = mid + 1,
+                Some(Ordering::Greater) => {
+                    if mid == 0 {
+                        break;
                     }
+                    high = mid - 1;
                 }
+                None => break,
+            }
+        }
+
+        trace.finish(None)
+    }
 
-                result
+    fn binary_search_first_traced(&self, target: &T) -> SearchTrace
+    where
+        T: Ord,
+    {
+        debug_assert_sorted_by(self, T::cmp);
+        bounded_search_traced(self, target, true)
+    }
+
+    fn binary_search_last_traced(&self, target: &T) -> SearchTrace
+    where
+        T: Ord,
+    {
+        debug_assert_sorted_by(self, T::cmp);
+        bounded_search_traced(self, target, false)
+    }
+
+    fn binary_search_branchless(&self, target: &T) -> Option<usize>
+    where
+        T: Ord,
+    {
+        self.binary_search_hybrid(target, 1)
+    }
+
+    fn binary_search_hybrid(&self, target: &T, threshold: usize) -> Option<usize>
+    where
+        T: Ord,
+    {
+        debug_assert_sorted_by(self, T::cmp);
+        let found = match self.as_slice() {
+            Some(slice) => hybrid_lower_bound(slice.len(), threshold, |i| &slice[i], target),
+            None => {
+                let at = |i| self.get(i).expect("index within bounds");
+                hybrid_lower_bound(self.len(), threshold, at, target)
             }
+        };
 
-            fn binary_search_insertion_point(&self, target: &T) -> usize
-            where
-                T: Ord,
-            {
-                if self.is_empty() {
-                    return 0;
-                }
+        match found {
+            Some(index) if self.get(index) == Some(target) => Some(index),
+            _ => None,
+        }
+    }
 
-                let mut low = 0;
-                let mut high = self.len();
+    fn search_rotated(&self, target: &T) -> Option<usize>
+    where
+        T: Ord,
+    {
+        let view = RotatedView::new(self);
+        let index = view.binary_search(target)?;
+        Some(view.to_physical(index))
+    }
 
-                while low < high {
-                    let mid = low + (high - low) / 2;
+    fn find_rotation_point(&self) -> usize
+    where
+        T: Ord,
+    {
+        if self.is_empty() {
+            return 0;
+        }
 
-                    match self.get(mid) {
-                        Some(elem) if elem < target => low = mid + 1,
-                        _ => high = mid,
+        let mut low = 0;
+        let mut high = self.len() - 1;
+
+        // Invariant: the rotation point lies in low..=high
+        while low < high {
+            let mid = low + (high - low) / 2;
+            let (Some(mid_value), Some(high_value)) = (self.get(mid), self.get(high)) else {
+                break;
+            };
+
+            match mid_value.cmp(high_value) {
+                // The drop is somewhere after mid
+                Ordering::Greater => low = mid + 1,
+                // mid..=high is ascending, so the drop is at or before mid
+                Ordering::Less => high = mid,
+                // Equal values hide which side the drop is on. Shrink by one,
+                // unless high itself is the drop.
+                Ordering::Equal => {
+                    if self.get(high - 1) > self.get(high) {
+                        return high;
                     }
+                    high -= 1;
                 }
+            }
+        }
+
+        low
+    }
+
+    fn min_in_rotated(&self) -> Option<&T>
+    where
+        T: Ord,
+    {
+        self.get(self.find_rotation_point())
+    }
+}
+
+/// A rotated sorted sequence presented in sorted order.
+///
+/// Logical index `i` maps to physical index `(i + offset) % len`, so for
+/// `[4, 5, 6, 7, 0, 1, 2]` with offset 4 the view reads `[0, 1, 2, 4, 5, 6, 7]`.
+/// The view implements `Sequence`, so every search in `algorithms` works
+/// through it without copying; indices it returns are logical and can be
+/// mapped back with `to_physical`.
+///
+/// # Examples
+/// ```
+/// use arrays::core::Array;
+/// use arrays::algorithms::{ExponentialSearchable, RotatedView};
+///
+/// let arr = Array::from_slice(&[4, 5, 6, 7, 0, 1, 2], 10).unwrap();
+/// let view = RotatedView::new(&arr);
+///
+/// let index = view.exponential_search(&6).unwrap();
+/// assert_eq!(index, 5);
+/// assert_eq!(view.to_physical(index), 2);
+/// ```
+pub struct RotatedView<'a, T, S: ?Sized> {
+    seq: &'a S,
+    offset: usize,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<'a, T, S> RotatedView<'a, T, S>
+where
+    S: Sequence<T> + ?Sized,
+{
+    /// Creates a view over `seq`, locating the rotation point with
+    /// `find_rotation_point`.
+    pub fn new(seq: &'a S) -> Self
+    where
+        T: Ord,
+    {
+        Self::with_offset(seq, seq.find_rotation_point())
+    }
 
-                low
+    /// Creates a view over `seq` whose logical start is at physical index
+    /// `offset`, for when the rotation is already known.
+    ///
+    /// Offsets past the end wrap around.
+    pub fn with_offset(seq: &'a S, offset: usize) -> Self {
+        let offset = if seq.is_empty() {
+            0
+        } else {
+            offset % seq.len()
+        };
+
+        RotatedView {
+            seq,
+            offset,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the physical index of the view's first element.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Converts a logical index in the view to an index in the underlying sequence.
+    pub fn to_physical(&self, index: usize) -> usize {
+        let len = self.seq.len();
+        let index = index + self.offset;
+        if index >= len {
+            index - len
+        } else {
+            index
+        }
+    }
+
+    /// Converts an index in the underlying sequence to a logical index in the view.
+    pub fn to_logical(&self, index: usize) -> usize {
+        if index >= self.offset {
+            index - self.offset
+        } else {
+            index + self.seq.len() - self.offset
+        }
+    }
+}
+
+impl<T, S> Sequence<T> for RotatedView<'_, T, S>
+where
+    S: Sequence<T> + ?Sized,
+{
+    fn len(&self) -> usize {
+        self.seq.len()
+    }
+
+    fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.seq.len() {
+            return None;
+        }
+        self.seq.get(self.to_physical(index))
+    }
+}
+
+impl<T, S: ?Sized> fmt::Debug for RotatedView<'_, T, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RotatedView")
+            .field("offset", &self.offset)
+            .finish_non_exhaustive()
+    }
+}
+
+// Index of the first element not less than `target`, or `None` if every
+// element is less. Halves branch-free while more than `threshold` candidates
+// remain, then scans the remaining window linearly.
+fn hybrid_lower_bound<'a, T, F>(len: usize, threshold: usize, at: F, target: &T) -> Option<usize>
+where
+    T: Ord + 'a,
+    F: Fn(usize) -> &'a T,
+{
+    if len == 0 {
+        return None;
+    }
+
+    let threshold = threshold.max(1);
+    let mut base = 0;
+    let mut size = len;
+
+    // Invariant: the answer lies in base..=base + size
+    while size > threshold {
+        let half = size / 2;
+        let mid = base + half;
+        // Compiles to a conditional move rather than a branch
+        base = if at(mid) < target { mid } else { base };
+        size -= half;
+    }
+
+    let end = (base + size + 1).min(len);
+    (base..end).find(|&i| at(i) >= target)
+}
+
+// Shared loop for the leftmost/rightmost searches. On a match the search
+// keeps narrowing towards the left (`leftmost`) or the right.
+pub(crate) fn bounded_search<T, S>(seq: &S, target: &T, leftmost: bool) -> Option<usize>
+where
+    T: Ord,
+    S: Sequence<T> + ?Sized,
+{
+    if seq.is_empty() {
+        return None;
+    }
+
+    let mut low = 0;
+    let mut high = seq.len() - 1;
+    let mut result = None;
+
+    while low <= high {
+        let mid = low + (high - low) / 2;
+
+        let go_left = match seq.get(mid)?.cmp(target) {
+            Ordering::Equal => {
+                result = Some(mid);
+                leftmost
+            }
+            Ordering::Less => false,
+            Ordering::Greater => true,
+        };
+
+        if go_left {
+            if mid == 0 {
+                break;
             }
+            high = mid - 1;
+        } else {
+            low = mid + 1;
         }
-    };
+    }
+
+    result
 }
 
-// Implement for both array types
-impl_binary_search!(Array<T>);
-impl_binary_search!(DynamicArray<T>);
+// `bounded_search` with every probe recorded.
+fn bounded_search_traced<T, S>(seq: &S, target: &T, leftmost: bool) -> SearchTrace
+where
+    T: Ord,
+    S: Sequence<T> + ?Sized,
+{
+    let mut trace = SearchTrace::new();
+    if seq.is_empty() {
+        return trace.finish(None);
+    }
+
+    let mut low = 0;
+    let mut high = seq.len() - 1;
+    let mut result = None;
+
+    while low <= high {
+        let mid = low + (high - low) / 2;
+        trace.probe(low, high, mid);
+
+        let ordering = match seq.get(mid) {
+            Some(elem) => elem.cmp(target),
+            None => break,
+        };
+
+        let go_left = match ordering {
+            Ordering::Equal => {
+                result = Some(mid);
+                leftmost
+            }
+            Ordering::Less => false,
+            Ordering::Greater => true,
+        };
+
+        if go_left {
+            if mid == 0 {
+                break;
+            }
+            high = mid - 1;
+        } else {
+            low = mid + 1;
+        }
+    }
+
+    trace.finish(result)
+}
 
 /// Additional binary search utilities
 pub mod utils {
     use super::*;
+    use crate::algorithms::precondition::is_sorted_by;
+    use crate::dynamic_array::DynamicArray;
 
     /// Performs binary search on a slice (for comparison with our implementations).
     pub fn binary_search_slice<T: Ord>(slice: &[T], target: &T) -> Option<usize> {
         slice.binary_search(target).ok()
     }
 
-    /// Checks if an array is sorted (precondition for binary search).
-    pub fn is_sorted<T: Ord>(arr: &Array<T>) -> bool {
-        for i in 1..arr.len() {
-            if arr.get(i - 1) > arr.get(i) {
-                return false;
-            }
-        }
-        true
+    /// Checks if a sequence is sorted (precondition for binary search).
+    pub fn is_sorted<T: Ord, S: Sequence<T> + ?Sized>(arr: &S) -> bool {
+        is_sorted_by(arr, T::cmp)
     }
 
     /// Checks if a dynamic array is sorted.
     pub fn is_sorted_dynamic<T: Ord>(arr: &DynamicArray<T>) -> bool {
-        for i in 1..arr.len() {
-            if arr.get(i - 1) > arr.get(i) {
-                return false;
-            }
-        }
-        true
+        is_sorted(arr)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::algorithms::test_util::random_inputs;
+    use crate::core::Array;
+    use crate::dynamic_array::DynamicArray;
 
     #[test]
     fn test_binary_search_basic() {
@@ -299,6 +890,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::manual_range_contains)]
     fn test_binary_search_duplicates() {
         let arr = Array::from_slice(&[1, 2, 2, 2, 3, 4, 5], 10).unwrap();
 
@@ -436,4 +1028,243 @@ mod tests {
         let unsorted = Array::from_slice(&[1, 3, 2, 4, 5], 5).unwrap();
         assert!(!utils::is_sorted(&unsorted));
     }
+
+    #[test]
+    fn test_binary_search_traced_matches_untraced() {
+        let mut arr = DynamicArray::with_capacity(100);
+        for i in 0..100 {
+            arr.push(i * 2);
+        }
+
+        for target in -1..=200 {
+            let trace = arr.binary_search_traced(&target);
+            assert_eq!(trace.result, arr.binary_search(&target));
+            assert_eq!(trace.comparisons, trace.probes.len());
+            // ⌊log₂ 100⌋ + 1 = 7
+            assert!(trace.comparisons <= 7);
+        }
+    }
+
+    #[test]
+    fn test_binary_search_traced_bounds() {
+        let arr = Array::from_slice(&[1, 3, 5, 7, 9, 11, 13], 10).unwrap();
+        let trace = arr.binary_search_traced(&1);
+
+        assert_eq!(trace.result, Some(0));
+        assert_eq!(trace.probes, vec![3, 1, 0]);
+        let bounds: Vec<(usize, usize)> = trace.steps.iter().map(|s| (s.low, s.high)).collect();
+        assert_eq!(bounds, vec![(0, 6), (0, 2), (0, 0)]);
+
+        let empty: Array<i32> = Array::new(4);
+        assert_eq!(empty.binary_search_traced(&1), SearchTrace::default());
+    }
+
+    #[test]
+    fn test_first_last_traced() {
+        let arr = Array::from_slice(&[1, 2, 2, 2, 2, 2, 3], 10).unwrap();
+
+        let first = arr.binary_search_first_traced(&2);
+        assert_eq!(first.result, Some(1));
+        assert_eq!(first.result, arr.binary_search_first(&2));
+
+        let last = arr.binary_search_last_traced(&2);
+        assert_eq!(last.result, Some(5));
+        assert_eq!(last.result, arr.binary_search_last(&2));
+
+        // Both keep halving after the first match
+        assert!(first.comparisons <= 3);
+        assert!(last.comparisons <= 3);
+    }
+
+    #[test]
+    fn test_binary_search_by_traced_matches_untraced() {
+        let mut arr = DynamicArray::new();
+        for i in 0..100 {
+            arr.push((i * 3, i));
+        }
+
+        for key in -1..=300 {
+            let trace = arr.binary_search_by_traced(|&(k, _)| k.cmp(&key));
+            assert_eq!(trace.result, arr.binary_search_by(|&(k, _)| k.cmp(&key)));
+            assert!(trace.comparisons <= 7);
+        }
+    }
+
+    #[test]
+    fn test_strategies_match_classic_on_random_inputs() {
+        let strategies = [
+            BinarySearchStrategy::Branchless,
+            BinarySearchStrategy::hybrid(),
+            BinarySearchStrategy::Hybrid { threshold: 1 },
+            BinarySearchStrategy::Hybrid { threshold: 3 },
+            BinarySearchStrategy::Hybrid { threshold: 1000 },
+        ];
+
+        for (seed, mut values) in random_inputs(50, 300) {
+            values.sort();
+            let mut arr = DynamicArray::with_capacity(values.len());
+            arr.extend(values.iter().copied());
+
+            // Every present value, its successor and the smallest keys
+            let targets = values.iter().flat_map(|&x| [x, x + 1]).chain(0..20);
+            for target in targets {
+                let first = arr.binary_search_first(&target);
+                assert_eq!(arr.binary_search(&target).is_some(), first.is_some());
+
+                for strategy in strategies {
+                    let found = arr.binary_search_with_strategy(&target, strategy);
+                    assert_eq!(found, first, "{strategy:?}, seed {seed}, target {target}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_branchless_matches_classic_without_duplicates() {
+        let arr = Array::from_slice(&[1, 3, 5, 7, 9, 11, 13], 10).unwrap();
+
+        for target in 0..15 {
+            assert_eq!(
+                arr.binary_search_branchless(&target),
+                arr.binary_search(&target)
+            );
+        }
+
+        let empty: Array<i32> = Array::new(5);
+        assert_eq!(empty.binary_search_branchless(&1), None);
+        assert_eq!(empty.binary_search_hybrid(&1, 0), None);
+    }
+
+    #[test]
+    fn test_strategies_on_non_contiguous_sequence() {
+        use std::collections::VecDeque;
+
+        let mut deque: VecDeque<i32> = (0..40).map(|i| i * 3).collect();
+        deque.rotate_left(7);
+        deque.make_contiguous().sort();
+        deque.push_front(-3);
+
+        for target in -4..125 {
+            let expected = deque.binary_search_first(&target);
+            assert_eq!(deque.binary_search_branchless(&target), expected);
+            assert_eq!(deque.binary_search_hybrid(&target, 4), expected);
+        }
+    }
+
+    // Every rotation of `sorted`, each paired with its rotation point
+    fn rotations(sorted: &[i32]) -> Vec<(Vec<i32>, usize)> {
+        (0..sorted.len().max(1))
+            .map(|k| {
+                let mut rotated = sorted.to_vec();
+                rotated.rotate_left(k);
+                (
+                    rotated,
+                    if sorted.is_empty() {
+                        0
+                    } else {
+                        (sorted.len() - k) % sorted.len()
+                    },
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_search_rotated_basic() {
+        let arr = Array::from_slice(&[4, 5, 6, 7, 0, 1, 2], 10).unwrap();
+
+        assert_eq!(arr.find_rotation_point(), 4);
+        assert_eq!(arr.min_in_rotated(), Some(&0));
+        assert_eq!(arr.search_rotated(&4), Some(0));
+        assert_eq!(arr.search_rotated(&7), Some(3));
+        assert_eq!(arr.search_rotated(&0), Some(4));
+        assert_eq!(arr.search_rotated(&2), Some(6));
+        assert_eq!(arr.search_rotated(&3), None);
+        assert_eq!(arr.search_rotated(&8), None);
+
+        // Not rotated at all
+        let sorted = Array::from_slice(&[1, 2, 3, 4, 5], 5).unwrap();
+        assert_eq!(sorted.find_rotation_point(), 0);
+        assert_eq!(sorted.search_rotated(&3), Some(2));
+
+        let empty: Array<i32> = Array::new(5);
+        assert_eq!(empty.find_rotation_point(), 0);
+        assert_eq!(empty.min_in_rotated(), None);
+        assert_eq!(empty.search_rotated(&1), None);
+    }
+
+    #[test]
+    fn test_search_rotated_every_rotation() {
+        let sorted: Vec<i32> = (0..13).map(|i| i * 2).collect();
+
+        for (rotated, point) in rotations(&sorted) {
+            let mut arr = DynamicArray::new();
+            arr.extend(rotated.iter().copied());
+
+            assert_eq!(arr.find_rotation_point(), point, "{rotated:?}");
+            assert_eq!(arr.min_in_rotated(), Some(&0));
+
+            for target in -1..27 {
+                let expected = rotated.iter().position(|&x| x == target);
+                assert_eq!(
+                    arr.search_rotated(&target),
+                    expected,
+                    "{rotated:?} {target}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_rotated_with_duplicates() {
+        for sorted in [
+            vec![1, 1, 1, 1, 2],
+            vec![1, 2, 2, 2, 2],
+            vec![0, 1, 1, 1, 1, 1, 1, 3],
+            vec![5, 5, 5],
+        ] {
+            for (rotated, _) in rotations(&sorted) {
+                let point = rotated.find_rotation_point();
+                let view = RotatedView::with_offset(rotated.as_slice(), point);
+                assert!(utils::is_sorted(&view), "{rotated:?} -> {point}");
+                assert_eq!(rotated.min_in_rotated(), sorted.first());
+
+                for target in -1..5 {
+                    let found = rotated.search_rotated(&target);
+                    assert_eq!(found.is_some(), sorted.contains(&target));
+                    if let Some(index) = found {
+                        assert_eq!(rotated[index], target);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_rotated_view_supports_other_searches() {
+        use crate::algorithms::{JumpSearchable, NearestSearchable};
+
+        let arr = Array::from_slice(&[30, 40, 50, 10, 20], 5).unwrap();
+        let view = RotatedView::new(&arr);
+
+        assert_eq!(view.offset(), 3);
+        assert_eq!(view.len(), 5);
+        assert_eq!(view.get(0), Some(&10));
+        assert_eq!(view.get(4), Some(&50));
+        assert_eq!(view.get(5), None);
+        assert!(utils::is_sorted(&view));
+
+        assert_eq!(view.jump_search(&40), Some(3));
+        assert_eq!(view.binary_search_first(&20), Some(1));
+        assert_eq!(view.floor(&35), Some(2));
+
+        for physical in 0..5 {
+            assert_eq!(view.to_physical(view.to_logical(physical)), physical);
+        }
+
+        // Offsets past the end wrap around
+        assert_eq!(RotatedView::with_offset(&arr, 8).offset(), 3);
+        let empty: DynamicArray<i32> = DynamicArray::new();
+        assert_eq!(RotatedView::with_offset(&empty, 3).offset(), 0);
+    }
 }
@@ -1 +1,204 @@
-// Bubble sort implementation for arrays
\ No newline at end of file
+//! Bubble sort implementations for arrays.
+//!
+//! Bubble sort repeatedly walks the array and swaps adjacent elements that
+//! are out of order, so each pass carries the largest remaining element to
+//! its final place. It is O(n²), stable and in place, and mostly useful as
+//! a teaching baseline for the faster sorts.
+//!
+//! Three variants are provided:
+//! - The classic version always makes n - 1 passes.
+//! - The early-exit version remembers where the last swap happened; nothing
+//!   after it can move again, so the next pass stops there, and a pass
+//!   without swaps ends the sort. Sorted input takes a single O(n) pass.
+//! - Cocktail shaker sort alternates forward and backward passes, so small
+//!   elements near the end ("turtles") move to the front in one pass instead
+//!   of one position per pass.
+//!
+//! For theory and visual explanations, see:
+//! - Bubble Sort Theory: `algorithms/sorting/bubble-sort/`
+
+use super::sort_observer::SortOps;
+use std::cmp::Ordering;
+
+pub(crate) fn bubble<T, S: SortOps<T>>(v: &mut [T], s: &mut S) {
+    let n = v.len();
+    for pass in 1..n {
+        // The last `pass - 1` elements are already in place
+        for i in 1..=n - pass {
+            if s.compare(&v[i - 1], &v[i]) == Ordering::Greater {
+                s.swap(v, i - 1, i);
+            }
+        }
+    }
+}
+
+pub(crate) fn bubble_early_exit<T, S: SortOps<T>>(v: &mut [T], s: &mut S) {
+    // v[end..] is sorted and in its final place
+    let mut end = v.len();
+    while end > 1 {
+        let mut last_swap = 0;
+        for i in 1..end {
+            if s.compare(&v[i - 1], &v[i]) == Ordering::Greater {
+                s.swap(v, i - 1, i);
+                last_swap = i;
+            }
+        }
+        end = last_swap;
+    }
+}
+
+pub(crate) fn cocktail_shaker<T, S: SortOps<T>>(v: &mut [T], s: &mut S) {
+    // Only v[start..end] can still be out of place
+    let mut start = 0;
+    let mut end = v.len();
+
+    while end > start + 1 {
+        // Forward pass: carry the largest element to the end
+        let mut last_swap = start;
+        for i in start + 1..end {
+            if s.compare(&v[i - 1], &v[i]) == Ordering::Greater {
+                s.swap(v, i - 1, i);
+                last_swap = i;
+            }
+        }
+        end = last_swap;
+
+        // Backward pass: carry the smallest element to the start
+        let mut first_swap = end;
+        for i in (start + 1..end).rev() {
+            if s.compare(&v[i - 1], &v[i]) == Ordering::Greater {
+                s.swap(v, i - 1, i);
+                first_swap = i;
+            }
+        }
+        start = first_swap;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::algorithms::test_util::assert_sorts_like_std;
+    use crate::algorithms::Sortable;
+    use crate::core::Array;
+    use crate::dynamic_array::DynamicArray;
+
+    type SortFn = fn(&mut DynamicArray<u32>);
+
+    const SORTS: [(&str, SortFn); 3] = [
+        ("bubble", |arr| arr.bubble_sort()),
+        ("early exit", |arr| arr.bubble_sort_early_exit()),
+        ("cocktail shaker", |arr| arr.cocktail_shaker_sort()),
+    ];
+
+    #[test]
+    fn test_sorts_match_std_sort() {
+        for (name, sort) in SORTS {
+            assert_sorts_like_std(name, 30, 90, sort);
+        }
+    }
+
+    #[test]
+    fn test_edge_cases() {
+        for (name, sort) in SORTS {
+            let mut empty = DynamicArray::new();
+            sort(&mut empty);
+            assert!(empty.is_empty(), "{name}");
+
+            let mut single = DynamicArray::new();
+            single.push(7);
+            sort(&mut single);
+            assert_eq!(single.as_slice(), &[7], "{name}");
+
+            let mut reversed = DynamicArray::new();
+            reversed.extend((0..20).rev());
+            sort(&mut reversed);
+            assert_eq!(reversed.as_slice(), (0..20).collect::<Vec<_>>(), "{name}");
+        }
+    }
+
+    #[test]
+    fn test_fixed_array() {
+        let mut arr = Array::from_slice(&[4, -1, 3, 0], 8).unwrap();
+        arr.bubble_sort();
+        assert_eq!(arr.as_slice(), &[-1, 0, 3, 4]);
+
+        let mut arr = Array::from_slice(&["pear", "fig", "apple"], 3).unwrap();
+        arr.cocktail_shaker_sort();
+        assert_eq!(arr.as_slice(), &["apple", "fig", "pear"]);
+    }
+
+    #[test]
+    fn test_sorts_are_stable() {
+        let records = [(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd'), (0, 'e'), (2, 'f')];
+        let expected = [(0, 'e'), (1, 'b'), (1, 'd'), (2, 'a'), (2, 'c'), (2, 'f')];
+        let by_key = |a: &(i32, char), b: &(i32, char)| a.0.cmp(&b.0);
+
+        let mut arr = Array::from_slice(&records, 6).unwrap();
+        arr.bubble_sort_by(by_key);
+        assert_eq!(arr.as_slice(), &expected);
+
+        let mut arr = Array::from_slice(&records, 6).unwrap();
+        arr.bubble_sort_early_exit_by(by_key);
+        assert_eq!(arr.as_slice(), &expected);
+
+        let mut arr = Array::from_slice(&records, 6).unwrap();
+        arr.cocktail_shaker_sort_by(by_key);
+        assert_eq!(arr.as_slice(), &expected);
+    }
+
+    #[test]
+    fn test_early_exit_comparison_counts() {
+        let n = 100;
+        let mut sorted = DynamicArray::new();
+        sorted.extend(0..n);
+
+        // Classic bubble sort does every pass regardless
+        let mut comparisons = 0;
+        sorted.bubble_sort_by(|a, b| {
+            comparisons += 1;
+            a.cmp(b)
+        });
+        assert_eq!(comparisons, n * (n - 1) / 2);
+
+        // One pass over sorted input is enough to stop
+        let mut comparisons = 0;
+        sorted.bubble_sort_early_exit_by(|a, b| {
+            comparisons += 1;
+            a.cmp(b)
+        });
+        assert_eq!(comparisons, n - 1);
+
+        let mut comparisons = 0;
+        sorted.cocktail_shaker_sort_by(|a, b| {
+            comparisons += 1;
+            a.cmp(b)
+        });
+        assert_eq!(comparisons, n - 1);
+    }
+
+    #[test]
+    fn test_cocktail_shaker_moves_turtles_quickly() {
+        // A single small element at the end is a bubble sort worst case
+        let mut values: Vec<usize> = (1..100).collect();
+        values.push(0);
+
+        let mut arr = DynamicArray::new();
+        arr.extend(values.iter().copied());
+        let mut early_exit = 0;
+        arr.bubble_sort_early_exit_by(|a, b| {
+            early_exit += 1;
+            a.cmp(b)
+        });
+
+        let mut arr = DynamicArray::new();
+        arr.extend(values.iter().copied());
+        let mut shaker = 0;
+        arr.cocktail_shaker_sort_by(|a, b| {
+            shaker += 1;
+            a.cmp(b)
+        });
+
+        assert_eq!(arr.as_slice(), (0..100).collect::<Vec<_>>());
+        assert!(shaker * 10 < early_exit, "{shaker} vs {early_exit}");
+    }
+}
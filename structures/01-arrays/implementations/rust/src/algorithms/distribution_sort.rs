@@ -0,0 +1,806 @@
+//! Non-comparison sorts for dynamic arrays.
+//!
+//! Comparison sorts cannot beat Ω(n log n) comparisons. Distribution sorts
+//! avoid comparing elements at all: they compute where each element belongs
+//! from its key and move it there directly, which runs in linear time for
+//! the right kind of key.
+//!
+//! - Counting sort counts each key value, turns the counts into starting
+//!   positions, and places every element at its key's next free position.
+//!   O(n + k) for a key range of size k, so it needs a narrow range.
+//! - LSD radix sort runs a stable counting pass per byte, from the least
+//!   significant byte to the most significant one. O(n) per byte for any
+//!   integer key, regardless of range.
+//! - MSD radix sort buckets byte strings by their first byte, then sorts
+//!   each bucket by the next byte. Only the bytes needed to tell keys apart
+//!   are ever looked at.
+//! - Bucket sort spreads floats over n equal-width buckets between the
+//!   minimum and the maximum and insertion-sorts each bucket. O(n) expected
+//!   for uniformly distributed values.
+//!
+//! Counting, MSD radix and bucket sort place elements by computing a
+//! destination index for each one and then applying that permutation with
+//! swaps, so they need neither `Clone` nor a second copy of the elements.
+//! All four sorts are stable.
+
+use super::float_search::Float;
+use super::insertion_sort::insertion;
+use super::sort_observer::{SortObserver, SortOps, Sorter};
+use crate::dynamic_array::DynamicArray;
+use std::cmp::Ordering;
+use std::fmt;
+
+/// Widest key range counting sort accepts.
+pub const MAX_COUNTING_RANGE: usize = 1 << 24;
+
+// Buckets at most this long are finished with insertion sort by MSD radix
+// sort.
+const MSD_INSERTION_LEN: usize = 16;
+
+/// Integer keys for counting and radix sort. This trait is sealed.
+pub trait RadixKey: Copy + Ord + private::Sealed {
+    /// Width of the key in bytes.
+    const BYTES: usize;
+
+    /// Maps the key to an unsigned integer with the same order.
+    fn to_radix(self) -> u128;
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+macro_rules! impl_radix_key {
+    ($($type:ty),*) => {
+        $(
+            impl private::Sealed for $type {}
+
+            impl RadixKey for $type {
+                const BYTES: usize = std::mem::size_of::<$type>();
+
+                fn to_radix(self) -> u128 {
+                    self as u128
+                }
+            }
+        )*
+    };
+}
+
+// Flipping the sign bit maps MIN..=MAX onto 0..=UMAX in order
+macro_rules! impl_signed_radix_key {
+    ($($type:ty => $unsigned:ty),*) => {
+        $(
+            impl private::Sealed for $type {}
+
+            impl RadixKey for $type {
+                const BYTES: usize = std::mem::size_of::<$type>();
+
+                fn to_radix(self) -> u128 {
+                    ((self as $unsigned) ^ (1 << (<$type>::BITS - 1))) as u128
+                }
+            }
+        )*
+    };
+}
+
+impl_radix_key!(u8, u16, u32, u64, u128, usize);
+impl_signed_radix_key!(
+    i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize
+);
+
+/// Errors reported by counting sort.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CountingSortError {
+    /// The keys span more values than `MAX_COUNTING_RANGE`.
+    RangeTooLarge {
+        /// Number of values between the smallest and largest key,
+        /// inclusive, saturating at `u128::MAX`.
+        range: u128,
+    },
+}
+
+impl fmt::Display for CountingSortError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::RangeTooLarge { range } => write!(
+                f,
+                "key range of {range} values exceeds the counting sort limit of {MAX_COUNTING_RANGE}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for CountingSortError {}
+
+/// Trait for arrays that support distribution sorts.
+pub trait DistributionSortable<T> {
+    /// Sorts the array with counting sort.
+    ///
+    /// The key range is detected from the data. Returns an error, leaving
+    /// the array unchanged, if it is wider than `MAX_COUNTING_RANGE`.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::dynamic_array::DynamicArray;
+    /// use arrays::algorithms::DistributionSortable;
+    ///
+    /// let mut arr = DynamicArray::new();
+    /// arr.extend([1003u32, 1001, 1004, 1001, 1000]);
+    /// arr.counting_sort().unwrap();
+    /// assert_eq!(arr.as_slice(), &[1000, 1001, 1001, 1003, 1004]);
+    ///
+    /// let mut wide = DynamicArray::new();
+    /// wide.extend([0u64, u64::MAX]);
+    /// assert!(wide.counting_sort().is_err());
+    /// ```
+    fn counting_sort(&mut self) -> Result<(), CountingSortError>
+    where
+        T: RadixKey;
+
+    /// Sorts the array with counting sort by the key `f` extracts.
+    ///
+    /// `f` is called once per element.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::dynamic_array::DynamicArray;
+    /// use arrays::algorithms::DistributionSortable;
+    ///
+    /// let mut grades = DynamicArray::new();
+    /// grades.extend([("ann", 'B'), ("bob", 'A'), ("cat", 'B'), ("dan", 'A')]);
+    /// grades.counting_sort_by_key(|&(_, grade)| grade as u8).unwrap();
+    /// assert_eq!(
+    ///     grades.as_slice(),
+    ///     &[("bob", 'A'), ("dan", 'A'), ("ann", 'B'), ("cat", 'B')]
+    /// );
+    /// ```
+    fn counting_sort_by_key<K, F>(&mut self, f: F) -> Result<(), CountingSortError>
+    where
+        K: RadixKey,
+        F: FnMut(&T) -> K;
+
+    /// Sorts the array of integers with LSD radix sort, one byte per pass.
+    ///
+    /// Passes over bytes that are the same for every element are skipped.
+    /// Uses a scratch buffer the size of the array.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::dynamic_array::DynamicArray;
+    /// use arrays::algorithms::DistributionSortable;
+    ///
+    /// let mut arr = DynamicArray::new();
+    /// arr.extend([170i32, -45, 75, -90, 802, 24, 2, 66]);
+    /// arr.radix_sort();
+    /// assert_eq!(arr.as_slice(), &[-90, -45, 2, 24, 66, 75, 170, 802]);
+    /// ```
+    fn radix_sort(&mut self)
+    where
+        T: RadixKey;
+
+    /// Sorts the array of byte strings lexicographically with MSD radix
+    /// sort.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::dynamic_array::DynamicArray;
+    /// use arrays::algorithms::DistributionSortable;
+    ///
+    /// let mut words = DynamicArray::new();
+    /// words.extend(["she", "sells", "sea", "shells", "by", "the", "sea"]);
+    /// words.msd_radix_sort();
+    /// assert_eq!(
+    ///     words.as_slice(),
+    ///     &["by", "sea", "sea", "sells", "she", "shells", "the"]
+    /// );
+    /// ```
+    fn msd_radix_sort(&mut self)
+    where
+        T: AsRef<[u8]>;
+
+    /// Sorts the array of floats with bucket sort, in IEEE 754 `totalOrder`
+    /// like `f64::total_cmp`.
+    ///
+    /// Expected O(n) when the finite values are spread uniformly between
+    /// their minimum and maximum. Infinities and `NaN`s get buckets of their
+    /// own at either end.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::dynamic_array::DynamicArray;
+    /// use arrays::algorithms::DistributionSortable;
+    ///
+    /// let mut arr = DynamicArray::new();
+    /// arr.extend([0.42, 0.32, 0.23, 0.52, 0.25, 0.47, 0.51]);
+    /// arr.bucket_sort();
+    /// assert_eq!(arr.as_slice(), &[0.23, 0.25, 0.32, 0.42, 0.47, 0.51, 0.52]);
+    /// ```
+    fn bucket_sort(&mut self)
+    where
+        T: Float;
+
+    /// `counting_sort`, reporting every operation to `observer`.
+    ///
+    /// Counting sort makes no comparisons; elements are moved into place
+    /// with swaps.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::dynamic_array::DynamicArray;
+    /// use arrays::algorithms::{DistributionSortable, SortStats};
+    ///
+    /// let mut arr = DynamicArray::new();
+    /// arr.extend([3u8, 1, 2, 0]);
+    /// let mut stats = SortStats::default();
+    /// arr.counting_sort_observed(&mut stats).unwrap();
+    /// assert_eq!(arr.as_slice(), &[0, 1, 2, 3]);
+    /// assert_eq!(stats.comparisons, 0);
+    /// ```
+    fn counting_sort_observed<O>(&mut self, observer: &mut O) -> Result<(), CountingSortError>
+    where
+        T: RadixKey,
+        O: SortObserver<T> + ?Sized;
+
+    /// `counting_sort_by_key`, reporting every operation to `observer`.
+    fn counting_sort_by_key_observed<K, F, O>(
+        &mut self,
+        f: F,
+        observer: &mut O,
+    ) -> Result<(), CountingSortError>
+    where
+        K: RadixKey,
+        F: FnMut(&T) -> K,
+        O: SortObserver<T> + ?Sized;
+
+    /// `radix_sort`, reporting every operation to `observer`.
+    ///
+    /// Passes that leave their output in the scratch buffer are invisible;
+    /// each pass that lands in the array reports a write for every element.
+    fn radix_sort_observed<O>(&mut self, observer: &mut O)
+    where
+        T: RadixKey,
+        O: SortObserver<T> + ?Sized;
+
+    /// `msd_radix_sort`, reporting every operation to `observer`.
+    fn msd_radix_sort_observed<O>(&mut self, observer: &mut O)
+    where
+        T: AsRef<[u8]>,
+        O: SortObserver<T> + ?Sized;
+
+    /// `bucket_sort`, reporting every operation to `observer`.
+    fn bucket_sort_observed<O>(&mut self, observer: &mut O)
+    where
+        T: Float,
+        O: SortObserver<T> + ?Sized;
+}
+
+impl<T> DistributionSortable<T> for DynamicArray<T> {
+    fn counting_sort(&mut self) -> Result<(), CountingSortError>
+    where
+        T: RadixKey,
+    {
+        counting(self.as_mut_slice(), |&x| x, &mut Sorter::new(uncompared))
+    }
+
+    fn counting_sort_by_key<K, F>(&mut self, f: F) -> Result<(), CountingSortError>
+    where
+        K: RadixKey,
+        F: FnMut(&T) -> K,
+    {
+        counting(self.as_mut_slice(), f, &mut Sorter::new(uncompared))
+    }
+
+    fn radix_sort(&mut self)
+    where
+        T: RadixKey,
+    {
+        lsd_radix(self.as_mut_slice(), &mut Sorter::new(uncompared));
+    }
+
+    fn msd_radix_sort(&mut self)
+    where
+        T: AsRef<[u8]>,
+    {
+        msd_radix(self.as_mut_slice(), 0, &mut Sorter::new(by_bytes));
+    }
+
+    fn bucket_sort(&mut self)
+    where
+        T: Float,
+    {
+        bucket(self.as_mut_slice(), &mut Sorter::new(T::total_cmp));
+    }
+
+    fn counting_sort_observed<O>(&mut self, observer: &mut O) -> Result<(), CountingSortError>
+    where
+        T: RadixKey,
+        O: SortObserver<T> + ?Sized,
+    {
+        let s = &mut Sorter::observed(uncompared, observer);
+        counting(self.as_mut_slice(), |&x| x, s)
+    }
+
+    fn counting_sort_by_key_observed<K, F, O>(
+        &mut self,
+        f: F,
+        observer: &mut O,
+    ) -> Result<(), CountingSortError>
+    where
+        K: RadixKey,
+        F: FnMut(&T) -> K,
+        O: SortObserver<T> + ?Sized,
+    {
+        let s = &mut Sorter::observed(uncompared, observer);
+        counting(self.as_mut_slice(), f, s)
+    }
+
+    fn radix_sort_observed<O>(&mut self, observer: &mut O)
+    where
+        T: RadixKey,
+        O: SortObserver<T> + ?Sized,
+    {
+        lsd_radix(
+            self.as_mut_slice(),
+            &mut Sorter::observed(uncompared, observer),
+        );
+    }
+
+    fn msd_radix_sort_observed<O>(&mut self, observer: &mut O)
+    where
+        T: AsRef<[u8]>,
+        O: SortObserver<T> + ?Sized,
+    {
+        msd_radix(
+            self.as_mut_slice(),
+            0,
+            &mut Sorter::observed(by_bytes, observer),
+        );
+    }
+
+    fn bucket_sort_observed<O>(&mut self, observer: &mut O)
+    where
+        T: Float,
+        O: SortObserver<T> + ?Sized,
+    {
+        bucket(
+            self.as_mut_slice(),
+            &mut Sorter::observed(T::total_cmp, observer),
+        );
+    }
+}
+
+// The comparator for sorts that never compare elements
+fn uncompared<T>(_: &T, _: &T) -> Ordering {
+    unreachable!("counting and LSD radix sort do not compare elements")
+}
+
+fn by_bytes<T: AsRef<[u8]>>(a: &T, b: &T) -> Ordering {
+    a.as_ref().cmp(b.as_ref())
+}
+
+fn zeroed(len: usize) -> DynamicArray<usize> {
+    let mut array = DynamicArray::with_capacity(len);
+    array.extend(std::iter::repeat_n(0, len));
+    array
+}
+
+// Stable destinations for elements whose bucket is `buckets[i]`: bucket
+// order first, then original order.
+fn destinations(buckets: &[usize], counts: &mut [usize]) -> DynamicArray<usize> {
+    let mut next = 0;
+    for count in counts.iter_mut() {
+        let start = next;
+        next += *count;
+        *count = start;
+    }
+
+    let mut dest = DynamicArray::with_capacity(buckets.len());
+    for &bucket in buckets {
+        dest.push(counts[bucket]);
+        counts[bucket] += 1;
+    }
+    dest
+}
+
+// Moves v[i] to index dest[i] for every i. Each swap puts at least one
+// element in its final place, so this takes fewer than n swaps.
+fn apply_permutation<T, S: SortOps<T>>(v: &mut [T], dest: &mut [usize], s: &mut S) {
+    for i in 0..v.len() {
+        while dest[i] != i {
+            let target = dest[i];
+            s.swap(v, i, target);
+            dest.swap(i, target);
+        }
+    }
+}
+
+fn counting<T, K, F, S>(v: &mut [T], mut f: F, s: &mut S) -> Result<(), CountingSortError>
+where
+    K: RadixKey,
+    F: FnMut(&T) -> K,
+    S: SortOps<T>,
+{
+    if v.len() < 2 {
+        return Ok(());
+    }
+
+    let mut keys = DynamicArray::with_capacity(v.len());
+    keys.extend(v.iter().map(|x| f(x).to_radix()));
+    let keys = keys.as_slice();
+
+    let min = keys.iter().copied().min().unwrap_or(0);
+    let max = keys.iter().copied().max().unwrap_or(0);
+    let range = (max - min).saturating_add(1);
+    if range > MAX_COUNTING_RANGE as u128 {
+        return Err(CountingSortError::RangeTooLarge { range });
+    }
+
+    // Offsets from the minimum index the counts
+    let mut offsets = DynamicArray::with_capacity(keys.len());
+    offsets.extend(keys.iter().map(|&key| (key - min) as usize));
+
+    let mut counts = zeroed(range as usize);
+    for &offset in offsets.as_slice() {
+        counts.as_mut_slice()[offset] += 1;
+    }
+    let mut dest = destinations(offsets.as_slice(), counts.as_mut_slice());
+    apply_permutation(v, dest.as_mut_slice(), s);
+    Ok(())
+}
+
+fn lsd_radix<T: RadixKey, S: SortOps<T>>(v: &mut [T], s: &mut S) {
+    let n = v.len();
+    if n < 2 {
+        return;
+    }
+
+    let mut scratch = DynamicArray::with_capacity(n);
+    scratch.extend(v.iter().copied());
+    // Which buffer holds the current order
+    let mut in_scratch = false;
+
+    for byte in 0..T::BYTES {
+        let digit = |x: &T| (x.to_radix() >> (8 * byte)) as u8 as usize;
+        let (src, dst) = if in_scratch {
+            (scratch.as_slice(), &mut *v)
+        } else {
+            (&*v, scratch.as_mut_slice())
+        };
+
+        let mut counts = [0usize; 256];
+        for x in src {
+            counts[digit(x)] += 1;
+        }
+        if counts.contains(&n) {
+            continue;
+        }
+
+        let mut next = 0;
+        for count in counts.iter_mut() {
+            let start = next;
+            next += *count;
+            *count = start;
+        }
+        for x in src {
+            let slot = &mut counts[digit(x)];
+            dst[*slot] = *x;
+            *slot += 1;
+        }
+        in_scratch = !in_scratch;
+        if !in_scratch {
+            s.wrote(v, 0..n);
+        }
+    }
+
+    if in_scratch {
+        v.copy_from_slice(scratch.as_slice());
+        s.wrote(v, 0..n);
+    }
+}
+
+// Only the largest bucket of each level is sorted by looping rather than
+// recursion, so every recursive call gets at most half the elements and the
+// depth stays O(log n) even for keys with long common prefixes.
+fn msd_radix<T: AsRef<[u8]>, S: SortOps<T>>(mut v: &mut [T], mut depth: usize, s: &mut S) {
+    // Bucket 0 holds keys that end at `depth`; byte b goes to bucket b + 1
+    let digit = |x: &T, depth: usize| x.as_ref().get(depth).map_or(0, |&b| b as usize + 1);
+
+    // Where `v` starts within the slice this call was given
+    let mut base = 0;
+    loop {
+        if v.len() <= MSD_INSERTION_LEN {
+            // Compares whole keys; they share their first `depth` bytes, so
+            // this orders them by the rest
+            s.within(base, |s| insertion(v, s));
+            return;
+        }
+
+        let mut buckets = DynamicArray::with_capacity(v.len());
+        buckets.extend(v.iter().map(|x| digit(x, depth)));
+        let mut counts = [0usize; 257];
+        for &bucket in buckets.as_slice() {
+            counts[bucket] += 1;
+        }
+        let sizes = counts;
+
+        let mut dest = destinations(buckets.as_slice(), &mut counts);
+        s.within(base, |s| apply_permutation(v, dest.as_mut_slice(), s));
+
+        // Keys that ended are equal and already in place
+        let largest = (1..257).max_by_key(|&b| sizes[b]).unwrap_or(1);
+        let mut start = sizes[0];
+        let mut rest = 0..0;
+        for (bucket, &size) in sizes.iter().enumerate().skip(1) {
+            if bucket == largest {
+                rest = start..start + size;
+            } else if size > 1 {
+                s.within(base + start, |s| {
+                    msd_radix(&mut v[start..start + size], depth + 1, s)
+                });
+            }
+            start += size;
+        }
+
+        base += rest.start;
+        v = &mut std::mem::take(&mut v)[rest];
+        depth += 1;
+    }
+}
+
+fn bucket<F: Float, S: SortOps<F>>(v: &mut [F], s: &mut S) {
+    let n = v.len();
+    if n < 2 {
+        return;
+    }
+
+    let finite = v.iter().map(|x| x.to_f64()).filter(|x| x.is_finite());
+    let min = finite.clone().fold(f64::INFINITY, f64::min);
+    let max = finite.fold(f64::NEG_INFINITY, f64::max);
+
+    // Bucket 0 holds -NaN and -∞, buckets 1..=n the finite values and
+    // bucket n + 1 holds +∞ and +NaN. The mapping never decreases along
+    // the total order, so sorting each bucket sorts the whole array.
+    let bucket_of = |x: F| {
+        let x = x.to_f64();
+        if !x.is_finite() {
+            return if x.is_sign_negative() { 0 } else { n + 1 };
+        }
+        if max == min {
+            return 1;
+        }
+        // Halving first keeps max - min from overflowing to infinity
+        let position = (x / 2.0 - min / 2.0) / (max / 2.0 - min / 2.0);
+        1 + ((position * (n - 1) as f64) as usize).min(n - 1)
+    };
+
+    let mut buckets = DynamicArray::with_capacity(n);
+    buckets.extend(v.iter().map(|&x| bucket_of(x)));
+    let mut counts = zeroed(n + 2);
+    for &bucket in buckets.as_slice() {
+        counts.as_mut_slice()[bucket] += 1;
+    }
+
+    let mut dest = destinations(buckets.as_slice(), counts.as_mut_slice());
+    apply_permutation(v, dest.as_mut_slice(), s);
+
+    // Each count has been advanced to the end of its bucket
+    let mut start = 0;
+    for &end in counts.as_slice() {
+        if end - start > 1 {
+            s.within(start, |s| insertion(&mut v[start..end], s));
+        }
+        start = end;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::test_util::random_u64s;
+
+    fn sorted_by_radix<T: RadixKey + fmt::Debug>(values: &[T]) {
+        let mut expected = values.to_vec();
+        expected.sort();
+
+        let mut arr = DynamicArray::new();
+        arr.extend(values.iter().copied());
+        arr.radix_sort();
+        assert_eq!(arr.as_slice(), expected.as_slice());
+    }
+
+    #[test]
+    fn test_radix_sort_all_integer_widths() {
+        for seed in 1..=10u64 {
+            let raw = random_u64s(seed, 300);
+            sorted_by_radix(&raw.iter().map(|&x| x as u8).collect::<Vec<_>>());
+            sorted_by_radix(&raw.iter().map(|&x| x as u16).collect::<Vec<_>>());
+            sorted_by_radix(&raw.iter().map(|&x| x as u32).collect::<Vec<_>>());
+            sorted_by_radix(&raw);
+            sorted_by_radix(
+                &raw.iter()
+                    .map(|&x| (x as u128) << 64 | x as u128)
+                    .collect::<Vec<_>>(),
+            );
+            sorted_by_radix(&raw.iter().map(|&x| x as usize).collect::<Vec<_>>());
+            sorted_by_radix(&raw.iter().map(|&x| x as i8).collect::<Vec<_>>());
+            sorted_by_radix(&raw.iter().map(|&x| x as i16).collect::<Vec<_>>());
+            sorted_by_radix(&raw.iter().map(|&x| x as i32).collect::<Vec<_>>());
+            sorted_by_radix(&raw.iter().map(|&x| x as i64).collect::<Vec<_>>());
+            sorted_by_radix(
+                &raw.iter()
+                    .map(|&x| (x as i128) << 64 | x as i128)
+                    .collect::<Vec<_>>(),
+            );
+            sorted_by_radix(&raw.iter().map(|&x| x as isize).collect::<Vec<_>>());
+        }
+        sorted_by_radix(&[i64::MAX, 0, i64::MIN, -1, 1, i64::MIN, i64::MAX]);
+        sorted_by_radix::<u32>(&[]);
+        sorted_by_radix(&[7u32]);
+    }
+
+    #[test]
+    fn test_radix_sort_skips_constant_bytes() {
+        // Values below 256 differ only in their lowest byte, so one pass
+        // leaves the result in the scratch buffer and it is copied back
+        let mut arr = DynamicArray::new();
+        arr.extend([200u64, 3, 150, 3, 0]);
+        arr.radix_sort();
+        assert_eq!(arr.as_slice(), &[0, 3, 3, 150, 200]);
+    }
+
+    #[test]
+    fn test_counting_sort_matches_std_sort() {
+        for seed in 1..=10u64 {
+            let values: Vec<i32> = random_u64s(seed, 500)
+                .into_iter()
+                .map(|x| (x % 2000) as i32 - 1000)
+                .collect();
+            let mut expected = values.clone();
+            expected.sort();
+
+            let mut arr = DynamicArray::new();
+            arr.extend(values.iter().copied());
+            arr.counting_sort().unwrap();
+            assert_eq!(arr.as_slice(), expected.as_slice());
+        }
+    }
+
+    #[test]
+    fn test_counting_sort_is_stable() {
+        let mut records = Vec::new();
+        for (i, x) in random_u64s(5, 400).into_iter().enumerate() {
+            records.push(((x % 10) as u8, i));
+        }
+        let mut expected = records.clone();
+        expected.sort_by_key(|&(key, _)| key);
+
+        let mut arr = DynamicArray::new();
+        arr.extend(records.iter().copied());
+        arr.counting_sort_by_key(|&(key, _)| key).unwrap();
+        assert_eq!(arr.as_slice(), expected.as_slice());
+    }
+
+    #[test]
+    fn test_counting_sort_range_too_large() {
+        let mut arr = DynamicArray::new();
+        arr.extend([3i64, -(MAX_COUNTING_RANGE as i64), 2]);
+        let err = arr.counting_sort().unwrap_err();
+        assert_eq!(
+            err,
+            CountingSortError::RangeTooLarge {
+                range: MAX_COUNTING_RANGE as u128 + 4
+            }
+        );
+        assert!(err.to_string().contains("exceeds"));
+        assert_eq!(arr.as_slice(), &[3, -(MAX_COUNTING_RANGE as i64), 2]);
+
+        let mut full = DynamicArray::new();
+        full.extend([u128::MAX, 0]);
+        assert_eq!(
+            full.counting_sort(),
+            Err(CountingSortError::RangeTooLarge { range: u128::MAX })
+        );
+    }
+
+    #[test]
+    fn test_msd_radix_sort_matches_std_sort() {
+        for seed in 1..=10u64 {
+            // Short keys over a small alphabet share many prefixes
+            let words: Vec<Vec<u8>> = random_u64s(seed, 400)
+                .into_iter()
+                .map(|x| {
+                    let len = (x % 7) as usize;
+                    (0..len)
+                        .map(|i| b'a' + ((x >> (8 * i)) % 3) as u8)
+                        .collect()
+                })
+                .collect();
+            let mut expected = words.clone();
+            expected.sort();
+
+            let mut arr = DynamicArray::new();
+            arr.extend(words);
+            arr.msd_radix_sort();
+            assert_eq!(arr.as_slice(), expected.as_slice(), "seed {seed}");
+        }
+    }
+
+    #[test]
+    fn test_msd_radix_sort_long_common_prefixes() {
+        // Every key is a prefix of the next, the worst case for recursion
+        let mut arr = DynamicArray::new();
+        arr.extend((0..3000).rev().map(|len| "x".repeat(len)));
+        arr.msd_radix_sort();
+        assert!(arr.as_slice().windows(2).all(|w| w[0] < w[1]));
+
+        let mut arr = DynamicArray::new();
+        arr.extend([[1u8, 2, 3], [1, 2, 0], [0, 9, 9], [1, 2, 3]]);
+        arr.msd_radix_sort();
+        assert_eq!(
+            arr.as_slice(),
+            &[[0, 9, 9], [1, 2, 0], [1, 2, 3], [1, 2, 3]]
+        );
+    }
+
+    #[test]
+    fn test_bucket_sort_matches_total_cmp() {
+        for seed in 1..=10u64 {
+            let values: Vec<f64> = random_u64s(seed, 500)
+                .into_iter()
+                .map(|x| (x >> 11) as f64 / (1u64 << 53) as f64)
+                .collect();
+            let mut expected = values.clone();
+            expected.sort_by(f64::total_cmp);
+
+            let mut arr = DynamicArray::new();
+            arr.extend(values.iter().copied());
+            arr.bucket_sort();
+            assert_eq!(arr.as_slice(), expected.as_slice());
+        }
+    }
+
+    #[test]
+    fn test_bucket_sort_special_values() {
+        let values = [
+            f32::NAN,
+            1.5,
+            f32::NEG_INFINITY,
+            -0.0,
+            f32::MAX,
+            -f32::NAN,
+            0.0,
+            f32::INFINITY,
+            f32::MIN,
+            -2.0,
+        ];
+        let mut expected = values.to_vec();
+        expected.sort_by(f32::total_cmp);
+
+        let mut arr = DynamicArray::new();
+        arr.extend(values);
+        arr.bucket_sort();
+        let bits = |v: &[f32]| v.iter().map(|x| x.to_bits()).collect::<Vec<_>>();
+        assert_eq!(bits(arr.as_slice()), bits(&expected));
+
+        let mut same = DynamicArray::new();
+        same.extend([2.0f64; 5]);
+        same.bucket_sort();
+        assert_eq!(same.as_slice(), &[2.0; 5]);
+
+        let mut no_finite = DynamicArray::new();
+        no_finite.extend([f64::INFINITY, f64::NEG_INFINITY]);
+        no_finite.bucket_sort();
+        assert_eq!(no_finite.as_slice(), &[f64::NEG_INFINITY, f64::INFINITY]);
+    }
+
+    #[test]
+    fn test_bucket_sort_uniform_buckets_are_small() {
+        // Evenly spaced values land one per bucket, so no bucket needs sorting
+        let n = 1000;
+        let mut arr = DynamicArray::new();
+        arr.extend((0..n).rev().map(|i| i as f64 / n as f64));
+        arr.bucket_sort();
+        let expected: Vec<f64> = (0..n).map(|i| i as f64 / n as f64).collect();
+        assert_eq!(arr.as_slice(), expected.as_slice());
+    }
+}
@@ -0,0 +1,181 @@
+//! Exponential search implementations for arrays.
+//!
+//! Exponential (galloping) search doubles a bound until it passes the target,
+//! then binary searches the last range. It runs in O(log i), where i is the
+//! target's position, so it beats plain binary search when matches tend to
+//! sit near the front, and it works on sequences whose end is expensive to
+//! find.
+//!
+//! For theory, see `algorithms/searching/binary-search/` (exponential search
+//! is covered as a binary search variant).
+
+use super::precondition::{debug_assert_sorted_by, enforce, PreconditionError, PreconditionMode};
+use crate::sequence::Sequence;
+use std::cmp::Ordering;
+
+/// Trait for types that support exponential search operations.
+/// Requires sorted data for correct operation.
+pub trait ExponentialSearchable<T> {
+    /// Performs exponential search on a sorted array.
+    ///
+    /// Returns the index of the target if found, or `None` if not found.
+    ///
+    /// # Requirements
+    /// - The array must be sorted in ascending order
+    ///
+    /// # Panics
+    /// Panics in debug builds if the array is not sorted, following
+    /// `PreconditionMode::DebugAssert`.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::core::Array;
+    /// use arrays::algorithms::ExponentialSearchable;
+    ///
+    /// let arr = Array::from_slice(&[2, 4, 6, 8, 10, 12, 14], 10).unwrap();
+    /// assert_eq!(arr.exponential_search(&10), Some(4));
+    /// assert_eq!(arr.exponential_search(&5), None);
+    /// ```
+    fn exponential_search(&self, target: &T) -> Option<usize>
+    where
+        T: Ord;
+
+    /// Performs exponential search using a custom comparison function.
+    ///
+    /// The comparator should return how an element orders relative to the
+    /// target, as in `BinarySearchable::binary_search_by`.
+    fn exponential_search_by<F>(&self, f: F) -> Option<usize>
+    where
+        F: FnMut(&T) -> Ordering;
+
+    /// Like `exponential_search`, but applies `mode` to the sortedness check
+    /// instead of the default debug assertion.
+    ///
+    /// # Errors
+    /// Returns `NotSorted` in `AlwaysCheck` mode if the array is unsorted.
+    fn exponential_search_checked(
+        &self,
+        target: &T,
+        mode: PreconditionMode,
+    ) -> Result<Option<usize>, PreconditionError>
+    where
+        T: Ord;
+}
+
+// Every `Sequence` gets exponential search through this blanket implementation
+impl<T, S> ExponentialSearchable<T> for S
+where
+    S: Sequence<T> + ?Sized,
+{
+    fn exponential_search(&self, target: &T) -> Option<usize>
+    where
+        T: Ord,
+    {
+        debug_assert_sorted_by(self, T::cmp);
+        self.exponential_search_by(|elem| elem.cmp(target))
+    }
+
+    fn exponential_search_by<F>(&self, mut f: F) -> Option<usize>
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        let n = self.len();
+        if n == 0 {
+            return None;
+        }
+
+        // Gallop: double the bound while the element there is too small
+        let mut bound = 1;
+        while bound < n && f(self.get(bound)?) == Ordering::Less {
+            bound *= 2;
+        }
+
+        // The target, if present, lies in bound/2..=bound
+        let mut low = bound / 2;
+        let mut high = bound.min(n - 1);
+
+        while low <= high {
+            let mid = low + (high - low) / 2;
+
+            match f(self.get(mid)?) {
+                Ordering::Equal => return Some(mid),
+                Ordering::Less => low = mid + 1,
+                Ordering::Greater => {
+                    if mid == 0 {
+                        break;
+                    }
+                    high = mid - 1;
+                }
+            }
+        }
+
+        None
+    }
+
+    fn exponential_search_checked(
+        &self,
+        target: &T,
+        mode: PreconditionMode,
+    ) -> Result<Option<usize>, PreconditionError>
+    where
+        T: Ord,
+    {
+        enforce(self, mode, T::cmp)?;
+        Ok(self.exponential_search_by(|elem| elem.cmp(target)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::BinarySearchable;
+    use crate::core::Array;
+    use crate::dynamic_array::DynamicArray;
+
+    #[test]
+    fn test_exponential_search_basic() {
+        let arr = Array::from_slice(&[1, 3, 5, 7, 9, 11, 13, 15, 17], 10).unwrap();
+
+        assert_eq!(arr.exponential_search(&1), Some(0));
+        assert_eq!(arr.exponential_search(&3), Some(1));
+        assert_eq!(arr.exponential_search(&17), Some(8));
+
+        assert_eq!(arr.exponential_search(&0), None);
+        assert_eq!(arr.exponential_search(&10), None);
+        assert_eq!(arr.exponential_search(&18), None);
+    }
+
+    #[test]
+    fn test_exponential_search_edge_cases() {
+        let empty: Array<i32> = Array::new(5);
+        assert_eq!(empty.exponential_search(&1), None);
+
+        let single = Array::from_slice(&[42], 1).unwrap();
+        assert_eq!(single.exponential_search(&42), Some(0));
+        assert_eq!(single.exponential_search(&7), None);
+    }
+
+    #[test]
+    fn test_exponential_search_agrees_with_binary_search() {
+        for n in [2, 3, 4, 5, 31, 32, 33, 1000] {
+            let mut arr = DynamicArray::with_capacity(n);
+            arr.extend((0..n as i32).map(|i| i * 2));
+
+            for target in -1..=(2 * n as i32) {
+                assert_eq!(
+                    arr.exponential_search(&target).is_some(),
+                    arr.binary_search(&target).is_some(),
+                    "n {n}, target {target}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_exponential_search_by() {
+        let arr = Array::from_slice(&[(1, "one"), (2, "two"), (3, "three")], 5).unwrap();
+
+        assert_eq!(arr.exponential_search_by(|&(key, _)| key.cmp(&3)), Some(2));
+        assert_eq!(arr.exponential_search_by(|&(key, _)| key.cmp(&4)), None);
+    }
+}
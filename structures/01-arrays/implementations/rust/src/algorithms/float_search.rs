@@ -0,0 +1,473 @@
+//! Searching floating-point data.
+//!
+//! `f32` and `f64` only implement `PartialOrd`: `NaN` is not equal to
+//! anything, itself included, so the `T: Ord` search traits reject them.
+//! This module offers two ways around that:
+//!
+//! - `TotalOrd<F>` wraps a float and orders it with IEEE 754 `totalOrder`
+//!   (`f64::total_cmp`), which makes it `Ord` and usable with every search
+//!   in this crate. The `*_by_total_cmp` methods apply the same ordering
+//!   without wrapping.
+//! - `FloatSearchable::*_with_nan_policy` compares numbers normally
+//!   (`-0.0 == 0.0`) and handles `NaN` according to an explicit `NanPolicy`.
+
+use super::precondition::{debug_assert_sorted_by, enforce, PreconditionError, PreconditionMode};
+use super::{BinarySearchable, ExponentialSearchable, JumpSearchable};
+use crate::sequence::Sequence;
+use std::cell::Cell;
+use std::cmp::Ordering;
+use std::fmt;
+
+/// Floating-point element types. This trait is sealed.
+pub trait Float: Copy + PartialOrd + private::Sealed {
+    /// Orders two values with IEEE 754 `totalOrder`.
+    fn total_cmp(&self, other: &Self) -> Ordering;
+
+    /// Returns true if the value is `NaN`.
+    fn is_nan(self) -> bool;
+
+    /// Converts the value to `f64` without loss.
+    fn to_f64(self) -> f64;
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+macro_rules! impl_float {
+    ($($type:ty),*) => {
+        $(
+            impl private::Sealed for $type {}
+
+            impl Float for $type {
+                fn total_cmp(&self, other: &Self) -> Ordering {
+                    <$type>::total_cmp(self, other)
+                }
+
+                fn is_nan(self) -> bool {
+                    <$type>::is_nan(self)
+                }
+
+                fn to_f64(self) -> f64 {
+                    f64::from(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_float!(f32, f64);
+
+/// A float ordered by IEEE 754 `totalOrder`, which makes it `Ord`.
+///
+/// The order is `-NaN < -∞ < … < -0.0 < +0.0 < … < +∞ < +NaN`. Every value,
+/// including `NaN`, equals itself.
+///
+/// # Examples
+/// ```
+/// use arrays::core::Array;
+/// use arrays::algorithms::BinarySearchable;
+/// use arrays::algorithms::float_search::TotalOrd;
+///
+/// let grades = [TotalOrd(2.5f32), TotalOrd(3.0), TotalOrd(3.7), TotalOrd(4.0)];
+/// let arr = Array::from_slice(&grades, 10).unwrap();
+/// assert_eq!(arr.binary_search(&TotalOrd(3.7)), Some(2));
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+#[repr(transparent)]
+pub struct TotalOrd<F>(pub F);
+
+impl<F: Float> TotalOrd<F> {
+    /// Views a slice of floats as a slice of `TotalOrd` without copying.
+    pub fn wrap_slice(slice: &[F]) -> &[TotalOrd<F>] {
+        // SAFETY: TotalOrd is repr(transparent) over F
+        unsafe { &*(slice as *const [F] as *const [TotalOrd<F>]) }
+    }
+}
+
+impl<F: Float> PartialEq for TotalOrd<F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<F: Float> Eq for TotalOrd<F> {}
+
+impl<F: Float> PartialOrd for TotalOrd<F> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<F: Float> Ord for TotalOrd<F> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+/// How searches treat `NaN` targets and elements.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NanPolicy {
+    /// A `NaN` target, or a `NaN` element met while probing, is an error.
+    Reject,
+    /// `NaN` sorts after every number; the array keeps its `NaN`s at the end.
+    SortLast,
+    /// `NaN` sorts before every number; the array keeps its `NaN`s at the start.
+    SortFirst,
+}
+
+/// Errors reported under `NanPolicy::Reject`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FloatSearchError {
+    /// The search target was `NaN`.
+    NanTarget,
+    /// The search probed a `NaN` element, so its answer cannot be trusted.
+    NanElement,
+}
+
+impl fmt::Display for FloatSearchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NanTarget => write!(f, "cannot search for NaN"),
+            Self::NanElement => write!(f, "array contains NaN"),
+        }
+    }
+}
+
+impl std::error::Error for FloatSearchError {}
+
+/// Trait for sequences of floats that support search operations.
+///
+/// The array must be sorted in the order the method uses: `total_cmp` order
+/// for the `*_by_total_cmp` methods, and numeric order with `NaN`s placed
+/// according to the policy for the `*_with_nan_policy` methods. Both panic
+/// in debug builds if it is not, following `PreconditionMode::DebugAssert`;
+/// under `NanPolicy::Reject` the check ignores where the `NaN`s are.
+pub trait FloatSearchable<F: Float> {
+    /// Binary search ordered by `total_cmp`.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::core::Array;
+    /// use arrays::algorithms::FloatSearchable;
+    ///
+    /// let arr = Array::from_slice(&[-1.5, 0.0, 2.25, f64::INFINITY, f64::NAN], 10).unwrap();
+    /// assert_eq!(arr.binary_search_by_total_cmp(&2.25), Some(2));
+    /// assert_eq!(arr.binary_search_by_total_cmp(&f64::NAN), Some(4));
+    /// assert_eq!(arr.binary_search_by_total_cmp(&-0.0), None);
+    /// ```
+    fn binary_search_by_total_cmp(&self, target: &F) -> Option<usize>;
+
+    /// Jump search ordered by `total_cmp`.
+    fn jump_search_by_total_cmp(&self, target: &F) -> Option<usize>;
+
+    /// Exponential search ordered by `total_cmp`.
+    fn exponential_search_by_total_cmp(&self, target: &F) -> Option<usize>;
+
+    /// Like `binary_search_by_total_cmp`, but applies `mode` to the
+    /// sortedness check instead of the default debug assertion.
+    ///
+    /// # Errors
+    /// Returns `NotSorted` in `AlwaysCheck` mode if the array is not in
+    /// `total_cmp` order.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::core::Array;
+    /// use arrays::algorithms::{FloatSearchable, PreconditionError, PreconditionMode};
+    ///
+    /// // -0.0 orders before 0.0 under total_cmp
+    /// let arr = Array::from_slice(&[0.0, -0.0, 1.0], 10).unwrap();
+    /// assert_eq!(
+    ///     arr.binary_search_by_total_cmp_checked(&1.0, PreconditionMode::AlwaysCheck),
+    ///     Err(PreconditionError::NotSorted { first_violation: 1 })
+    /// );
+    /// ```
+    fn binary_search_by_total_cmp_checked(
+        &self,
+        target: &F,
+        mode: PreconditionMode,
+    ) -> Result<Option<usize>, PreconditionError>;
+
+    /// Like `jump_search_by_total_cmp`, with the sortedness check of
+    /// `binary_search_by_total_cmp_checked`.
+    fn jump_search_by_total_cmp_checked(
+        &self,
+        target: &F,
+        mode: PreconditionMode,
+    ) -> Result<Option<usize>, PreconditionError>;
+
+    /// Like `exponential_search_by_total_cmp`, with the sortedness check of
+    /// `binary_search_by_total_cmp_checked`.
+    fn exponential_search_by_total_cmp_checked(
+        &self,
+        target: &F,
+        mode: PreconditionMode,
+    ) -> Result<Option<usize>, PreconditionError>;
+
+    /// Binary search with numeric comparison and explicit `NaN` handling.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::core::Array;
+    /// use arrays::algorithms::FloatSearchable;
+    /// use arrays::algorithms::float_search::{FloatSearchError, NanPolicy};
+    ///
+    /// let arr = Array::from_slice(&[1.0, 2.0, 3.0, f64::NAN], 10).unwrap();
+    /// assert_eq!(arr.binary_search_with_nan_policy(&f64::NAN, NanPolicy::SortLast), Ok(Some(3)));
+    /// assert_eq!(
+    ///     arr.binary_search_with_nan_policy(&f64::NAN, NanPolicy::Reject),
+    ///     Err(FloatSearchError::NanTarget)
+    /// );
+    /// ```
+    fn binary_search_with_nan_policy(
+        &self,
+        target: &F,
+        policy: NanPolicy,
+    ) -> Result<Option<usize>, FloatSearchError>;
+
+    /// Jump search with numeric comparison and explicit `NaN` handling.
+    fn jump_search_with_nan_policy(
+        &self,
+        target: &F,
+        policy: NanPolicy,
+    ) -> Result<Option<usize>, FloatSearchError>;
+
+    /// Exponential search with numeric comparison and explicit `NaN` handling.
+    fn exponential_search_with_nan_policy(
+        &self,
+        target: &F,
+        policy: NanPolicy,
+    ) -> Result<Option<usize>, FloatSearchError>;
+}
+
+impl<F, S> FloatSearchable<F> for S
+where
+    F: Float,
+    S: Sequence<F> + ?Sized,
+{
+    fn binary_search_by_total_cmp(&self, target: &F) -> Option<usize> {
+        debug_assert_sorted_by(self, F::total_cmp);
+        self.binary_search_by(|elem| elem.total_cmp(target))
+    }
+
+    fn jump_search_by_total_cmp(&self, target: &F) -> Option<usize> {
+        debug_assert_sorted_by(self, F::total_cmp);
+        self.jump_search_by(|elem| elem.total_cmp(target))
+    }
+
+    fn exponential_search_by_total_cmp(&self, target: &F) -> Option<usize> {
+        debug_assert_sorted_by(self, F::total_cmp);
+        self.exponential_search_by(|elem| elem.total_cmp(target))
+    }
+
+    fn binary_search_by_total_cmp_checked(
+        &self,
+        target: &F,
+        mode: PreconditionMode,
+    ) -> Result<Option<usize>, PreconditionError> {
+        enforce(self, mode, F::total_cmp)?;
+        Ok(self.binary_search_by(|elem| elem.total_cmp(target)))
+    }
+
+    fn jump_search_by_total_cmp_checked(
+        &self,
+        target: &F,
+        mode: PreconditionMode,
+    ) -> Result<Option<usize>, PreconditionError> {
+        enforce(self, mode, F::total_cmp)?;
+        Ok(self.jump_search_by(|elem| elem.total_cmp(target)))
+    }
+
+    fn exponential_search_by_total_cmp_checked(
+        &self,
+        target: &F,
+        mode: PreconditionMode,
+    ) -> Result<Option<usize>, PreconditionError> {
+        enforce(self, mode, F::total_cmp)?;
+        Ok(self.exponential_search_by(|elem| elem.total_cmp(target)))
+    }
+
+    fn binary_search_with_nan_policy(
+        &self,
+        target: &F,
+        policy: NanPolicy,
+    ) -> Result<Option<usize>, FloatSearchError> {
+        debug_assert_sorted_by(self, |a, b| order_with_policy(a, b, policy));
+        search_with_policy(target, policy, |cmp| self.binary_search_by(cmp))
+    }
+
+    fn jump_search_with_nan_policy(
+        &self,
+        target: &F,
+        policy: NanPolicy,
+    ) -> Result<Option<usize>, FloatSearchError> {
+        debug_assert_sorted_by(self, |a, b| order_with_policy(a, b, policy));
+        search_with_policy(target, policy, |cmp| self.jump_search_by(cmp))
+    }
+
+    fn exponential_search_with_nan_policy(
+        &self,
+        target: &F,
+        policy: NanPolicy,
+    ) -> Result<Option<usize>, FloatSearchError> {
+        debug_assert_sorted_by(self, |a, b| order_with_policy(a, b, policy));
+        search_with_policy(target, policy, |cmp| self.exponential_search_by(cmp))
+    }
+}
+
+// Runs `search` with a comparator that applies `policy`, and turns a probed
+// NaN into an error under `NanPolicy::Reject`.
+fn search_with_policy<F, R>(
+    target: &F,
+    policy: NanPolicy,
+    search: R,
+) -> Result<Option<usize>, FloatSearchError>
+where
+    F: Float,
+    R: FnOnce(&mut dyn FnMut(&F) -> Ordering) -> Option<usize>,
+{
+    if policy == NanPolicy::Reject && target.is_nan() {
+        return Err(FloatSearchError::NanTarget);
+    }
+
+    let saw_nan = Cell::new(false);
+    let mut compare = |elem: &F| {
+        if elem.is_nan() {
+            saw_nan.set(true);
+        }
+        compare_with_policy(elem, target, policy)
+    };
+    let result = search(&mut compare);
+
+    if policy == NanPolicy::Reject && saw_nan.get() {
+        return Err(FloatSearchError::NanElement);
+    }
+    Ok(result)
+}
+
+// The order `policy` expects the array to be sorted in. Under `Reject` a
+// NaN element may sit anywhere, since probing it is reported rather than
+// trusted, so it is left out of the check.
+fn order_with_policy<F: Float>(a: &F, b: &F, policy: NanPolicy) -> Ordering {
+    if policy == NanPolicy::Reject && (a.is_nan() || b.is_nan()) {
+        return Ordering::Equal;
+    }
+    compare_with_policy(a, b, policy)
+}
+
+// Numeric comparison with NaN placed first or last. Under `Reject` a NaN
+// element compares as greater; the caller reports it as an error.
+fn compare_with_policy<F: Float>(elem: &F, target: &F, policy: NanPolicy) -> Ordering {
+    let nan = match policy {
+        NanPolicy::SortFirst => Ordering::Less,
+        NanPolicy::SortLast | NanPolicy::Reject => Ordering::Greater,
+    };
+
+    match (elem.is_nan(), target.is_nan()) {
+        (false, false) => elem.partial_cmp(target).unwrap_or(Ordering::Equal),
+        (true, true) => Ordering::Equal,
+        (true, false) => nan,
+        (false, true) => nan.reverse(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Array;
+    use crate::dynamic_array::DynamicArray;
+
+    #[test]
+    fn test_total_ord_wrapper() {
+        let mut values = [3.0f64, f64::NAN, -0.0, 0.0, -1.0, f64::NEG_INFINITY];
+        values.sort_by(f64::total_cmp);
+
+        let wrapped = TotalOrd::wrap_slice(&values);
+        // Slices have an inherent `binary_search`, so name the trait
+        let search = |target| BinarySearchable::binary_search(wrapped, &TotalOrd(target));
+        assert_eq!(search(0.0), Some(3));
+        assert_eq!(search(-0.0), Some(2));
+        assert_eq!(search(f64::NAN), Some(5));
+        assert_eq!(wrapped.jump_search(&TotalOrd(-1.0)), Some(1));
+        assert_eq!(TotalOrd(f64::NAN), TotalOrd(f64::NAN));
+    }
+
+    #[test]
+    fn test_by_total_cmp_variants_agree() {
+        let mut arr = DynamicArray::new();
+        arr.extend((0..200).map(|i| i as f32 * 0.25 - 10.0));
+
+        for i in -50..250 {
+            let target = i as f32 * 0.25 - 10.0;
+            let expected = arr.binary_search_by_total_cmp(&target);
+            assert_eq!(arr.jump_search_by_total_cmp(&target), expected);
+            assert_eq!(arr.exponential_search_by_total_cmp(&target), expected);
+            assert_eq!(expected.is_some(), (0..200).contains(&i));
+        }
+    }
+
+    #[test]
+    fn test_sort_last_policy() {
+        let arr = Array::from_slice(&[-2.0, -0.0, 1.5, 8.0, f64::NAN, f64::NAN], 10).unwrap();
+        let policy = NanPolicy::SortLast;
+
+        for search in [
+            FloatSearchable::binary_search_with_nan_policy,
+            FloatSearchable::jump_search_with_nan_policy,
+            FloatSearchable::exponential_search_with_nan_policy,
+        ] {
+            assert_eq!(search(&arr, &1.5, policy), Ok(Some(2)));
+            // Numeric comparison treats the zeros as equal
+            assert_eq!(search(&arr, &0.0, policy), Ok(Some(1)));
+            assert_eq!(search(&arr, &9.0, policy), Ok(None));
+            assert!(matches!(search(&arr, &f64::NAN, policy), Ok(Some(4 | 5))));
+        }
+    }
+
+    #[test]
+    fn test_sort_first_policy() {
+        let arr = Array::from_slice(&[f32::NAN, -3.0, 0.5, 4.0], 10).unwrap();
+        let policy = NanPolicy::SortFirst;
+
+        for search in [
+            FloatSearchable::binary_search_with_nan_policy,
+            FloatSearchable::jump_search_with_nan_policy,
+            FloatSearchable::exponential_search_with_nan_policy,
+        ] {
+            assert_eq!(search(&arr, &f32::NAN, policy), Ok(Some(0)));
+            assert_eq!(search(&arr, &4.0, policy), Ok(Some(3)));
+            assert_eq!(search(&arr, &-3.5, policy), Ok(None));
+        }
+    }
+
+    #[test]
+    fn test_reject_policy() {
+        let clean = Array::from_slice(&[1.0, 2.0, 3.0], 10).unwrap();
+        assert_eq!(
+            clean.binary_search_with_nan_policy(&2.0, NanPolicy::Reject),
+            Ok(Some(1))
+        );
+        assert_eq!(
+            clean.jump_search_with_nan_policy(&f64::NAN, NanPolicy::Reject),
+            Err(FloatSearchError::NanTarget)
+        );
+
+        let dirty = Array::from_slice(&[1.0, 2.0, f64::NAN, 4.0, 5.0], 10).unwrap();
+        assert_eq!(
+            dirty.binary_search_with_nan_policy(&4.0, NanPolicy::Reject),
+            Err(FloatSearchError::NanElement)
+        );
+    }
+
+    #[test]
+    fn test_empty() {
+        let arr: DynamicArray<f64> = DynamicArray::new();
+
+        assert_eq!(arr.binary_search_by_total_cmp(&1.0), None);
+        assert_eq!(
+            arr.exponential_search_with_nan_policy(&1.0, NanPolicy::Reject),
+            Ok(None)
+        );
+    }
+}
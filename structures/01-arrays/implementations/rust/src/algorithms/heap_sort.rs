@@ -0,0 +1,333 @@
+//! Heap sort and the binary heap operations it is built from.
+//!
+//! A binary heap is stored in an array as an implicit tree: the children of
+//! index `i` are at `2i + 1` and `2i + 2`, and its parent is at
+//! `(i - 1) / 2`. No pointers are needed, and the tree is always complete.
+//! In a max-heap every parent is at least as large as its children, so the
+//! maximum sits at index 0.
+//!
+//! ```text
+//! index:  0   1   2   3   4   5
+//! value: [9,  7,  8,  3,  5,  6]
+//!
+//!            9
+//!          /   \
+//!         7     8
+//!        / \   /
+//!       3   5 6
+//! ```
+//!
+//! Two operations restore the heap property after a change:
+//! - `sift_down` moves an element that became too small down the tree.
+//! - `sift_up` moves an element that became too large up the tree.
+//!
+//! `heapify` builds a heap bottom-up in O(n), and heap sort then repeatedly
+//! swaps the maximum to the end and sifts the new root down. That gives an
+//! O(n log n) worst case with no extra memory, at the cost of stability and
+//! cache locality. The operations are public so that other structures, such
+//! as a priority queue, can keep a heap in an array.
+//!
+//! All functions take a `compare` that orders the heap: the element `compare`
+//! ranks greatest is at the root. Pass a reversed comparator for a min-heap.
+
+use super::sort_observer::{SortOps, Sorter};
+use std::cmp::Ordering;
+
+/// Rearranges `v` into a max-heap in O(n).
+///
+/// # Examples
+/// ```
+/// use arrays::dynamic_array::DynamicArray;
+/// use arrays::algorithms::heap_sort::{heapify, is_heap};
+///
+/// let mut arr = DynamicArray::new();
+/// arr.extend([3, 1, 4, 1, 5, 9, 2, 6]);
+/// heapify(arr.as_mut_slice());
+/// assert_eq!(arr.as_slice()[0], 9);
+/// assert!(is_heap(arr.as_slice()));
+/// ```
+pub fn heapify<T: Ord>(v: &mut [T]) {
+    heapify_by(v, T::cmp);
+}
+
+/// Rearranges `v` into a heap ordered by `compare` in O(n).
+///
+/// # Examples
+/// ```
+/// use arrays::core::Array;
+/// use arrays::algorithms::heap_sort::heapify_by;
+///
+/// // A min-heap
+/// let mut arr = Array::from_slice(&[5, 3, 8, 1], 4).unwrap();
+/// heapify_by(arr.as_mut_slice(), |a, b| b.cmp(a));
+/// assert_eq!(arr.as_slice()[0], 1);
+/// ```
+pub fn heapify_by<T, F>(v: &mut [T], compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    heapify_with(v, &mut Sorter::new(compare));
+}
+
+pub(crate) fn heapify_with<T, S: SortOps<T>>(v: &mut [T], s: &mut S) {
+    // Leaves are already heaps; fix every parent from the last one up
+    for i in (0..v.len() / 2).rev() {
+        sift_down_with(v, i, s);
+    }
+}
+
+/// Moves the element at `index` down until neither child is greater, and
+/// returns its final index, or `None` if `index` is out of bounds.
+///
+/// Both subtrees of `index` must already be heaps.
+///
+/// # Examples
+/// ```
+/// use arrays::dynamic_array::DynamicArray;
+/// use arrays::algorithms::heap_sort::{is_heap, sift_down};
+///
+/// let mut arr = DynamicArray::new();
+/// arr.extend([9, 7, 8, 3, 5, 6]);
+/// // Replace the root with a small value
+/// *arr.get_mut(0).unwrap() = 1;
+/// assert_eq!(sift_down(arr.as_mut_slice(), 0), Some(5));
+/// assert!(is_heap(arr.as_slice()));
+/// ```
+pub fn sift_down<T: Ord>(v: &mut [T], index: usize) -> Option<usize> {
+    sift_down_by(v, index, T::cmp)
+}
+
+/// Like `sift_down`, but for a heap ordered by `compare`.
+pub fn sift_down_by<T, F>(v: &mut [T], index: usize, compare: F) -> Option<usize>
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    if index >= v.len() {
+        return None;
+    }
+    Some(sift_down_with(v, index, &mut Sorter::new(compare)))
+}
+
+pub(crate) fn sift_down_with<T, S: SortOps<T>>(v: &mut [T], mut index: usize, s: &mut S) -> usize {
+    loop {
+        let left = 2 * index + 1;
+        if left >= v.len() {
+            return index;
+        }
+
+        let right = left + 1;
+        let child = if right < v.len() && s.is_less(&v[left], &v[right]) {
+            right
+        } else {
+            left
+        };
+
+        if !s.is_less(&v[index], &v[child]) {
+            return index;
+        }
+        s.swap(v, index, child);
+        index = child;
+    }
+}
+
+/// Moves the element at `index` up until its parent is not smaller, and
+/// returns its final index, or `None` if `index` is out of bounds.
+///
+/// Everything except `index` must already satisfy the heap property, as
+/// after pushing a new element to the end of a heap.
+///
+/// # Examples
+/// ```
+/// use arrays::dynamic_array::DynamicArray;
+/// use arrays::algorithms::heap_sort::{is_heap, sift_up};
+///
+/// let mut heap = DynamicArray::new();
+/// heap.extend([9, 7, 8, 3, 5]);
+/// heap.push(10);
+/// let last = heap.len() - 1;
+/// assert_eq!(sift_up(heap.as_mut_slice(), last), Some(0));
+/// assert!(is_heap(heap.as_slice()));
+/// ```
+pub fn sift_up<T: Ord>(v: &mut [T], index: usize) -> Option<usize> {
+    sift_up_by(v, index, T::cmp)
+}
+
+/// Like `sift_up`, but for a heap ordered by `compare`.
+pub fn sift_up_by<T, F>(v: &mut [T], index: usize, compare: F) -> Option<usize>
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    if index >= v.len() {
+        return None;
+    }
+    Some(sift_up_with(v, index, &mut Sorter::new(compare)))
+}
+
+pub(crate) fn sift_up_with<T, S: SortOps<T>>(v: &mut [T], mut index: usize, s: &mut S) -> usize {
+    while index > 0 {
+        let parent = (index - 1) / 2;
+        if !s.is_less(&v[parent], &v[index]) {
+            break;
+        }
+        s.swap(v, parent, index);
+        index = parent;
+    }
+    index
+}
+
+/// Returns `true` if `v` is a max-heap.
+pub fn is_heap<T: Ord>(v: &[T]) -> bool {
+    is_heap_by(v, T::cmp)
+}
+
+/// Returns `true` if `v` is a heap ordered by `compare`.
+pub fn is_heap_by<T, F>(v: &[T], mut compare: F) -> bool
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    (1..v.len()).all(|i| compare(&v[(i - 1) / 2], &v[i]) != Ordering::Less)
+}
+
+pub(crate) fn heap<T, S: SortOps<T>>(v: &mut [T], s: &mut S) {
+    heapify_with(v, s);
+    sort_heap(v, s);
+}
+
+// Sorts a slice that is already a heap by repeatedly moving its root to
+// the end.
+pub(crate) fn sort_heap<T, S: SortOps<T>>(v: &mut [T], s: &mut S) {
+    // v[end..] holds the largest elements in order
+    for end in (1..v.len()).rev() {
+        s.swap(v, 0, end);
+        sift_down_with(&mut v[..end], 0, s);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::test_util::{assert_sorts_like_std, random_values};
+    use crate::algorithms::Sortable;
+    use crate::core::Array;
+    use crate::dynamic_array::DynamicArray;
+
+    #[test]
+    fn test_heap_sort_matches_std_sort() {
+        assert_sorts_like_std("heap", 40, 500, |arr| arr.heap_sort());
+        // Reversing a descending sort must give the ascending order
+        assert_sorts_like_std("heap by key", 40, 500, |arr| {
+            arr.heap_sort_by_key(|&x| std::cmp::Reverse(x));
+            arr.as_mut_slice().reverse();
+        });
+    }
+
+    #[test]
+    fn test_edge_cases() {
+        let mut empty: DynamicArray<i32> = DynamicArray::new();
+        empty.heap_sort();
+        heapify(empty.as_mut_slice());
+        assert!(empty.is_empty());
+        assert!(is_heap(empty.as_slice()));
+
+        let mut single = Array::from_slice(&[1], 1).unwrap();
+        single.heap_sort();
+        assert_eq!(single.as_slice(), &[1]);
+        assert_eq!(sift_down(single.as_mut_slice(), 0), Some(0));
+        assert_eq!(sift_up(single.as_mut_slice(), 0), Some(0));
+    }
+
+    #[test]
+    fn test_sift_out_of_bounds() {
+        let mut arr = Array::from_slice(&[2, 1], 2).unwrap();
+        assert_eq!(sift_down(arr.as_mut_slice(), 2), None);
+        assert_eq!(sift_up(arr.as_mut_slice(), 2), None);
+        assert_eq!(arr.as_slice(), &[2, 1]);
+
+        let mut empty: DynamicArray<i32> = DynamicArray::new();
+        assert_eq!(sift_down(empty.as_mut_slice(), 0), None);
+        assert_eq!(sift_up_by(empty.as_mut_slice(), 0, |a, b| b.cmp(a)), None);
+    }
+
+    #[test]
+    fn test_heapify_is_linear() {
+        for seed in 1..=5u64 {
+            let n = 5000;
+            let mut arr = DynamicArray::new();
+            arr.extend(random_values(seed, n, 1_000_000));
+
+            let mut comparisons = 0;
+            heapify_by(arr.as_mut_slice(), |a, b| {
+                comparisons += 1;
+                a.cmp(b)
+            });
+            assert!(is_heap(arr.as_slice()));
+            assert!(comparisons <= 2 * n, "{comparisons}");
+        }
+    }
+
+    #[test]
+    fn test_heap_sort_worst_case_comparisons() {
+        let n = 2048usize;
+        let bound = 2 * n * n.ilog2() as usize;
+        for values in [
+            (0..n as u32).collect::<Vec<_>>(),
+            (0..n as u32).rev().collect(),
+            random_values(3, n, u32::MAX),
+        ] {
+            let mut arr = DynamicArray::new();
+            arr.extend(values);
+            let mut comparisons = 0;
+            arr.heap_sort_by(|a, b| {
+                comparisons += 1;
+                a.cmp(b)
+            });
+            assert!(comparisons <= bound, "{comparisons}");
+        }
+    }
+
+    #[test]
+    fn test_heap_as_priority_queue() {
+        // A min-heap kept in a DynamicArray with push/sift_up and
+        // swap/pop/sift_down
+        let min_first = |a: &u32, b: &u32| b.cmp(a);
+        let values = random_values(21, 300, 1000);
+
+        let mut heap = DynamicArray::new();
+        for &value in &values {
+            heap.push(value);
+            let last = heap.len() - 1;
+            sift_up_by(heap.as_mut_slice(), last, min_first);
+            assert!(is_heap_by(heap.as_slice(), min_first));
+        }
+
+        let mut popped = Vec::new();
+        while !heap.is_empty() {
+            let last = heap.len() - 1;
+            heap.as_mut_slice().swap(0, last);
+            popped.push(heap.pop().unwrap());
+            // Nothing to sift once the last element is popped
+            sift_down_by(heap.as_mut_slice(), 0, min_first);
+        }
+
+        let mut expected = values;
+        expected.sort();
+        assert_eq!(popped, expected);
+    }
+
+    #[test]
+    fn test_sift_returns_final_index() {
+        let mut arr = Array::from_slice(&[9, 7, 8, 3, 5, 6, 4], 7).unwrap();
+        assert!(is_heap(arr.as_slice()));
+
+        // A decrease at the root lands in a leaf on the larger-child path
+        arr.set(0, 0);
+        assert_eq!(sift_down(arr.as_mut_slice(), 0), Some(5));
+        assert_eq!(arr.as_slice(), &[8, 7, 6, 3, 5, 0, 4]);
+
+        // An increase at a leaf bubbles all the way up
+        arr.set(6, 10);
+        assert_eq!(sift_up(arr.as_mut_slice(), 6), Some(0));
+        assert_eq!(arr.as_slice()[0], 10);
+        assert!(is_heap(arr.as_slice()));
+    }
+}
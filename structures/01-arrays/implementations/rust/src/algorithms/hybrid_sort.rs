@@ -0,0 +1,361 @@
+//! Hybrid sorts: the fast defaults for general-purpose sorting.
+//!
+//! The teaching sorts elsewhere in `algorithms` each have a weak spot. The
+//! two sorts here combine them so that the weak spots never show:
+//!
+//! - `sort_unstable` is an introsort. It runs quicksort with a ninther pivot
+//!   and Hoare partitioning, finishes small partitions with insertion sort,
+//!   and switches to heap sort once the recursion gets deeper than
+//!   2·log₂ n, which caps the worst case at O(n log n). Input that is
+//!   already one ascending or descending run is detected up front and
+//!   finished in O(n).
+//! - `sort` is a Timsort-style stable merge sort. It splits the array into
+//!   natural runs, reversing strictly descending ones, extends short runs
+//!   to a minimum length with binary insertion sort, and merges runs from a
+//!   stack whose lengths grow roughly like the Fibonacci numbers, so merges
+//!   stay balanced. Presorted data, even when it is only presorted in
+//!   stretches, takes far fewer than n log n comparisons.
+
+use super::heap_sort::heap;
+use super::insertion_sort::{binary_insertion_from, insertion};
+use super::merge_sort::merge;
+use super::quick_sort::{choose_pivot, hoare, PivotStrategy};
+use super::sort_observer::SortOps;
+use crate::dynamic_array::DynamicArray;
+
+// Partitions at most this long are finished with insertion sort.
+const INSERTION_SORT_LEN: usize = 16;
+
+// Arrays shorter than this are a single run extended by binary insertion.
+const MIN_MERGE: usize = 64;
+
+// Returns the length of the run at the start of `v`: the longest prefix
+// that never decreases, or the longest strictly decreasing prefix, which is
+// reversed in place. Strictness keeps the reversal stable.
+fn find_run<T, S: SortOps<T>>(v: &mut [T], s: &mut S) -> usize {
+    if v.len() < 2 {
+        return v.len();
+    }
+
+    let mut end = 2;
+    if s.is_less(&v[1], &v[0]) {
+        while end < v.len() && s.is_less(&v[end], &v[end - 1]) {
+            end += 1;
+        }
+        s.reverse(&mut v[..end]);
+    } else {
+        while end < v.len() && !s.is_less(&v[end], &v[end - 1]) {
+            end += 1;
+        }
+    }
+    end
+}
+
+pub(crate) fn introsort<T, S: SortOps<T>>(v: &mut [T], s: &mut S) {
+    if find_run(v, s) == v.len() {
+        return;
+    }
+    let limit = 2 * v.len().ilog2() as usize;
+    introsort_recursive(v, s, limit);
+}
+
+fn introsort_recursive<T, S: SortOps<T>>(mut v: &mut [T], s: &mut S, mut limit: usize) {
+    // Where `v` starts within the slice this call was given
+    let mut base = 0;
+    loop {
+        if v.len() <= INSERTION_SORT_LEN {
+            s.within(base, |s| insertion(v, s));
+            return;
+        }
+        // Too many unbalanced partitions: the pivots are being defeated
+        if limit == 0 {
+            s.within(base, |s| heap(v, s));
+            return;
+        }
+        limit -= 1;
+
+        // The ninther never draws on the generator
+        let pivot = choose_pivot(v, PivotStrategy::Ninther, s, &mut 0);
+        let p = s.within(base, |s| hoare(v, pivot, s));
+
+        // Recurse into the smaller side and loop on the larger one
+        let (less, rest) = std::mem::take(&mut v).split_at_mut(p);
+        let greater = &mut rest[1..];
+        if less.len() < greater.len() {
+            s.within(base, |s| introsort_recursive(less, s, limit));
+            v = greater;
+            base += p + 1;
+        } else {
+            s.within(base + p + 1, |s| introsort_recursive(greater, s, limit));
+            v = less;
+        }
+    }
+}
+
+// A number between MIN_MERGE / 2 and MIN_MERGE such that n divided by it is
+// a power of two or slightly below one, so the final merges are balanced.
+fn min_run_length(mut n: usize) -> usize {
+    let mut remainder = 0;
+    while n >= MIN_MERGE {
+        remainder |= n & 1;
+        n >>= 1;
+    }
+    n + remainder
+}
+
+pub(crate) fn timsort<T: Clone, S: SortOps<T>>(v: &mut [T], s: &mut S) {
+    let n = v.len();
+    if n < 2 {
+        return;
+    }
+
+    let min_run = min_run_length(n);
+    // A left run can be almost the whole array
+    let mut scratch = DynamicArray::with_capacity(n);
+    // (start, len) of the pending runs, left to right
+    let mut runs = DynamicArray::new();
+
+    let mut start = 0;
+    while start < n {
+        let mut len = s.within(start, |s| find_run(&mut v[start..], s));
+        if len < min_run {
+            let end = (start + min_run).min(n);
+            s.within(start, |s| binary_insertion_from(&mut v[start..end], len, s));
+            len = end - start;
+        }
+        runs.push((start, len));
+        start += len;
+
+        collapse(v, &mut runs, &mut scratch, s, false);
+    }
+    collapse(v, &mut runs, &mut scratch, s, true);
+}
+
+// Merges runs until, for the run lengths A, B, C, D from the top of the
+// stack, D > C + B, C > B + A and B > A, or until one run is left when
+// `force` is set. Checking D as well as C is the fix for the invariant bug
+// found in the original Timsort in 2015.
+fn collapse<T: Clone, S: SortOps<T>>(
+    v: &mut [T],
+    runs: &mut DynamicArray<(usize, usize)>,
+    scratch: &mut DynamicArray<T>,
+    s: &mut S,
+    force: bool,
+) {
+    while runs.len() > 1 {
+        let pending = runs.as_mut_slice();
+        let len = |i: usize| pending[i].1;
+        let top = pending.len() - 1;
+
+        let unbalanced = (top >= 2 && len(top - 2) <= len(top - 1) + len(top))
+            || (top >= 3 && len(top - 3) <= len(top - 2) + len(top - 1));
+        let at = if force || unbalanced {
+            // Merge B with the smaller of its neighbours
+            if top >= 2 && len(top - 2) < len(top) {
+                top - 2
+            } else {
+                top - 1
+            }
+        } else if len(top - 1) <= len(top) {
+            top - 1
+        } else {
+            break;
+        };
+
+        let (start, left_len) = pending[at];
+        let right_len = pending[at + 1].1;
+        s.within(start, |s| {
+            merge(
+                &mut v[start..start + left_len + right_len],
+                left_len,
+                scratch,
+                s,
+            )
+        });
+        pending[at].1 = left_len + right_len;
+        // `at` is one of the top two merge points, so at most one run
+        // follows the merged pair
+        if at + 2 < pending.len() {
+            pending[at + 1] = pending[at + 2];
+        }
+        runs.pop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::test_util::random_values;
+    use crate::algorithms::{PartitionScheme, QuickSortConfig, Sortable};
+    use std::cmp::Ordering;
+
+    fn inputs(len: usize) -> Vec<(&'static str, Vec<u32>)> {
+        let n = len as u32;
+        vec![
+            ("random", random_values(len as u64 + 1, len, u32::MAX)),
+            ("few distinct", random_values(len as u64 + 2, len, 4)),
+            ("sorted", (0..n).collect()),
+            ("reversed", (0..n).rev().collect()),
+            ("saw-tooth", (0..n).map(|i| i % 37).collect()),
+            ("reversed saw-tooth", (0..n).map(|i| 36 - i % 37).collect()),
+            ("organ pipe", (0..n).map(|i| i.min(n - i)).collect()),
+            ("all equal", vec![7; len]),
+        ]
+    }
+
+    const LENGTHS: [usize; 10] = [0, 1, 2, 3, 17, 64, 65, 200, 1000, 5000];
+
+    #[test]
+    fn test_sort_unstable_matches_std() {
+        for len in LENGTHS {
+            for (name, values) in inputs(len) {
+                let mut expected = values.clone();
+                expected.sort_unstable();
+
+                let mut arr = DynamicArray::new();
+                arr.extend(values.iter().copied());
+                arr.sort_unstable();
+                assert_eq!(arr.as_slice(), expected.as_slice(), "{name}, len {len}");
+
+                let mut arr = DynamicArray::new();
+                arr.extend(values.iter().copied());
+                arr.sort_unstable_by(|a, b| b.cmp(a));
+                expected.reverse();
+                assert_eq!(arr.as_slice(), expected.as_slice(), "{name}, len {len}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_sort_matches_std_and_is_stable() {
+        for len in LENGTHS {
+            for (name, values) in inputs(len) {
+                // Sort by a coarse key so that stability is observable
+                let records: Vec<(u32, usize)> = values
+                    .iter()
+                    .enumerate()
+                    .map(|(i, &x)| (x % 50, i))
+                    .collect();
+                let mut expected = records.clone();
+                expected.sort_by_key(|&(key, _)| key);
+
+                let mut arr = DynamicArray::new();
+                arr.extend(records.iter().copied());
+                arr.sort_by_key(|&(key, _)| key);
+                assert_eq!(arr.as_slice(), expected.as_slice(), "{name}, len {len}");
+
+                let mut expected = values.clone();
+                expected.sort();
+                let mut arr = DynamicArray::new();
+                arr.extend(values.iter().copied());
+                arr.sort();
+                assert_eq!(arr.as_slice(), expected.as_slice(), "{name}, len {len}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_presorted_input_is_linear() {
+        let n = 10_000;
+        for values in [(0..n).collect::<Vec<u32>>(), (0..n).rev().collect()] {
+            let mut arr = DynamicArray::new();
+            arr.extend(values.iter().copied());
+            let mut comparisons = 0;
+            arr.sort_by(|a, b| {
+                comparisons += 1;
+                a.cmp(b)
+            });
+            assert_eq!(comparisons, n - 1);
+
+            let mut arr = DynamicArray::new();
+            arr.extend(values.iter().copied());
+            let mut comparisons = 0;
+            arr.sort_unstable_by(|a, b| {
+                comparisons += 1;
+                a.cmp(b)
+            });
+            assert_eq!(comparisons, n - 1);
+        }
+    }
+
+    #[test]
+    fn test_sort_exploits_partial_order() {
+        // Four long ascending runs need only a few merges
+        let n = 8000u32;
+        let values: Vec<u32> = (0..n).map(|i| (i % 2000) * 4 + i / 2000).collect();
+
+        let mut arr = DynamicArray::new();
+        arr.extend(values);
+        let mut comparisons = 0;
+        arr.sort_by(|a, b| {
+            comparisons += 1;
+            a.cmp(b)
+        });
+        assert!(arr.as_slice().windows(2).all(|w| w[0] <= w[1]));
+        assert!(comparisons < 3 * n, "{comparisons}");
+    }
+
+    // McIlroy's "killer adversary": values are decided lazily, during the
+    // sort, so that the element the sort treats as a pivot candidate is
+    // always made as small as possible. Any pure quicksort goes quadratic.
+    fn adversarial_comparisons(
+        n: usize,
+        sort: impl FnOnce(&mut DynamicArray<usize>, &mut dyn FnMut(&usize, &usize) -> Ordering),
+    ) -> usize {
+        let gas = n;
+        let mut value = vec![gas; n];
+        let mut solid = 0;
+        let mut candidate = 0;
+        let mut comparisons = 0;
+
+        let mut compare = |&x: &usize, &y: &usize| {
+            comparisons += 1;
+            if value[x] == gas && value[y] == gas {
+                let frozen = if x == candidate { x } else { y };
+                value[frozen] = solid;
+                solid += 1;
+            }
+            if value[x] == gas {
+                candidate = x;
+            } else if value[y] == gas {
+                candidate = y;
+            }
+            value[x].cmp(&value[y])
+        };
+
+        let mut arr = DynamicArray::new();
+        arr.extend(0..n);
+        sort(&mut arr, &mut compare);
+        comparisons
+    }
+
+    #[test]
+    fn test_sort_unstable_survives_adversary() {
+        let n: usize = 4096;
+        let bound = 4 * n * n.ilog2() as usize;
+
+        let introsort = adversarial_comparisons(n, |arr, compare| arr.sort_unstable_by(compare));
+        assert!(introsort <= bound, "{introsort}");
+
+        // The same pivots without the heap sort fallback
+        let config = QuickSortConfig {
+            partition: PartitionScheme::Hoare,
+            pivot: PivotStrategy::Ninther,
+            insertion_threshold: INSERTION_SORT_LEN,
+        };
+        let quicksort =
+            adversarial_comparisons(n, |arr, compare| arr.quick_sort_with_by(&config, compare));
+        assert!(quicksort > bound, "{quicksort}");
+    }
+
+    #[test]
+    fn test_min_run_length() {
+        assert_eq!(min_run_length(10), 10);
+        assert_eq!(min_run_length(64), 32);
+        assert_eq!(min_run_length(65), 33);
+        for n in 64..5000 {
+            let run = min_run_length(n);
+            assert!((MIN_MERGE / 2..=MIN_MERGE).contains(&run), "{n}: {run}");
+        }
+    }
+}
@@ -0,0 +1,209 @@
+//! Insertion sort implementations for arrays.
+//!
+//! Insertion sort grows a sorted prefix one element at a time, shifting the
+//! new element left until it sits after every element not greater than it.
+//! It is O(n²) in general but O(n + inversions), so it is the sort of choice
+//! for small or nearly sorted arrays, and the faster sorts use it for their
+//! smallest partitions.
+//!
+//! Binary insertion sort finds each element's slot in the sorted prefix by
+//! binary search instead of by scanning. That cuts comparisons to
+//! O(n log n), which pays off when comparing is expensive; the element
+//! moves are still O(n²).
+//!
+//! Both sorts are stable and in place.
+//!
+//! For theory and visual explanations, see:
+//! - Insertion Sort Theory: `algorithms/sorting/insertion-sort/`
+
+use super::binary_search::BinarySearchable;
+use super::sort_observer::SortOps;
+use std::cmp::Ordering;
+
+pub(crate) fn insertion<T, S: SortOps<T>>(v: &mut [T], s: &mut S) {
+    for i in 1..v.len() {
+        let mut j = i;
+        while j > 0 && s.compare(&v[j - 1], &v[j]) == Ordering::Greater {
+            s.swap(v, j - 1, j);
+            j -= 1;
+        }
+    }
+}
+
+// Inserts v[sorted..] one at a time into the already sorted v[..sorted].
+pub(crate) fn binary_insertion_from<T, S: SortOps<T>>(v: &mut [T], sorted: usize, s: &mut S) {
+    for i in sorted.max(1)..v.len() {
+        // First slot in the sorted prefix holding an element greater than
+        // v[i], so equal elements stay in front of it
+        let (prefix, rest) = v.split_at(i);
+        let low = prefix.binary_search_insertion_point_by(|elem| match s.compare(elem, &rest[0]) {
+            Ordering::Greater => Ordering::Greater,
+            _ => Ordering::Less,
+        });
+
+        s.within(low, |s| s.rotate_right(&mut v[low..=i], 1));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::test_util::assert_sorts_like_std;
+    use crate::algorithms::Sortable;
+    use crate::core::Array;
+    use crate::dynamic_array::DynamicArray;
+
+    #[test]
+    fn test_sorts_match_std_sort() {
+        assert_sorts_like_std("insertion", 30, 120, |arr| arr.insertion_sort());
+        assert_sorts_like_std("binary insertion", 30, 120, |arr| {
+            arr.binary_insertion_sort()
+        });
+        // Reversing a descending sort must give the ascending order
+        assert_sorts_like_std("binary insertion by", 30, 120, |arr| {
+            arr.binary_insertion_sort_by(|a, b| b.cmp(a));
+            arr.as_mut_slice().reverse();
+        });
+    }
+
+    #[test]
+    fn test_edge_cases() {
+        let mut empty: DynamicArray<i32> = DynamicArray::new();
+        empty.insertion_sort();
+        empty.binary_insertion_sort();
+        assert!(empty.is_empty());
+
+        let mut single = Array::from_slice(&[1], 1).unwrap();
+        single.binary_insertion_sort();
+        assert_eq!(single.as_slice(), &[1]);
+
+        let mut same = Array::from_slice(&[3; 8], 8).unwrap();
+        same.binary_insertion_sort();
+        assert_eq!(same.as_slice(), &[3; 8]);
+    }
+
+    #[test]
+    fn test_sorts_are_stable() {
+        let records = [(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd'), (0, 'e'), (2, 'f')];
+        let expected = [(0, 'e'), (1, 'b'), (1, 'd'), (2, 'a'), (2, 'c'), (2, 'f')];
+
+        let mut arr = Array::from_slice(&records, 6).unwrap();
+        arr.insertion_sort_by_key(|&(key, _)| key);
+        assert_eq!(arr.as_slice(), &expected);
+
+        let mut arr = Array::from_slice(&records, 6).unwrap();
+        arr.binary_insertion_sort_by_key(|&(key, _)| key);
+        assert_eq!(arr.as_slice(), &expected);
+
+        let mut arr = Array::from_slice(&records, 6).unwrap();
+        arr.insertion_sort_by(|a, b| a.0.cmp(&b.0));
+        assert_eq!(arr.as_slice(), &expected);
+
+        // The plain form only has `Ord`, so use a type whose ordering
+        // ignores the tag
+        #[derive(Debug, Clone, Copy)]
+        struct Tagged(i32, char);
+        impl PartialEq for Tagged {
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+        impl Eq for Tagged {}
+        impl PartialOrd for Tagged {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl Ord for Tagged {
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.0.cmp(&other.0)
+            }
+        }
+
+        let mut tagged = DynamicArray::new();
+        tagged.extend(records.iter().map(|&(key, tag)| Tagged(key, tag)));
+        tagged.binary_insertion_sort();
+        let tags: Vec<char> = tagged.as_slice().iter().map(|t| t.1).collect();
+        assert_eq!(tags, vec!['e', 'b', 'd', 'a', 'c', 'f']);
+    }
+
+    #[test]
+    fn test_binary_insertion_uses_fewer_comparisons() {
+        let n = 256;
+        let reversed: Vec<u32> = (0..n).rev().collect();
+
+        let mut arr = DynamicArray::new();
+        arr.extend(reversed.iter().copied());
+        let mut linear = 0;
+        arr.insertion_sort_by(|a, b| {
+            linear += 1;
+            a.cmp(b)
+        });
+
+        let mut arr = DynamicArray::new();
+        arr.extend(reversed.iter().copied());
+        let mut binary = 0;
+        arr.binary_insertion_sort_by(|a, b| {
+            binary += 1;
+            a.cmp(b)
+        });
+
+        // n(n - 1)/2 against at most n·⌈log₂ n⌉
+        assert_eq!(linear as u32, n * (n - 1) / 2);
+        assert!(binary as u32 <= n * 8, "{binary}");
+    }
+
+    #[test]
+    fn test_binary_insertion_is_log_linear_on_duplicates() {
+        use std::cell::Cell;
+
+        // Counts comparisons made through `Ord`, which the plain form uses
+        struct Counted<'a>(u32, &'a Cell<usize>);
+        impl PartialEq for Counted<'_> {
+            fn eq(&self, other: &Self) -> bool {
+                self.cmp(other) == Ordering::Equal
+            }
+        }
+        impl Eq for Counted<'_> {}
+        impl PartialOrd for Counted<'_> {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl Ord for Counted<'_> {
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.1.set(self.1.get() + 1);
+                self.0.cmp(&other.0)
+            }
+        }
+
+        let n = 512;
+        let comparisons = Cell::new(0);
+        let mut arr = DynamicArray::new();
+        arr.extend((0..n).map(|_| Counted(7, &comparisons)));
+        arr.binary_insertion_sort();
+        assert!(comparisons.get() <= n * 9, "{}", comparisons.get());
+
+        let mut by = 0;
+        let mut arr = DynamicArray::new();
+        arr.extend((0..n).map(|i| i % 2));
+        arr.binary_insertion_sort_by(|a, b| {
+            by += 1;
+            a.cmp(b)
+        });
+        assert!(by <= n * 9, "{by}");
+    }
+
+    #[test]
+    fn test_insertion_sort_is_linear_on_sorted_input() {
+        let mut arr = DynamicArray::new();
+        arr.extend(0..500);
+
+        let mut comparisons = 0;
+        arr.insertion_sort_by(|a, b| {
+            comparisons += 1;
+            a.cmp(b)
+        });
+        assert_eq!(comparisons, 499);
+    }
+}
@@ -0,0 +1,217 @@
+//! Interpolation search implementations for arrays.
+//!
+//! This module provides interpolation search for sorted sequences of numeric
+//! keys. Instead of always probing the middle, it estimates where the target
+//! should be from its value, like opening a phone book near the back for
+//! "Smith".
+//!
+//! For theory and visual explanations, see:
+//! - Interpolation Search Theory: `algorithms/searching/interpolation-search/`
+//! - Array-Specific Details: `structures/01-arrays/algorithms/interpolation-search.md`
+
+use super::precondition::{debug_assert_sorted_by, enforce, PreconditionError, PreconditionMode};
+use crate::sequence::Sequence;
+use std::cmp::Ordering;
+
+/// Keys that can be placed on a number line for interpolation.
+pub trait InterpolationKey: Ord {
+    /// Returns the key's position on the number line.
+    ///
+    /// Only used to estimate probe positions, so precision loss for very
+    /// large values slows the search down but never makes it wrong.
+    fn to_f64(&self) -> f64;
+}
+
+macro_rules! impl_interpolation_key {
+    ($($type:ty),*) => {
+        $(
+            impl InterpolationKey for $type {
+                fn to_f64(&self) -> f64 {
+                    *self as f64
+                }
+            }
+        )*
+    };
+}
+
+impl_interpolation_key!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Trait for types that support interpolation search operations.
+/// Requires sorted data for correct operation.
+pub trait InterpolationSearchable<T> {
+    /// Performs interpolation search on a sorted array.
+    ///
+    /// Returns the index of the target if found, or `None` if not found.
+    /// Runs in O(log log n) on uniformly distributed keys and degrades to
+    /// O(n) on heavily skewed ones.
+    ///
+    /// # Requirements
+    /// - The array must be sorted in ascending order
+    ///
+    /// # Panics
+    /// Panics in debug builds if the array is not sorted, following
+    /// `PreconditionMode::DebugAssert`.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::core::Array;
+    /// use arrays::algorithms::InterpolationSearchable;
+    ///
+    /// let arr = Array::from_slice(&[10, 20, 30, 40, 50, 60], 10).unwrap();
+    /// assert_eq!(arr.interpolation_search(&40), Some(3));
+    /// assert_eq!(arr.interpolation_search(&45), None);
+    /// ```
+    fn interpolation_search(&self, target: &T) -> Option<usize>
+    where
+        T: InterpolationKey;
+
+    /// Like `interpolation_search`, but applies `mode` to the sortedness
+    /// check instead of the default debug assertion.
+    ///
+    /// # Errors
+    /// Returns `NotSorted` in `AlwaysCheck` mode if the array is unsorted.
+    fn interpolation_search_checked(
+        &self,
+        target: &T,
+        mode: PreconditionMode,
+    ) -> Result<Option<usize>, PreconditionError>
+    where
+        T: InterpolationKey;
+}
+
+// Every `Sequence` gets interpolation search through this blanket implementation
+impl<T, S> InterpolationSearchable<T> for S
+where
+    S: Sequence<T> + ?Sized,
+{
+    fn interpolation_search(&self, target: &T) -> Option<usize>
+    where
+        T: InterpolationKey,
+    {
+        debug_assert_sorted_by(self, T::cmp);
+        interpolate(self, target)
+    }
+
+    fn interpolation_search_checked(
+        &self,
+        target: &T,
+        mode: PreconditionMode,
+    ) -> Result<Option<usize>, PreconditionError>
+    where
+        T: InterpolationKey,
+    {
+        enforce(self, mode, T::cmp)?;
+        Ok(interpolate(self, target))
+    }
+}
+
+// Interpolation search without the sortedness check.
+pub(crate) fn interpolate<T, S>(seq: &S, target: &T) -> Option<usize>
+where
+    T: InterpolationKey,
+    S: Sequence<T> + ?Sized,
+{
+    if seq.is_empty() {
+        return None;
+    }
+
+    let mut low = 0;
+    let mut high = seq.len() - 1;
+
+    while low <= high {
+        let low_value = seq.get(low)?;
+        let high_value = seq.get(high)?;
+
+        // Outside the remaining value range, so not present
+        if target < low_value || target > high_value {
+            return None;
+        }
+
+        // Every remaining element is equal; avoid dividing by zero
+        if low_value == high_value {
+            return Some(low);
+        }
+
+        let span = high_value.to_f64() - low_value.to_f64();
+        let offset = (target.to_f64() - low_value.to_f64()) / span;
+        let estimate = low + (offset * (high - low) as f64) as usize;
+        let pos = estimate.clamp(low, high);
+
+        match seq.get(pos)?.cmp(target) {
+            Ordering::Equal => return Some(pos),
+            Ordering::Less => low = pos + 1,
+            // pos > low here, since self[low] <= target
+            Ordering::Greater => high = pos - 1,
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::BinarySearchable;
+    use crate::core::Array;
+    use crate::dynamic_array::DynamicArray;
+
+    #[test]
+    fn test_interpolation_search_basic() {
+        let arr = Array::from_slice(&[1, 3, 5, 7, 9, 11, 13], 10).unwrap();
+
+        assert_eq!(arr.interpolation_search(&1), Some(0));
+        assert_eq!(arr.interpolation_search(&7), Some(3));
+        assert_eq!(arr.interpolation_search(&13), Some(6));
+
+        assert_eq!(arr.interpolation_search(&0), None);
+        assert_eq!(arr.interpolation_search(&8), None);
+        assert_eq!(arr.interpolation_search(&14), None);
+    }
+
+    #[test]
+    fn test_interpolation_search_edge_cases() {
+        let empty: Array<i32> = Array::new(5);
+        assert_eq!(empty.interpolation_search(&1), None);
+
+        let single = Array::from_slice(&[42], 1).unwrap();
+        assert_eq!(single.interpolation_search(&42), Some(0));
+        assert_eq!(single.interpolation_search(&41), None);
+
+        let same = Array::from_slice(&[5, 5, 5, 5], 4).unwrap();
+        assert!(same.interpolation_search(&5).is_some());
+        assert_eq!(same.interpolation_search(&6), None);
+    }
+
+    #[test]
+    fn test_skewed_distribution_still_correct() {
+        let mut arr = DynamicArray::new();
+        for i in 0..40u64 {
+            arr.push(1u64 << i);
+        }
+
+        for i in 0..40u64 {
+            assert_eq!(arr.interpolation_search(&(1u64 << i)), Some(i as usize));
+            assert_eq!(arr.interpolation_search(&(3u64 << i)), None);
+        }
+    }
+
+    #[test]
+    fn test_matches_binary_search() {
+        let mut arr = DynamicArray::new();
+        for i in -500i64..500 {
+            arr.push(i * 3);
+        }
+
+        for target in -1600..1600 {
+            assert_eq!(
+                arr.interpolation_search(&target),
+                arr.binary_search(&target)
+            );
+        }
+
+        // Extreme values do not overflow the position estimate
+        let wide = Array::from_slice(&[i64::MIN, -1, 0, 1, i64::MAX], 5).unwrap();
+        assert_eq!(wide.interpolation_search(&i64::MAX), Some(4));
+        assert_eq!(wide.interpolation_search(&1), Some(3));
+    }
+}
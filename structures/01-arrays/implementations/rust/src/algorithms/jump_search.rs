@@ -7,8 +7,9 @@
 //! - Jump Search Theory: `algorithms/searching/jump-search/`
 //! - Array-Specific Details: `structures/01-arrays/algorithms/jump-search.md`
 
-use crate::core::Array;
-use crate::dynamic_array::DynamicArray;
+use super::precondition::{debug_assert_sorted_by, enforce, PreconditionError, PreconditionMode};
+use super::search_trace::SearchTrace;
+use crate::sequence::Sequence;
 use std::cmp::{min, Ordering};
 
 /// Trait for types that support jump search operations.
@@ -22,6 +23,11 @@ pub trait JumpSearchable<T> {
     /// # Requirements
     /// - The array must be sorted in ascending order
     ///
+    /// # Panics
+    /// Panics in debug builds if the array is not sorted, following
+    /// `PreconditionMode::DebugAssert`. The other `Ord`-based searches in
+    /// this trait check the same way.
+    ///
     /// # Examples
     /// ```
     /// use arrays::core::Array;
@@ -52,6 +58,48 @@ pub trait JumpSearchable<T> {
     where
         T: Ord;
 
+    /// Performs jump search using a custom comparison function.
+    ///
+    /// The comparator should return how an element orders relative to the
+    /// target, as in `BinarySearchable::binary_search_by`.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::core::Array;
+    /// use arrays::algorithms::JumpSearchable;
+    ///
+    /// let arr = Array::from_slice(&[(1, 'a'), (4, 'b'), (9, 'c')], 5).unwrap();
+    /// assert_eq!(arr.jump_search_by(|&(key, _)| key.cmp(&4)), Some(1));
+    /// ```
+    fn jump_search_by<F>(&self, f: F) -> Option<usize>
+    where
+        F: FnMut(&T) -> Ordering;
+
+    /// Like `jump_search`, but applies `mode` to the sortedness check
+    /// instead of the default debug assertion.
+    ///
+    /// # Errors
+    /// Returns `NotSorted` in `AlwaysCheck` mode if the array is unsorted.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::core::Array;
+    /// use arrays::algorithms::{JumpSearchable, PreconditionError, PreconditionMode};
+    ///
+    /// let unsorted = Array::from_slice(&[1, 3, 2, 4], 10).unwrap();
+    /// assert_eq!(
+    ///     unsorted.jump_search_checked(&2, PreconditionMode::AlwaysCheck),
+    ///     Err(PreconditionError::NotSorted { first_violation: 2 })
+    /// );
+    /// ```
+    fn jump_search_checked(
+        &self,
+        target: &T,
+        mode: PreconditionMode,
+    ) -> Result<Option<usize>, PreconditionError>
+    where
+        T: Ord;
+
     /// Finds the leftmost (first) occurrence using jump search.
     ///
     /// Useful when the array contains duplicates.
@@ -65,10 +113,78 @@ pub trait JumpSearchable<T> {
     fn jump_search_last(&self, target: &T) -> Option<usize>
     where
         T: Ord;
+
+    /// Like `jump_search_first`, but applies `mode` to the sortedness check,
+    /// see `jump_search_checked`.
+    fn jump_search_first_checked(
+        &self,
+        target: &T,
+        mode: PreconditionMode,
+    ) -> Result<Option<usize>, PreconditionError>
+    where
+        T: Ord;
+
+    /// Like `jump_search_last`, but applies `mode` to the sortedness check,
+    /// see `jump_search_checked`.
+    fn jump_search_last_checked(
+        &self,
+        target: &T,
+        mode: PreconditionMode,
+    ) -> Result<Option<usize>, PreconditionError>
+    where
+        T: Ord;
+
+    /// Like `jump_search`, but records every probe in a `SearchTrace`.
+    ///
+    /// Probes from the jump phase come first, followed by the linear scan
+    /// of the block that may contain the target.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::core::Array;
+    /// use arrays::algorithms::JumpSearchable;
+    ///
+    /// let arr = Array::from_slice(&[1, 3, 5, 7, 9, 11, 13, 15, 17], 10).unwrap();
+    /// let trace = arr.jump_search_traced(&13);
+    /// assert_eq!(trace.result, Some(6));
+    /// assert_eq!(trace.probes, vec![2, 5, 8, 6]);
+    /// ```
+    fn jump_search_traced(&self, target: &T) -> SearchTrace
+    where
+        T: Ord;
+
+    /// Like `jump_search_with_size`, but records every probe in a `SearchTrace`.
+    fn jump_search_with_size_traced(&self, target: &T, jump_size: usize) -> SearchTrace
+    where
+        T: Ord;
+
+    /// Like `jump_search_first`, but records every probe in a `SearchTrace`.
+    ///
+    /// The probes of the backward scan over equal elements follow those of
+    /// the jump search that found the first match.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::core::Array;
+    /// use arrays::algorithms::JumpSearchable;
+    ///
+    /// let arr = Array::from_slice(&[1, 2, 2, 2, 3, 4, 5, 6, 7], 10).unwrap();
+    /// let trace = arr.jump_search_first_traced(&2);
+    /// assert_eq!(trace.result, Some(1));
+    /// assert_eq!(trace.probes, vec![2, 0, 1, 0]);
+    /// ```
+    fn jump_search_first_traced(&self, target: &T) -> SearchTrace
+    where
+        T: Ord;
+
+    /// Like `jump_search_last`, but records every probe in a `SearchTrace`.
+    fn jump_search_last_traced(&self, target: &T) -> SearchTrace
+    where
+        T: Ord;
 }
 
 // Helper function to calculate optimal jump size
-fn optimal_jump_size(n: usize) -> usize {
+pub(crate) fn optimal_jump_size(n: usize) -> usize {
     if n == 0 {
         return 0;
     }
@@ -77,142 +193,327 @@ fn optimal_jump_size(n: usize) -> usize {
     sqrt.max(1) // Ensure at least 1
 }
 
-// Helper macro to implement jump search for both array types
-macro_rules! impl_jump_search {
-    ($type:ty) => {
-        impl<T> JumpSearchable<T> for $type {
-            fn jump_search(&self, target: &T) -> Option<usize>
-            where
-                T: Ord,
-            {
-                let n = self.len();
-                if n == 0 {
-                    return None;
-                }
+// Every `Sequence` gets jump search through this blanket implementation
+impl<T, S> JumpSearchable<T> for S
+where
+    S: Sequence<T> + ?Sized,
+{
+    fn jump_search(&self, target: &T) -> Option<usize>
+    where
+        T: Ord,
+    {
+        let n = self.len();
+        if n == 0 {
+            return None;
+        }
+
+        let jump = optimal_jump_size(n);
+        self.jump_search_with_size(target, jump)
+    }
+
+    fn jump_search_with_size(&self, target: &T, jump_size: usize) -> Option<usize>
+    where
+        T: Ord,
+    {
+        debug_assert_sorted_by(self, T::cmp);
+        jump_with_size(self, target, jump_size)
+    }
+
+    fn jump_search_checked(
+        &self,
+        target: &T,
+        mode: PreconditionMode,
+    ) -> Result<Option<usize>, PreconditionError>
+    where
+        T: Ord,
+    {
+        enforce(self, mode, T::cmp)?;
+        Ok(jump_with_size(self, target, optimal_jump_size(self.len())))
+    }
+
+    fn jump_search_by<F>(&self, mut f: F) -> Option<usize>
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        let n = self.len();
+        if n == 0 {
+            return None;
+        }
+
+        let jump = optimal_jump_size(n);
+        let mut prev = 0;
+        let mut curr = min(jump, n) - 1;
 
-                let jump = optimal_jump_size(n);
-                self.jump_search_with_size(target, jump)
+        // Jump phase: stop at the first block end not less than the target
+        while f(self.get(curr)?) == Ordering::Less {
+            if curr == n - 1 {
+                return None;
             }
+            prev = curr + 1;
+            curr = min(curr + jump, n - 1);
+        }
 
-            fn jump_search_with_size(&self, target: &T, jump_size: usize) -> Option<usize>
-            where
-                T: Ord,
-            {
-                let n = self.len();
-                if n == 0 || jump_size == 0 {
-                    return None;
-                }
+        // Linear search phase within the identified block
+        while prev <= curr {
+            match f(self.get(prev)?) {
+                Ordering::Equal => return Some(prev),
+                Ordering::Greater => return None,
+                Ordering::Less => prev += 1,
+            }
+        }
 
-                // Jump phase: Find the block where element may exist
-                let mut prev = 0;
-                let mut curr = min(jump_size, n) - 1;
+        None
+    }
 
-                // Jump until we find a block where target might be
-                while curr < n && self.get(curr)? < target {
-                    prev = curr + 1;
-                    curr = min(curr + jump_size, n - 1);
+    fn jump_search_first(&self, target: &T) -> Option<usize>
+    where
+        T: Ord,
+    {
+        debug_assert_sorted_by(self, T::cmp);
+        jump_first(self, target)
+    }
 
-                    // If we've reached the end, break
-                    if curr == n - 1 && prev > n - jump_size {
-                        break;
-                    }
-                }
+    fn jump_search_last(&self, target: &T) -> Option<usize>
+    where
+        T: Ord,
+    {
+        debug_assert_sorted_by(self, T::cmp);
+        jump_last(self, target)
+    }
 
-                // Linear search phase: Search within the identified block
-                while prev <= curr && prev < n {
-                    match self.get(prev)?.cmp(target) {
-                        Ordering::Equal => return Some(prev),
-                        Ordering::Greater => return None, // Sorted array, can't find it
-                        Ordering::Less => prev += 1,
-                    }
-                }
+    fn jump_search_first_checked(
+        &self,
+        target: &T,
+        mode: PreconditionMode,
+    ) -> Result<Option<usize>, PreconditionError>
+    where
+        T: Ord,
+    {
+        enforce(self, mode, T::cmp)?;
+        Ok(jump_first(self, target))
+    }
 
-                None
+    fn jump_search_last_checked(
+        &self,
+        target: &T,
+        mode: PreconditionMode,
+    ) -> Result<Option<usize>, PreconditionError>
+    where
+        T: Ord,
+    {
+        enforce(self, mode, T::cmp)?;
+        Ok(jump_last(self, target))
+    }
+
+    fn jump_search_traced(&self, target: &T) -> SearchTrace
+    where
+        T: Ord,
+    {
+        let jump = optimal_jump_size(self.len());
+        self.jump_search_with_size_traced(target, jump)
+    }
+
+    fn jump_search_with_size_traced(&self, target: &T, jump_size: usize) -> SearchTrace
+    where
+        T: Ord,
+    {
+        debug_assert_sorted_by(self, T::cmp);
+        let mut trace = SearchTrace::new();
+        let n = self.len();
+        if n == 0 || jump_size == 0 {
+            return trace.finish(None);
+        }
+
+        // Jump phase: every block end we look at costs one comparison
+        let mut prev = 0;
+        let mut curr = min(jump_size, n) - 1;
+
+        loop {
+            trace.probe(prev, n - 1, curr);
+            match self.get(curr) {
+                Some(elem) if elem < target => {}
+                Some(_) => break,
+                None => return trace.finish(None),
             }
 
-            fn jump_search_first(&self, target: &T) -> Option<usize>
-            where
-                T: Ord,
-            {
-                let n = self.len();
-                if n == 0 {
-                    return None;
-                }
+            prev = curr + 1;
+            curr = min(curr + jump_size, n - 1);
 
-                let jump = optimal_jump_size(n);
-                let mut result = None;
-
-                // Find any occurrence first
-                if let Some(idx) = self.jump_search_with_size(target, jump) {
-                    result = Some(idx);
-
-                    // Now search backwards to find the first occurrence
-                    let mut i = idx;
-                    while i > 0 {
-                        if let Some(elem) = self.get(i - 1) {
-                            if elem == target {
-                                result = Some(i - 1);
-                                i -= 1;
-                            } else {
-                                break;
-                            }
-                        } else {
-                            break;
-                        }
-                    }
-                }
+            if curr == n - 1 && prev > n.saturating_sub(jump_size) {
+                break;
+            }
+        }
 
-                result
+        // Linear search phase within the identified block
+        let block_end = curr;
+        while prev <= block_end && prev < n {
+            trace.probe(prev, block_end, prev);
+            match self.get(prev).map(|elem| elem.cmp(target)) {
+                Some(Ordering::Equal) => return trace.finish(Some(prev)),
+                Some(Ordering::Less) => prev += 1,
+                _ => break,
             }
+        }
 
-            fn jump_search_last(&self, target: &T) -> Option<usize>
-            where
-                T: Ord,
-            {
-                let n = self.len();
-                if n == 0 {
-                    return None;
-                }
+        trace.finish(None)
+    }
 
-                let jump = optimal_jump_size(n);
-                let mut result = None;
-
-                // Find any occurrence first
-                if let Some(idx) = self.jump_search_with_size(target, jump) {
-                    result = Some(idx);
-
-                    // Now search forwards to find the last occurrence
-                    let mut i = idx;
-                    while i < n - 1 {
-                        if let Some(elem) = self.get(i + 1) {
-                            if elem == target {
-                                result = Some(i + 1);
-                                i += 1;
-                            } else {
-                                break;
-                            }
-                        } else {
-                            break;
-                        }
-                    }
-                }
+    fn jump_search_first_traced(&self, target: &T) -> SearchTrace
+    where
+        T: Ord,
+    {
+        let mut trace = self.jump_search_traced(target);
+        let Some(mut first) = trace.result else {
+            return trace;
+        };
+
+        // Scan backwards over the elements equal to the match
+        while first > 0 {
+            trace.probe(0, first - 1, first - 1);
+            if self.get(first - 1) != Some(target) {
+                break;
+            }
+            first -= 1;
+        }
+        trace.finish(Some(first))
+    }
+
+    fn jump_search_last_traced(&self, target: &T) -> SearchTrace
+    where
+        T: Ord,
+    {
+        let mut trace = self.jump_search_traced(target);
+        let Some(mut last) = trace.result else {
+            return trace;
+        };
+
+        // Scan forwards over the elements equal to the match
+        let n = self.len();
+        while last + 1 < n {
+            trace.probe(last + 1, n - 1, last + 1);
+            if self.get(last + 1) != Some(target) {
+                break;
+            }
+            last += 1;
+        }
+        trace.finish(Some(last))
+    }
+}
+
+// Jump search with a fixed block size; `jump_search_with_size` without the
+// sortedness check.
+pub(crate) fn jump_with_size<T: Ord, S: Sequence<T> + ?Sized>(
+    seq: &S,
+    target: &T,
+    jump_size: usize,
+) -> Option<usize> {
+    let n = seq.len();
+    if n == 0 || jump_size == 0 {
+        return None;
+    }
+
+    // Jump phase: Find the block where element may exist
+    let mut prev = 0;
+    let mut curr = min(jump_size, n) - 1;
+
+    // Jump until we find a block where target might be
+    while curr < n && seq.get(curr)? < target {
+        prev = curr + 1;
+        curr = min(curr + jump_size, n - 1);
+
+        // If we've reached the end, break
+        if curr == n - 1 && prev > n.saturating_sub(jump_size) {
+            break;
+        }
+    }
+
+    // Linear search phase: Search within the identified block
+    while prev <= curr && prev < n {
+        match seq.get(prev)?.cmp(target) {
+            Ordering::Equal => return Some(prev),
+            Ordering::Greater => return None, // Sorted array, can't find it
+            Ordering::Less => prev += 1,
+        }
+    }
 
-                result
+    None
+}
+
+// Finds any match with `jump_with_size`, then scans back to the first equal element.
+fn jump_first<T: Ord, S: Sequence<T> + ?Sized>(seq: &S, target: &T) -> Option<usize> {
+    let n = seq.len();
+    if n == 0 {
+        return None;
+    }
+
+    let jump = optimal_jump_size(n);
+    let mut result = None;
+
+    // Find any occurrence first
+    if let Some(idx) = jump_with_size(seq, target, jump) {
+        result = Some(idx);
+
+        // Now search backwards to find the first occurrence
+        let mut i = idx;
+        while i > 0 {
+            if let Some(elem) = seq.get(i - 1) {
+                if elem == target {
+                    result = Some(i - 1);
+                    i -= 1;
+                } else {
+                    break;
+                }
+            } else {
+                break;
             }
         }
-    };
+    }
+
+    result
 }
 
-// Implement for both array types
-impl_jump_search!(Array<T>);
-impl_jump_search!(DynamicArray<T>);
+// Finds any match with `jump_with_size`, then scans forward to the last equal element.
+fn jump_last<T: Ord, S: Sequence<T> + ?Sized>(seq: &S, target: &T) -> Option<usize> {
+    let n = seq.len();
+    if n == 0 {
+        return None;
+    }
+
+    let jump = optimal_jump_size(n);
+    let mut result = None;
+
+    // Find any occurrence first
+    if let Some(idx) = jump_with_size(seq, target, jump) {
+        result = Some(idx);
+
+        // Now search forwards to find the last occurrence
+        let mut i = idx;
+        while i < n - 1 {
+            if let Some(elem) = seq.get(i + 1) {
+                if elem == target {
+                    result = Some(i + 1);
+                    i += 1;
+                } else {
+                    break;
+                }
+            } else {
+                break;
+            }
+        }
+    }
+
+    result
+}
 
 /// Additional jump search utilities
 pub mod utils {
     use super::*;
 
     /// Performs adaptive jump search that adjusts jump size based on comparisons.
-    pub fn adaptive_jump_search<T: Ord>(arr: &Array<T>, target: &T) -> Option<usize> {
+    pub fn adaptive_jump_search<T: Ord, S: Sequence<T> + ?Sized>(
+        arr: &S,
+        target: &T,
+    ) -> Option<usize> {
         let n = arr.len();
         if n == 0 {
             return None;
@@ -237,7 +538,7 @@ pub mod utils {
                     if jump == 1 {
                         return None;
                     }
-                    jump = jump / 2;
+                    jump /= 2;
                     continue;
                 }
             }
@@ -256,18 +557,16 @@ pub mod utils {
         }
     }
 
-    /// Checks if an array is sorted (precondition for jump search).
-    pub fn is_sorted_for_jump<T: Ord>(arr: &Array<T>) -> bool {
-        for i in 1..arr.len() {
-            if arr.get(i - 1) > arr.get(i) {
-                return false;
-            }
-        }
-        true
+    /// Checks if a sequence is sorted (precondition for jump search).
+    pub fn is_sorted_for_jump<T: Ord, S: Sequence<T> + ?Sized>(arr: &S) -> bool {
+        crate::algorithms::precondition::is_sorted_by(arr, T::cmp)
     }
 
-    /// Finds the optimal jump size experimentally for a given array.
-    pub fn find_optimal_jump_size<T: Ord>(arr: &Array<T>, samples: &[T]) -> usize {
+    /// Finds the optimal jump size experimentally for a given sequence.
+    pub fn find_optimal_jump_size<T: Ord, S: Sequence<T> + ?Sized>(
+        arr: &S,
+        samples: &[T],
+    ) -> usize {
         let n = arr.len();
         if n == 0 || samples.is_empty() {
             return optimal_jump_size(n);
@@ -320,6 +619,8 @@ pub mod utils {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::Array;
+    use crate::dynamic_array::DynamicArray;
 
     #[test]
     fn test_jump_search_basic() {
@@ -365,6 +666,20 @@ mod tests {
     }
 
     #[test]
+    fn test_jump_size_larger_than_array_past_the_end() {
+        // A target above every element reaches the end check with
+        // prev == n, which used to compute n - jump_size and underflow
+        let arr = Array::from_slice(&[1, 3, 5, 7, 9, 11, 13, 15], 10).unwrap();
+        assert_eq!(arr.jump_search_with_size(&16, 20), None);
+        assert_eq!(arr.jump_search_with_size(&15, 9), Some(7));
+        assert_eq!(arr.jump_search_with_size_traced(&16, 20).result, None);
+
+        let single = Array::from_slice(&[4], 1).unwrap();
+        assert_eq!(single.jump_search_with_size(&5, 2), None);
+    }
+
+    #[test]
+    #[allow(clippy::manual_range_contains)]
     fn test_jump_search_duplicates() {
         let arr = Array::from_slice(&[1, 2, 2, 2, 3, 4, 5], 10).unwrap();
 
@@ -449,4 +764,67 @@ mod tests {
         assert_eq!(arr.jump_search(&9998), Some(4999));
         assert_eq!(arr.jump_search(&5001), None); // Odd number
     }
+
+    #[test]
+    fn test_jump_search_traced_matches_untraced() {
+        let mut arr = DynamicArray::with_capacity(100);
+        for i in 0..100 {
+            arr.push(i * 2);
+        }
+
+        for target in -1..=200 {
+            let trace = arr.jump_search_traced(&target);
+            assert_eq!(trace.result, arr.jump_search(&target));
+            // At most n/m jumps plus m - 1 linear steps (m = 10)
+            assert!(trace.comparisons <= 20);
+        }
+    }
+
+    #[test]
+    fn test_jump_search_traced_phases() {
+        let arr = Array::from_slice(&[1, 3, 5, 7, 9, 11, 13, 15], 10).unwrap();
+        let trace = arr.jump_search_with_size_traced(&11, 3);
+
+        assert_eq!(trace.result, Some(5));
+        assert_eq!(trace.probes, vec![2, 5, 3, 4, 5]);
+        // Jump phase spans the rest of the array, linear phase a single block
+        assert_eq!((trace.steps[1].low, trace.steps[1].high), (3, 7));
+        assert_eq!((trace.steps[2].low, trace.steps[2].high), (3, 5));
+
+        // Jump size larger than the array behaves like the untraced search
+        assert_eq!(arr.jump_search_with_size_traced(&20, 20).result, None);
+        assert_eq!(arr.jump_search_with_size(&20, 20), None);
+    }
+
+    #[test]
+    fn test_jump_search_first_last_traced() {
+        let arr = Array::from_slice(&[1, 2, 2, 2, 2, 3, 4, 5, 6], 10).unwrap();
+        for target in 0..=7 {
+            let first = arr.jump_search_first_traced(&target);
+            let last = arr.jump_search_last_traced(&target);
+            assert_eq!(first.result, arr.jump_search_first(&target));
+            assert_eq!(last.result, arr.jump_search_last(&target));
+            assert_eq!(first.comparisons, first.probes.len());
+        }
+
+        // The jump search lands on index 2; the scans cover the equal run
+        // plus the element that ends it
+        let first = arr.jump_search_first_traced(&2);
+        assert_eq!(first.probes[first.probes.len() - 2..], [1, 0]);
+        let last = arr.jump_search_last_traced(&2);
+        assert_eq!(last.probes[last.probes.len() - 3..], [3, 4, 5]);
+    }
+
+    #[test]
+    fn test_jump_search_by() {
+        let arr = Array::from_slice(&[1, 3, 5, 7, 9, 11, 13, 15, 17], 10).unwrap();
+
+        for target in 0..19 {
+            let found = arr.jump_search_by(|elem| elem.cmp(&target));
+            assert_eq!(found, arr.jump_search(&target));
+        }
+
+        let empty: Array<i32> = Array::new(3);
+        assert_eq!(empty.jump_search_by(|elem| elem.cmp(&1)), None);
+    }
 }
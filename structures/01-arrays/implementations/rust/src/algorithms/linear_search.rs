@@ -1,14 +1,17 @@
 //! Linear search implementations for arrays.
 //!
-//! This module provides linear search functionality for both fixed-size
-//! arrays (Array<T>) and dynamic arrays (DynamicArray<T>).
+//! This module provides linear search functionality for any `Sequence<T>`,
+//! including fixed-size arrays (Array<T>), dynamic arrays (DynamicArray<T>),
+//! slices, `Vec<T>` and `VecDeque<T>`.
 //!
 //! For theory and visual explanations, see:
 //! - Linear Search Theory: `algorithms/searching/linear-search/`
 //! - Array-Specific Details: `structures/01-arrays/algorithms/linear-search.md`
 
+use super::search_trace::SearchTrace;
 use crate::core::Array;
 use crate::dynamic_array::DynamicArray;
+use crate::sequence::Sequence;
 
 /// Trait for types that support linear search operations.
 pub trait LinearSearchable<T> {
@@ -80,6 +83,29 @@ pub trait LinearSearchable<T> {
     where
         T: PartialEq;
 
+    /// Like `linear_search`, but records every probe in a `SearchTrace`.
+    ///
+    /// Each step's bounds are the part of the array not yet examined.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::core::Array;
+    /// use arrays::algorithms::LinearSearchable;
+    ///
+    /// let arr = Array::from_slice(&[5, 2, 8, 1, 9], 10).unwrap();
+    /// let trace = arr.linear_search_traced(&8);
+    /// assert_eq!(trace.result, Some(2));
+    /// assert_eq!(trace.comparisons, 3);
+    /// ```
+    fn linear_search_traced(&self, target: &T) -> SearchTrace
+    where
+        T: PartialEq;
+
+    /// Like `reverse_linear_search`, but records every probe in a `SearchTrace`.
+    fn reverse_linear_search_traced(&self, target: &T) -> SearchTrace
+    where
+        T: PartialEq;
+
     /// Checks if the array contains the target element.
     ///
     /// # Examples
@@ -99,97 +125,336 @@ pub trait LinearSearchable<T> {
     }
 }
 
-// Helper macro to implement linear search for both array types
-macro_rules! impl_linear_search {
-    ($type:ty) => {
-        impl<T> LinearSearchable<T> for $type {
-            fn linear_search(&self, target: &T) -> Option<usize>
-            where
-                T: PartialEq,
-            {
-                for i in 0..self.len() {
-                    if self.get(i)? == target {
-                        return Some(i);
-                    }
-                }
-                None
+// Every `Sequence` gets linear search through this blanket implementation
+impl<T, S> LinearSearchable<T> for S
+where
+    S: Sequence<T> + ?Sized,
+{
+    fn linear_search(&self, target: &T) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        for i in 0..self.len() {
+            if self.get(i)? == target {
+                return Some(i);
             }
+        }
+        None
+    }
 
-            fn linear_search_if<F>(&self, mut predicate: F) -> Option<usize>
-            where
-                F: FnMut(&T) -> bool,
-            {
-                for i in 0..self.len() {
-                    if predicate(self.get(i)?) {
-                        return Some(i);
-                    }
-                }
-                None
+    fn linear_search_if<F>(&self, mut predicate: F) -> Option<usize>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        for i in 0..self.len() {
+            if predicate(self.get(i)?) {
+                return Some(i);
             }
+        }
+        None
+    }
 
-            fn linear_search_all(&self, target: &T) -> Vec<usize>
-            where
-                T: PartialEq,
-            {
-                let mut indices = Vec::new();
-                for i in 0..self.len() {
-                    if let Some(elem) = self.get(i) {
-                        if elem == target {
-                            indices.push(i);
-                        }
-                    }
+    fn linear_search_all(&self, target: &T) -> Vec<usize>
+    where
+        T: PartialEq,
+    {
+        let mut indices = Vec::new();
+        for i in 0..self.len() {
+            if let Some(elem) = self.get(i) {
+                if elem == target {
+                    indices.push(i);
                 }
-                indices
             }
+        }
+        indices
+    }
 
-            fn reverse_linear_search(&self, target: &T) -> Option<usize>
-            where
-                T: PartialEq,
-            {
-                if self.is_empty() {
-                    return None;
-                }
+    fn reverse_linear_search(&self, target: &T) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        if self.is_empty() {
+            return None;
+        }
 
-                for i in (0..self.len()).rev() {
-                    if self.get(i)? == target {
-                        return Some(i);
-                    }
-                }
-                None
+        for i in (0..self.len()).rev() {
+            if self.get(i)? == target {
+                return Some(i);
             }
         }
+        None
+    }
+
+    fn linear_search_traced(&self, target: &T) -> SearchTrace
+    where
+        T: PartialEq,
+    {
+        let mut trace = SearchTrace::new();
+        let n = self.len();
+
+        for i in 0..n {
+            trace.probe(i, n - 1, i);
+            match self.get(i) {
+                Some(elem) if elem == target => return trace.finish(Some(i)),
+                Some(_) => {}
+                None => break,
+            }
+        }
+        trace.finish(None)
+    }
+
+    fn reverse_linear_search_traced(&self, target: &T) -> SearchTrace
+    where
+        T: PartialEq,
+    {
+        let mut trace = SearchTrace::new();
+
+        for i in (0..self.len()).rev() {
+            trace.probe(0, i, i);
+            match self.get(i) {
+                Some(elem) if elem == target => return trace.finish(Some(i)),
+                Some(_) => {}
+                None => break,
+            }
+        }
+        trace.finish(None)
+    }
+}
+
+/// Trait for arrays that can run a sentinel linear search in place.
+///
+/// The search temporarily writes a clone of the target into the
+/// uninitialized slot just past the last element. The sentinel guarantees
+/// the scan stops, so the loop only compares elements and never checks the
+/// index. The sentinel is dropped again before the search returns, even if
+/// a comparison panics, so the array's length and contents are never
+/// changed.
+///
+/// The loop is only sound if the sentinel is certain to compare equal to the
+/// target, which ordinary `Eq` does not promise, so the element type must
+/// implement the unsafe marker trait [`TrustedEq`].
+pub trait SentinelSearchable<T> {
+    /// Searches for `target` using a sentinel in the spare capacity.
+    ///
+    /// When `len == capacity` there is no spare slot, and the search falls
+    /// back to an ordinary linear search.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::dynamic_array::DynamicArray;
+    /// use arrays::algorithms::SentinelSearchable;
+    ///
+    /// let mut arr = DynamicArray::with_capacity(8);
+    /// arr.extend(["pear", "fig", "kiwi"].map(String::from));
+    /// assert_eq!(arr.sentinel_search(&"fig".to_string()), Some(1));
+    /// assert_eq!(arr.sentinel_search(&"plum".to_string()), None);
+    /// assert_eq!(arr.len(), 3);
+    /// ```
+    fn sentinel_search(&mut self, target: &T) -> Option<usize>
+    where
+        T: TrustedEq;
+}
+
+/// Types whose `==` can be trusted to stop a sentinel scan.
+///
+/// # Safety
+/// Implementors promise that a clone always compares equal to the value
+/// it was cloned from, whatever other values are around. A sentinel search
+/// relies on this to find its sentinel; an `==` that broke the promise
+/// would let the scan run past the end of the allocation.
+///
+/// `clone` and `==` may still panic: the search drops its sentinel on the
+/// way out.
+pub unsafe trait TrustedEq: Clone + Eq {}
+
+macro_rules! impl_trusted_eq {
+    ($($type:ty),*) => {
+        $(
+            // SAFETY: the standard library's equality for these types is
+            // reflexive and clones compare equal to their source.
+            unsafe impl TrustedEq for $type {}
+        )*
     };
 }
 
-// Implement for both array types
-impl_linear_search!(Array<T>);
-impl_linear_search!(DynamicArray<T>);
+impl_trusted_eq!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_trusted_eq!(bool, char, String, &str);
+
+impl<T> SentinelSearchable<T> for Array<T> {
+    fn sentinel_search(&mut self, target: &T) -> Option<usize>
+    where
+        T: TrustedEq,
+    {
+        if self.len() == self.capacity() {
+            return self.linear_search(target);
+        }
+
+        let len = self.len();
+        // SAFETY: len < capacity, so slot `len` exists and is uninitialized.
+        unsafe { sentinel_scan(self.as_mut_ptr(), len, target) }
+    }
+}
+
+impl<T> SentinelSearchable<T> for DynamicArray<T> {
+    fn sentinel_search(&mut self, target: &T) -> Option<usize>
+    where
+        T: TrustedEq,
+    {
+        if self.len() == self.capacity() {
+            return self.linear_search(target);
+        }
+
+        let len = self.len();
+        // SAFETY: len < capacity, so slot `len` exists and is uninitialized.
+        unsafe { sentinel_scan(self.as_mut_ptr(), len, target) }
+    }
+}
+
+// Drops the sentinel when the scan ends, whether it returns or unwinds.
+struct SentinelGuard<T> {
+    slot: *mut T,
+}
+
+impl<T> Drop for SentinelGuard<T> {
+    fn drop(&mut self) {
+        // SAFETY: the guard is only created once the sentinel is written,
+        // and nothing else reads or drops that slot.
+        unsafe { self.slot.drop_in_place() }
+    }
+}
+
+/// Scans `len` elements starting at `base` with a sentinel at `base + len`.
+///
+/// `TrustedEq` guarantees the scan stops at the sentinel at the latest.
+///
+/// # Safety
+/// `base` must point to `len` initialized elements followed by at least one
+/// writable, uninitialized slot, and nothing else may access that memory
+/// for the duration of the call.
+unsafe fn sentinel_scan<T: TrustedEq>(base: *mut T, len: usize, target: &T) -> Option<usize> {
+    // Cloning first means a panicking `clone` leaves the slot untouched
+    let sentinel = target.clone();
+    base.add(len).write(sentinel);
+    let _guard = SentinelGuard {
+        slot: base.add(len),
+    };
+
+    let mut i = 0;
+    while *base.add(i) != *target {
+        i += 1;
+    }
+
+    if i < len {
+        Some(i)
+    } else {
+        None
+    }
+}
+
+/// Primitive element types with a chunked linear search fast path.
+///
+/// Comparing plain integers has no side effects, so the search can compare a
+/// whole chunk without stopping at the first match. Loops like that compile
+/// to SIMD compares on stable Rust. This trait is sealed.
+pub trait ChunkedElement: Copy + PartialEq + private::Sealed {
+    /// Elements compared per chunk: two 64-byte cache lines.
+    const LANES: usize = 128 / std::mem::size_of::<Self>();
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+macro_rules! impl_chunked_element {
+    ($($type:ty),*) => {
+        $(
+            impl private::Sealed for $type {}
+            impl ChunkedElement for $type {}
+        )*
+    };
+}
+
+impl_chunked_element!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+/// Trait for sequences of primitive integers that support a chunked linear search.
+pub trait FastLinearSearchable<T: ChunkedElement> {
+    /// Linear search that scans fixed-width chunks instead of single elements.
+    ///
+    /// Each chunk is tested with a branch-free "any equal" fold that the
+    /// compiler vectorizes; only a chunk known to contain the target is
+    /// scanned element by element. Returns the same index as `linear_search`.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::dynamic_array::DynamicArray;
+    /// use arrays::algorithms::FastLinearSearchable;
+    ///
+    /// let mut bytes = DynamicArray::new();
+    /// bytes.extend(b"hello, world".iter().copied());
+    /// assert_eq!(bytes.linear_search_fast(&b'w'), Some(7));
+    /// assert_eq!(bytes.linear_search_fast(&b'z'), None);
+    /// ```
+    fn linear_search_fast(&self, target: &T) -> Option<usize>;
+}
+
+impl<T, S> FastLinearSearchable<T> for S
+where
+    T: ChunkedElement,
+    S: Sequence<T> + ?Sized,
+{
+    fn linear_search_fast(&self, target: &T) -> Option<usize> {
+        match self.as_slice() {
+            Some(slice) => chunked_position(slice, *target),
+            // Non-contiguous storage cannot be scanned in chunks
+            None => self.linear_search(target),
+        }
+    }
+}
+
+fn chunked_position<T: ChunkedElement>(slice: &[T], target: T) -> Option<usize> {
+    let mut chunks = slice.chunks_exact(T::LANES);
+    let mut offset = 0;
+
+    for chunk in &mut chunks {
+        // `|` instead of `||` keeps the fold free of early exits
+        let hit = chunk
+            .iter()
+            .fold(false, |hit, &elem| hit | (elem == target));
+        if hit {
+            return chunk
+                .iter()
+                .position(|&elem| elem == target)
+                .map(|i| offset + i);
+        }
+        offset += T::LANES;
+    }
+
+    chunks
+        .remainder()
+        .iter()
+        .position(|&elem| elem == target)
+        .map(|i| offset + i)
+}
 
 /// Additional linear search utilities
 pub mod utils {
     use super::*;
 
-    /// Sentinel linear search - eliminates bounds checking
-    /// For demonstration purposes only - requires unsafe manipulation
-    pub fn sentinel_linear_search<T: PartialEq + Clone>(
-        arr: &Array<T>,
-        target: &T,
-    ) -> Option<usize> {
-        // Since we can't modify Array internals safely,
-        // we'll demonstrate the concept with regular linear search
-        // In a real implementation, you'd need access to raw array memory
-
-        // Conceptual implementation:
-        // 1. Place target at end of array (sentinel)
-        // 2. Search without bounds checking
-        // 3. Check if found before sentinel position
-
-        // For now, use regular linear search
-        arr.linear_search(target)
+    /// Sentinel linear search - eliminates the bounds check from the loop.
+    ///
+    /// Writes a clone of `target` into the spare slot after the last element,
+    /// so the scan is guaranteed to stop without testing the index. See
+    /// [`SentinelSearchable`] for the requirements on the array.
+    pub fn sentinel_linear_search<T, A>(arr: &mut A, target: &T) -> Option<usize>
+    where
+        T: TrustedEq,
+        A: SentinelSearchable<T> + ?Sized,
+    {
+        arr.sentinel_search(target)
     }
 
-    /// Count occurrences of target in array
-    pub fn count_occurrences<T: PartialEq>(arr: &Array<T>, target: &T) -> usize {
+    /// Count occurrences of target in a sequence
+    pub fn count_occurrences<T: PartialEq, S: Sequence<T> + ?Sized>(arr: &S, target: &T) -> usize {
         let mut count = 0;
         for i in 0..arr.len() {
             if let Some(elem) = arr.get(i) {
@@ -201,8 +466,8 @@ pub mod utils {
         count
     }
 
-    /// Find minimum element in array
-    pub fn find_min<T: Ord>(arr: &Array<T>) -> Option<(usize, &T)> {
+    /// Find minimum element in a sequence
+    pub fn find_min<T: Ord, S: Sequence<T> + ?Sized>(arr: &S) -> Option<(usize, &T)> {
         if arr.is_empty() {
             return None;
         }
@@ -222,8 +487,8 @@ pub mod utils {
         Some((min_idx, min_val))
     }
 
-    /// Find maximum element in array
-    pub fn find_max<T: Ord>(arr: &Array<T>) -> Option<(usize, &T)> {
+    /// Find maximum element in a sequence
+    pub fn find_max<T: Ord, S: Sequence<T> + ?Sized>(arr: &S) -> Option<(usize, &T)> {
         if arr.is_empty() {
             return None;
         }
@@ -378,11 +643,87 @@ mod tests {
 
     #[test]
     fn test_sentinel_search() {
-        let arr = Array::<i32>::from_slice(&[3, 1, 4, 1, 5], 10).unwrap();
+        let mut arr = Array::<i32>::from_slice(&[3, 1, 4, 1, 5], 10).unwrap();
+
+        assert_eq!(utils::sentinel_linear_search(&mut arr, &4), Some(2));
+        assert_eq!(utils::sentinel_linear_search(&mut arr, &5), Some(4));
+        assert_eq!(utils::sentinel_linear_search(&mut arr, &6), None);
+        assert_eq!(arr.len(), 5);
+    }
+
+    #[test]
+    fn test_sentinel_search_full_array() {
+        // No spare slot, falls back to the checked loop
+        let mut arr = Array::from_slice(&[3, 1, 4], 3).unwrap();
+        assert_eq!(arr.sentinel_search(&4), Some(2));
+        assert_eq!(arr.sentinel_search(&7), None);
+
+        // A full dynamic array does not grow to make room for the sentinel
+        let mut dynamic = DynamicArray::with_capacity(3);
+        dynamic.extend([3, 1, 4]);
+        assert_eq!(dynamic.sentinel_search(&4), Some(2));
+        assert_eq!(dynamic.sentinel_search(&7), None);
+        assert_eq!(dynamic.capacity(), 3);
+
+        let mut empty: DynamicArray<i32> = DynamicArray::new();
+        assert_eq!(empty.sentinel_search(&1), None);
+    }
+
+    #[test]
+    fn test_sentinel_search_drops_its_sentinel() {
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+        use std::rc::Rc;
+
+        // Counts live clones through the `Rc`, and panics when 13 is compared
+        #[derive(Clone)]
+        struct Tracked {
+            value: u32,
+            _live: Rc<()>,
+        }
+
+        impl PartialEq for Tracked {
+            fn eq(&self, other: &Self) -> bool {
+                assert_ne!(self.value, 13, "unlucky comparison");
+                self.value == other.value
+            }
+        }
+
+        impl Eq for Tracked {}
+
+        // SAFETY: clones keep the value `eq` compares.
+        unsafe impl TrustedEq for Tracked {}
+
+        let token = Rc::new(());
+        let mut arr = DynamicArray::with_capacity(8);
+        let tracked = |value| Tracked {
+            value,
+            _live: Rc::clone(&token),
+        };
+        arr.extend([4, 13, 7].map(tracked));
+
+        assert_eq!(arr.sentinel_search(&tracked(4)), Some(0));
+        assert_eq!(Rc::strong_count(&token), 4);
+
+        let missing = tracked(9);
+        let search = catch_unwind(AssertUnwindSafe(|| arr.sentinel_search(&missing)));
+        assert!(search.is_err());
+        assert_eq!(Rc::strong_count(&token), 5);
+        assert_eq!(arr.len(), 3);
+    }
+
+    #[test]
+    fn test_sentinel_search_matches_linear_search() {
+        let mut bytes = DynamicArray::new();
+        bytes.extend(*b"sentinel");
+        for target in 0u8..=255 {
+            assert_eq!(bytes.sentinel_search(&target), bytes.linear_search(&target));
+        }
 
-        assert_eq!(utils::sentinel_linear_search(&arr, &4), Some(2));
-        assert_eq!(utils::sentinel_linear_search(&arr, &5), Some(4));
-        assert_eq!(utils::sentinel_linear_search(&arr, &6), None);
+        let mut wide = Array::<i64>::from_slice(&[-3, i64::MAX, 0, i64::MIN], 8).unwrap();
+        for target in [-3, i64::MAX, 0, i64::MIN, 1] {
+            assert_eq!(wide.sentinel_search(&target), wide.linear_search(&target));
+        }
+        assert_eq!(wide.as_slice(), &[-3, i64::MAX, 0, i64::MIN]);
     }
 
     #[test]
@@ -402,4 +743,95 @@ mod tests {
         // Element not in array
         assert_eq!(arr.linear_search(&10000), None);
     }
+
+    #[test]
+    fn test_linear_search_traced() {
+        let arr = Array::from_slice(&[3, 1, 4, 1, 5, 9, 2, 6], 10).unwrap();
+
+        for target in 0..10 {
+            let trace = arr.linear_search_traced(&target);
+            assert_eq!(trace.result, arr.linear_search(&target));
+        }
+
+        let hit = arr.linear_search_traced(&5);
+        assert_eq!(hit.probes, vec![0, 1, 2, 3, 4]);
+        assert_eq!(hit.steps[2].low, 2);
+        assert_eq!(hit.steps[2].high, 7);
+
+        // A miss examines every element exactly once
+        let miss = arr.linear_search_traced(&7);
+        assert_eq!(miss.result, None);
+        assert_eq!(miss.comparisons, arr.len());
+    }
+
+    #[test]
+    fn test_reverse_linear_search_traced() {
+        let arr = Array::from_slice(&[1, 2, 3, 2, 4, 2, 5], 10).unwrap();
+
+        let trace = arr.reverse_linear_search_traced(&2);
+        assert_eq!(trace.result, Some(5));
+        assert_eq!(trace.probes, vec![6, 5]);
+        assert_eq!((trace.steps[1].low, trace.steps[1].high), (0, 5));
+
+        let empty: Array<i32> = Array::new(3);
+        assert_eq!(empty.reverse_linear_search_traced(&1).comparisons, 0);
+    }
+
+    #[test]
+    fn test_linear_search_fast_matches_linear_search() {
+        let mut bytes = DynamicArray::new();
+        let mut ints = DynamicArray::new();
+        let mut longs = DynamicArray::new();
+        for i in 0..1000u32 {
+            bytes.push((i * 7 % 251) as u8);
+            ints.push((i * 7919 % 1013) as i32 - 500);
+            longs.push(u64::from(i) * 3);
+        }
+
+        for target in 0..=u8::MAX {
+            assert_eq!(
+                bytes.linear_search_fast(&target),
+                bytes.linear_search(&target)
+            );
+        }
+        for target in -600..600 {
+            assert_eq!(
+                ints.linear_search_fast(&target),
+                ints.linear_search(&target)
+            );
+        }
+        for target in 2990..3010 {
+            assert_eq!(
+                longs.linear_search_fast(&target),
+                longs.linear_search(&target)
+            );
+        }
+    }
+
+    #[test]
+    fn test_linear_search_fast_chunk_boundaries() {
+        // u64 chunks hold 16 elements, check hits on either side of each edge
+        for len in [0, 1, 15, 16, 17, 31, 32, 33, 100] {
+            let mut values: Vec<u64> = vec![0; len];
+            for position in 0..len {
+                values[position] = 7;
+                let arr = Array::from_slice(&values, len.max(1)).unwrap();
+                assert_eq!(arr.linear_search_fast(&7), Some(position));
+                values[position] = 0;
+            }
+
+            let arr = Array::from_slice(&values, len.max(1)).unwrap();
+            assert_eq!(arr.linear_search_fast(&7), None);
+        }
+    }
+
+    #[test]
+    fn test_linear_search_fast_returns_first_occurrence() {
+        let arr = Array::from_slice(&[9i16, 4, 4, 9, 4], 10).unwrap();
+        assert_eq!(arr.linear_search_fast(&4), Some(1));
+
+        // VecDeque has no contiguous slice and takes the plain path
+        let deque: std::collections::VecDeque<i16> = arr.as_slice().iter().copied().collect();
+        assert_eq!(deque.linear_search_fast(&9), Some(0));
+    }
 }
@@ -0,0 +1,387 @@
+//! Search in sorted two-dimensional grids.
+//!
+//! A `MatrixView` reads a flat `Array`, `DynamicArray` or any other
+//! `Sequence` as a `rows × cols` grid in row-major order. Two kinds of
+//! sorted grid are supported:
+//!
+//! - Row- and column-sorted: every row and every column is ascending, but a
+//!   row may start below the previous row's end. Staircase search walks from
+//!   the top-right corner in O(rows + cols).
+//! - Fully sorted: the row-major order is ascending, so the grid is just a
+//!   sorted array. Flattened binary search runs in O(log(rows · cols)).
+//!
+//! The traced variants return a `SearchTrace` so the two bounds can be
+//! compared directly.
+
+use super::binary_search::BinarySearchable;
+use super::search_trace::SearchTrace;
+use crate::sequence::Sequence;
+use std::cmp::Ordering;
+use std::fmt;
+use std::marker::PhantomData;
+
+/// A sequence viewed as a `rows × cols` matrix in row-major order.
+///
+/// The view itself implements `Sequence` over the flat elements, so every
+/// one-dimensional search also works on it.
+///
+/// # Examples
+/// ```
+/// use arrays::core::Array;
+/// use arrays::algorithms::MatrixView;
+///
+/// let data = Array::from_slice(&[1, 4, 7, 2, 5, 8, 3, 6, 9], 9).unwrap();
+/// let matrix = MatrixView::new(&data, 3, 3).unwrap();
+///
+/// assert_eq!(matrix.get(1, 2), Some(&8));
+/// assert_eq!(matrix.staircase_search(&6), Some((2, 1)));
+/// ```
+pub struct MatrixView<'a, T, S: ?Sized> {
+    data: &'a S,
+    rows: usize,
+    cols: usize,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<'a, T, S> MatrixView<'a, T, S>
+where
+    S: Sequence<T> + ?Sized,
+{
+    /// Creates a `rows × cols` view over `data`.
+    ///
+    /// Returns `None` if `data` does not hold exactly `rows * cols` elements.
+    pub fn new(data: &'a S, rows: usize, cols: usize) -> Option<Self> {
+        if rows.checked_mul(cols)? != data.len() {
+            return None;
+        }
+
+        Some(MatrixView {
+            data,
+            rows,
+            cols,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Returns the number of rows.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns.
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns the element at `(row, col)`, or `None` if out of bounds.
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        if row >= self.rows || col >= self.cols {
+            return None;
+        }
+        self.data.get(self.flat_index(row, col))
+    }
+
+    /// Converts a `(row, col)` position to its row-major index.
+    pub fn flat_index(&self, row: usize, col: usize) -> usize {
+        row * self.cols + col
+    }
+
+    /// Converts a row-major index to its `(row, col)` position.
+    ///
+    /// Returns `None` if `index` is out of bounds, which includes every
+    /// index of a view with no columns. Use this to read the `result` and
+    /// `probes` of a traced search.
+    pub fn position(&self, index: usize) -> Option<(usize, usize)> {
+        if index >= self.data.len() {
+            return None;
+        }
+        Some((index / self.cols, index % self.cols))
+    }
+
+    /// Searches a matrix whose rows and columns are each sorted ascending.
+    ///
+    /// Starts in the top-right corner and steps left when the element is
+    /// too large or down when it is too small, so each comparison discards a
+    /// whole column or row. At most `rows + cols - 1` comparisons.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::dynamic_array::DynamicArray;
+    /// use arrays::algorithms::MatrixView;
+    ///
+    /// let mut data = DynamicArray::new();
+    /// data.extend([1, 4, 7, 11, 2, 5, 8, 12, 3, 6, 9, 16]);
+    /// let matrix = MatrixView::new(&data, 3, 4).unwrap();
+    ///
+    /// assert_eq!(matrix.staircase_search(&5), Some((1, 1)));
+    /// assert_eq!(matrix.staircase_search(&10), None);
+    /// ```
+    pub fn staircase_search(&self, target: &T) -> Option<(usize, usize)>
+    where
+        T: Ord,
+    {
+        if self.cols == 0 {
+            return None;
+        }
+
+        let mut row = 0;
+        let mut col = self.cols - 1;
+
+        while row < self.rows {
+            match self.get(row, col)?.cmp(target) {
+                Ordering::Equal => return Some((row, col)),
+                Ordering::Less => row += 1,
+                Ordering::Greater => {
+                    if col == 0 {
+                        break;
+                    }
+                    col -= 1;
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Like `staircase_search`, but records every probe in a `SearchTrace`.
+    ///
+    /// `result` and `probes` are row-major indices, see `position`. Each
+    /// step's `low` and `high` are the row-major indices of the candidate
+    /// region's top-left and bottom-right corners.
+    pub fn staircase_search_traced(&self, target: &T) -> SearchTrace
+    where
+        T: Ord,
+    {
+        let mut trace = SearchTrace::new();
+        if self.cols == 0 {
+            return trace.finish(None);
+        }
+
+        let mut row = 0;
+        let mut col = self.cols - 1;
+
+        while row < self.rows {
+            let index = self.flat_index(row, col);
+            let corner = self.flat_index(self.rows - 1, col);
+            trace.probe(self.flat_index(row, 0), corner, index);
+
+            match self.data.get(index).map(|elem| elem.cmp(target)) {
+                Some(Ordering::Equal) => return trace.finish(Some(index)),
+                Some(Ordering::Less) => row += 1,
+                Some(Ordering::Greater) => {
+                    if col == 0 {
+                        break;
+                    }
+                    col -= 1;
+                }
+                None => break,
+            }
+        }
+
+        trace.finish(None)
+    }
+
+    /// Searches a matrix whose row-major order is sorted ascending, i.e.
+    /// each row starts after the previous one ends.
+    ///
+    /// Binary search over the flattened index, in O(log(rows · cols)).
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::core::Array;
+    /// use arrays::algorithms::MatrixView;
+    ///
+    /// let data = Array::from_slice(&[1, 3, 5, 7, 10, 11, 16, 20, 23, 30, 34, 60], 12).unwrap();
+    /// let matrix = MatrixView::new(&data, 3, 4).unwrap();
+    ///
+    /// assert_eq!(matrix.flat_search(&16), Some((1, 2)));
+    /// assert_eq!(matrix.flat_search(&13), None);
+    /// ```
+    pub fn flat_search(&self, target: &T) -> Option<(usize, usize)>
+    where
+        T: Ord,
+    {
+        self.binary_search(target)
+            .and_then(|index| self.position(index))
+    }
+
+    /// Like `flat_search`, but records every probe in a `SearchTrace`.
+    ///
+    /// `result` and `probes` are row-major indices, see `position`.
+    pub fn flat_search_traced(&self, target: &T) -> SearchTrace
+    where
+        T: Ord,
+    {
+        self.binary_search_traced(target)
+    }
+}
+
+impl<T, S> Sequence<T> for MatrixView<'_, T, S>
+where
+    S: Sequence<T> + ?Sized,
+{
+    fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    fn get(&self, index: usize) -> Option<&T> {
+        self.data.get(index)
+    }
+
+    fn as_slice(&self) -> Option<&[T]> {
+        self.data.as_slice()
+    }
+}
+
+impl<T, S: ?Sized> fmt::Debug for MatrixView<'_, T, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MatrixView")
+            .field("rows", &self.rows)
+            .field("cols", &self.cols)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Array;
+    use crate::dynamic_array::DynamicArray;
+
+    fn grid(rows: usize, cols: usize, cell: impl Fn(usize, usize) -> i32) -> DynamicArray<i32> {
+        let mut data = DynamicArray::with_capacity(rows * cols);
+        for row in 0..rows {
+            for col in 0..cols {
+                data.push(cell(row, col));
+            }
+        }
+        data
+    }
+
+    #[test]
+    fn test_new_checks_dimensions() {
+        let data = Array::from_slice(&[1, 2, 3, 4, 5, 6], 6).unwrap();
+
+        assert!(MatrixView::new(&data, 2, 3).is_some());
+        assert!(MatrixView::new(&data, 3, 2).is_some());
+        assert!(MatrixView::new(&data, 2, 2).is_none());
+        assert!(MatrixView::new(&data, usize::MAX, 2).is_none());
+
+        let matrix = MatrixView::new(&data, 2, 3).unwrap();
+        assert_eq!(matrix.get(1, 0), Some(&4));
+        assert_eq!(matrix.get(2, 0), None);
+        assert_eq!(matrix.get(0, 3), None);
+        assert_eq!(matrix.position(5), Some((1, 2)));
+        assert_eq!(matrix.position(6), None);
+        assert_eq!(matrix.flat_index(1, 2), 5);
+    }
+
+    #[test]
+    fn test_empty_matrices() {
+        let empty: DynamicArray<i32> = DynamicArray::new();
+
+        for (rows, cols) in [(0, 0), (0, 5), (5, 0)] {
+            let matrix = MatrixView::new(&empty, rows, cols).unwrap();
+            assert_eq!(matrix.staircase_search(&1), None);
+            assert_eq!(matrix.flat_search(&1), None);
+            assert_eq!(matrix.staircase_search_traced(&1).comparisons, 0);
+            assert_eq!(matrix.position(0), None);
+        }
+    }
+
+    #[test]
+    fn test_staircase_search_finds_every_element() {
+        // Rows and columns ascend, but rows overlap in value
+        let data = grid(5, 7, |row, col| (row * 3 + col * 5) as i32);
+        let matrix = MatrixView::new(&data, 5, 7).unwrap();
+
+        for row in 0..5 {
+            for col in 0..7 {
+                let value = matrix.get(row, col).unwrap();
+                let (r, c) = matrix.staircase_search(value).unwrap();
+                assert_eq!(matrix.get(r, c), Some(value));
+            }
+        }
+
+        assert_eq!(matrix.staircase_search(&-1), None);
+        assert_eq!(matrix.staircase_search(&1), None);
+        assert_eq!(matrix.staircase_search(&100), None);
+    }
+
+    #[test]
+    fn test_flat_search_finds_every_element() {
+        let data = grid(4, 6, |row, col| (row * 12 + col * 2) as i32);
+        let matrix = MatrixView::new(&data, 4, 6).unwrap();
+
+        for row in 0..4 {
+            for col in 0..6 {
+                let value = matrix.get(row, col).unwrap();
+                assert_eq!(matrix.flat_search(value), Some((row, col)));
+                assert_eq!(matrix.flat_search(&(value + 1)), None);
+            }
+        }
+        assert_eq!(matrix.flat_search(&-1), None);
+    }
+
+    #[test]
+    fn test_traced_results_match_untraced() {
+        let data = grid(6, 6, |row, col| (row * 6 + col) as i32);
+        let matrix = MatrixView::new(&data, 6, 6).unwrap();
+
+        for target in -1..40 {
+            let staircase = matrix.staircase_search_traced(&target);
+            let flat = matrix.flat_search_traced(&target);
+
+            assert_eq!(
+                staircase.result.and_then(|i| matrix.position(i)),
+                matrix.staircase_search(&target)
+            );
+            assert_eq!(
+                flat.result.and_then(|i| matrix.position(i)),
+                matrix.flat_search(&target)
+            );
+
+            for step in &staircase.steps {
+                assert!(step.low <= step.index && step.index <= step.high);
+            }
+        }
+    }
+
+    #[test]
+    fn test_comparison_bounds() {
+        let (rows, cols) = (100, 100);
+        let data = grid(rows, cols, |row, col| (row * cols + col) as i32);
+        let matrix = MatrixView::new(&data, rows, cols).unwrap();
+
+        let mut worst_staircase = 0;
+        let mut worst_flat = 0;
+        for target in (-1..(rows * cols) as i32 + 1).step_by(7) {
+            worst_staircase =
+                worst_staircase.max(matrix.staircase_search_traced(&target).comparisons);
+            worst_flat = worst_flat.max(matrix.flat_search_traced(&target).comparisons);
+        }
+
+        // O(rows + cols) against O(log(rows * cols))
+        assert!(worst_staircase < rows + cols);
+        assert!(worst_flat <= 14);
+        assert!(worst_staircase > 10 * worst_flat);
+
+        // The bottom-left corner is the staircase worst case
+        let corner = matrix.get(rows - 1, 0).unwrap();
+        assert_eq!(
+            matrix.staircase_search_traced(corner).comparisons,
+            rows + cols - 1
+        );
+    }
+
+    #[test]
+    fn test_view_works_with_one_dimensional_searches() {
+        use crate::algorithms::ExponentialSearchable;
+
+        let data = Array::from_slice(&[1, 3, 5, 7, 10, 11, 16, 20, 23], 9).unwrap();
+        let matrix = MatrixView::new(&data, 3, 3).unwrap();
+
+        let index = matrix.exponential_search(&20).unwrap();
+        assert_eq!(matrix.position(index), Some((2, 1)));
+    }
+}
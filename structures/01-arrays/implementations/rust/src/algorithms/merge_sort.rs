@@ -0,0 +1,353 @@
+//! Merge sort implementations for arrays.
+//!
+//! Merge sort splits the array into halves, sorts them, and merges the
+//! sorted halves. It is O(n log n) in every case and stable, which makes it
+//! the usual choice when equal elements must keep their order.
+//!
+//! Three variants are provided:
+//! - Top-down merge sort recurses on halves.
+//! - Bottom-up merge sort merges runs of width 1, 2, 4, … in a loop, with no
+//!   recursion at all.
+//! - In-place merge sort merges without a buffer by rotating blocks into
+//!   place. It needs neither extra memory nor `Clone`, at the cost of
+//!   O(n log² n) time; this suits a fixed `Array`, which cannot grow a
+//!   buffer alongside its elements.
+//!
+//! The buffered variants allocate a single scratch `DynamicArray` up front
+//! and reuse it for every merge. Merges copy the left run into it and merge
+//! back into the array, so the buffer only ever holds half the elements.
+//! Both skip a merge when the two runs are already in order, so sorted input
+//! takes O(n) comparisons.
+
+use super::sort_observer::SortOps;
+use crate::dynamic_array::DynamicArray;
+use crate::sequence::Sequence;
+use std::cmp::Ordering;
+
+/// Merges two sorted sequences into a new sorted array.
+///
+/// The merge is stable: elements from `a` come before equal elements from
+/// `b`.
+///
+/// # Examples
+/// ```
+/// use arrays::core::Array;
+/// use arrays::algorithms::merge_sort::merge_sorted;
+///
+/// let a = Array::from_slice(&[1, 4, 9], 3).unwrap();
+/// let merged = merge_sorted(&a, &[2, 4, 10][..]);
+/// assert_eq!(merged.as_slice(), &[1, 2, 4, 4, 9, 10]);
+/// ```
+pub fn merge_sorted<T, A, B>(a: &A, b: &B) -> DynamicArray<T>
+where
+    T: Ord + Clone,
+    A: Sequence<T> + ?Sized,
+    B: Sequence<T> + ?Sized,
+{
+    merge_sorted_by(a, b, T::cmp)
+}
+
+/// Merges two sequences sorted by `compare` into a new sorted array.
+pub fn merge_sorted_by<T, A, B, F>(a: &A, b: &B, mut compare: F) -> DynamicArray<T>
+where
+    T: Clone,
+    A: Sequence<T> + ?Sized,
+    B: Sequence<T> + ?Sized,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let mut merged = DynamicArray::with_capacity(a.len() + b.len());
+    let (mut i, mut j) = (0, 0);
+    while let (Some(x), Some(y)) = (a.get(i), b.get(j)) {
+        if compare(y, x) == Ordering::Less {
+            merged.push(y.clone());
+            j += 1;
+        } else {
+            merged.push(x.clone());
+            i += 1;
+        }
+    }
+    while let Some(x) = a.get(i) {
+        merged.push(x.clone());
+        i += 1;
+    }
+    while let Some(y) = b.get(j) {
+        merged.push(y.clone());
+        j += 1;
+    }
+    merged
+}
+
+pub(crate) fn top_down<T: Clone, S: SortOps<T>>(v: &mut [T], s: &mut S) {
+    let mut scratch = DynamicArray::with_capacity(v.len() / 2);
+    top_down_recursive(v, &mut scratch, s);
+}
+
+fn top_down_recursive<T: Clone, S: SortOps<T>>(
+    v: &mut [T],
+    scratch: &mut DynamicArray<T>,
+    s: &mut S,
+) {
+    if v.len() < 2 {
+        return;
+    }
+    let mid = v.len() / 2;
+    top_down_recursive(&mut v[..mid], scratch, s);
+    s.within(mid, |s| top_down_recursive(&mut v[mid..], scratch, s));
+    merge(v, mid, scratch, s);
+}
+
+pub(crate) fn bottom_up<T: Clone, S: SortOps<T>>(v: &mut [T], s: &mut S) {
+    let n = v.len();
+    // The widest left run is the largest power of two below n
+    let mut scratch = DynamicArray::with_capacity(n.next_power_of_two() / 2);
+    let mut width = 1;
+    while width < n {
+        let mut start = 0;
+        while start + width < n {
+            let end = (start + 2 * width).min(n);
+            s.within(start, |s| merge(&mut v[start..end], width, &mut scratch, s));
+            start = end;
+        }
+        width *= 2;
+    }
+}
+
+// Merges the sorted runs v[..mid] and v[mid..]. Only the left run is copied
+// out; right elements are swapped forward, which is safe because the slot
+// they move into always holds a value already copied to `scratch`.
+pub(crate) fn merge<T: Clone, S: SortOps<T>>(
+    v: &mut [T],
+    mid: usize,
+    scratch: &mut DynamicArray<T>,
+    s: &mut S,
+) {
+    if mid == 0 || mid == v.len() || s.compare(&v[mid - 1], &v[mid]) != Ordering::Greater {
+        return;
+    }
+
+    scratch.clear();
+    scratch.extend(v[..mid].iter().cloned());
+    let left = scratch.as_slice();
+
+    let (mut i, mut j, mut k) = (0, mid, 0);
+    while i < left.len() && j < v.len() {
+        // Ties take the left element, which keeps the sort stable
+        if s.is_less(&v[j], &left[i]) {
+            s.swap(v, k, j);
+            j += 1;
+        } else {
+            s.set(v, k, left[i].clone());
+            i += 1;
+        }
+        k += 1;
+    }
+    // Leftover right elements are already in place
+    while i < left.len() {
+        s.set(v, k, left[i].clone());
+        i += 1;
+        k += 1;
+    }
+}
+
+pub(crate) fn in_place<T, S: SortOps<T>>(v: &mut [T], s: &mut S) {
+    if v.len() < 2 {
+        return;
+    }
+    let mid = v.len() / 2;
+    in_place(&mut v[..mid], s);
+    s.within(mid, |s| in_place(&mut v[mid..], s));
+    if s.compare(&v[mid - 1], &v[mid]) == Ordering::Greater {
+        merge_in_place(v, mid, s);
+    }
+}
+
+// Merges v[..mid] and v[mid..] without a buffer. The middle element of the
+// longer run is a cut point; the elements of the other run that belong
+// before it are found by binary search, and one rotation moves both blocks
+// to the right side of each other. That leaves two independent, smaller
+// merges.
+fn merge_in_place<T, S: SortOps<T>>(v: &mut [T], mid: usize, s: &mut S) {
+    let n = v.len();
+    if mid == 0 || mid == n {
+        return;
+    }
+    if n == 2 {
+        if s.is_less(&v[1], &v[0]) {
+            s.swap(v, 0, 1);
+        }
+        return;
+    }
+
+    let (left_cut, right_cut) = if mid >= n - mid {
+        // Right elements strictly less than the cut move in front of it
+        let left_cut = mid / 2;
+        let offset = partition_point(&v[mid..], |x| s.is_less(x, &v[left_cut]));
+        (left_cut, mid + offset)
+    } else {
+        // Left elements strictly greater than the cut move behind it
+        let right_cut = mid + (n - mid) / 2;
+        let left_cut = partition_point(&v[..mid], |x| !s.is_less(&v[right_cut], x));
+        (left_cut, right_cut)
+    };
+
+    s.within(left_cut, |s| {
+        s.rotate_left(&mut v[left_cut..right_cut], mid - left_cut)
+    });
+    let new_mid = left_cut + (right_cut - mid);
+    merge_in_place(&mut v[..new_mid], left_cut, s);
+    s.within(new_mid, |s| {
+        merge_in_place(&mut v[new_mid..], right_cut - new_mid, s)
+    });
+}
+
+// Index of the first element for which `pred` is false
+fn partition_point<T>(v: &[T], mut pred: impl FnMut(&T) -> bool) -> usize {
+    let (mut low, mut high) = (0, v.len());
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if pred(&v[mid]) {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    low
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::test_util::{assert_sorts_like_std, random_values};
+    use crate::algorithms::Sortable;
+    use crate::core::Array;
+
+    type SortFn = fn(&mut DynamicArray<u32>);
+
+    const SORTS: [(&str, SortFn); 3] = [
+        ("top-down", |arr| arr.merge_sort()),
+        ("bottom-up", |arr| arr.bottom_up_merge_sort()),
+        ("in place", |arr| arr.in_place_merge_sort()),
+    ];
+
+    #[test]
+    fn test_sorts_match_std_sort() {
+        for (name, sort) in SORTS {
+            assert_sorts_like_std(name, 40, 600, sort);
+        }
+    }
+
+    #[test]
+    fn test_edge_cases() {
+        for (name, sort) in SORTS {
+            let mut empty = DynamicArray::new();
+            sort(&mut empty);
+            assert!(empty.is_empty(), "{name}");
+
+            let mut single = DynamicArray::new();
+            single.push(1);
+            sort(&mut single);
+            assert_eq!(single.as_slice(), &[1], "{name}");
+
+            let mut reversed = DynamicArray::new();
+            reversed.extend((0..100).rev());
+            sort(&mut reversed);
+            assert_eq!(reversed.as_slice(), (0..100).collect::<Vec<_>>(), "{name}");
+        }
+    }
+
+    #[test]
+    fn test_sorts_are_stable() {
+        let mut records = Vec::new();
+        for (i, key) in random_values(9, 300, 10).into_iter().enumerate() {
+            records.push((key, i));
+        }
+        let mut expected = records.clone();
+        expected.sort_by_key(|&(key, _)| key);
+
+        let mut arr = DynamicArray::new();
+        arr.extend(records.iter().copied());
+        arr.merge_sort_by_key(|&(key, _)| key);
+        assert_eq!(arr.as_slice(), expected.as_slice());
+
+        let mut arr = DynamicArray::new();
+        arr.extend(records.iter().copied());
+        arr.bottom_up_merge_sort_by(|a, b| a.0.cmp(&b.0));
+        assert_eq!(arr.as_slice(), expected.as_slice());
+
+        let mut arr = Array::from_slice(&records, records.len()).unwrap();
+        arr.in_place_merge_sort_by(|a, b| a.0.cmp(&b.0));
+        assert_eq!(arr.as_slice(), expected.as_slice());
+    }
+
+    #[test]
+    fn test_in_place_does_not_need_clone() {
+        #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+        struct Token(u32);
+
+        let mut arr = DynamicArray::new();
+        arr.extend(random_values(4, 200, 50).into_iter().map(Token));
+        arr.in_place_merge_sort();
+        assert!(arr.as_slice().windows(2).all(|w| w[0] <= w[1]));
+    }
+
+    #[test]
+    fn test_sorted_input_is_linear() {
+        let n = 1024;
+        let mut arr = DynamicArray::new();
+        arr.extend(0..n);
+
+        let mut comparisons = 0;
+        arr.merge_sort_by(|a, b| {
+            comparisons += 1;
+            a.cmp(b)
+        });
+        // One comparison per merge to see the runs are in order
+        assert_eq!(comparisons, n - 1);
+
+        let mut comparisons = 0;
+        arr.bottom_up_merge_sort_by(|a, b| {
+            comparisons += 1;
+            a.cmp(b)
+        });
+        assert_eq!(comparisons, n - 1);
+    }
+
+    #[test]
+    fn test_worst_case_comparisons() {
+        let n = 1024u32;
+        let values = random_values(17, n as usize, u32::MAX);
+        let bound = n * n.ilog2();
+
+        let mut arr = DynamicArray::new();
+        arr.extend(values.iter().copied());
+        let mut comparisons = 0;
+        arr.merge_sort_by(|a, b| {
+            comparisons += 1;
+            a.cmp(b)
+        });
+        assert!(comparisons <= bound, "{comparisons}");
+    }
+
+    #[test]
+    fn test_merge_sorted() {
+        let a = Array::from_slice(&[1, 3, 5, 7], 4).unwrap();
+        let mut b = DynamicArray::new();
+        b.extend([2, 3, 8]);
+        assert_eq!(merge_sorted(&a, &b).as_slice(), &[1, 2, 3, 3, 5, 7, 8]);
+
+        let empty: [i32; 0] = [];
+        assert_eq!(merge_sorted(&a, &empty[..]).as_slice(), &[1, 3, 5, 7]);
+        assert!(merge_sorted(&empty[..], &empty[..]).is_empty());
+
+        // Ties come from the first sequence
+        let merged = merge_sorted_by(
+            &[(1, 'a'), (2, 'a')][..],
+            &[(1, 'b'), (2, 'b')][..],
+            |x, y| x.0.cmp(&y.0),
+        );
+        assert_eq!(merged.as_slice(), &[(1, 'a'), (1, 'b'), (2, 'a'), (2, 'b')]);
+
+        let merged = merge_sorted_by(&[9, 4, 1][..], &[8, 2][..], |x, y| y.cmp(x));
+        assert_eq!(merged.as_slice(), &[9, 8, 4, 2, 1]);
+    }
+}
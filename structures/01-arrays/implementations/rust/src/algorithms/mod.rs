@@ -5,9 +5,58 @@
 pub mod binary_search;
 pub mod linear_search;
 pub mod jump_search;
+pub mod interpolation_search;
+pub mod exponential_search;
+pub mod nearest_search;
+pub mod matrix_search;
+pub mod selection;
+pub mod pattern_search;
+pub mod precondition;
+pub mod float_search;
 pub mod bubble_sort;
+pub mod insertion_sort;
+pub mod selection_sort;
+pub mod quick_sort;
+pub mod merge_sort;
+pub mod heap_sort;
+pub mod distribution_sort;
+pub mod hybrid_sort;
+pub mod shell_sort;
+pub mod sortable;
+pub mod partial_sort;
+pub mod sort_observer;
+pub mod search_trace;
+pub mod parallel_search;
+pub mod smart_search;
+#[cfg(test)]
+mod test_util;
 
 // Re-export commonly used traits
-pub use binary_search::BinarySearchable;
-pub use linear_search::LinearSearchable;
-pub use jump_search::JumpSearchable;
\ No newline at end of file
+pub use binary_search::{BinarySearchable, RotatedView};
+pub use linear_search::{
+    ChunkedElement, FastLinearSearchable, LinearSearchable, SentinelSearchable, TrustedEq,
+};
+pub use jump_search::JumpSearchable;
+pub use interpolation_search::{InterpolationKey, InterpolationSearchable};
+pub use exponential_search::ExponentialSearchable;
+pub use nearest_search::{Distance, NearestSearchable};
+pub use matrix_search::MatrixView;
+pub use selection::Selectable;
+pub use pattern_search::{BytePatternSearchable, PatternAlgorithm, PatternSearchable};
+pub use precondition::{
+    is_sorted_by, PreconditionError, PreconditionMode, SortedPrecondition, SortedView,
+};
+pub use float_search::FloatSearchable;
+pub use quick_sort::{PartitionScheme, PivotStrategy, QuickSortConfig};
+pub use merge_sort::merge_sorted;
+pub use heap_sort::{heapify, sift_down, sift_up};
+pub use distribution_sort::{CountingSortError, DistributionSortable, RadixKey};
+pub use shell_sort::{GapSequence, PassStats, ShellSortStats};
+pub use sortable::Sortable;
+pub use partial_sort::PartialSortable;
+pub use sort_observer::{
+    BufferedSortAlgorithm, Frame, FrameRecorder, SortAlgorithm, SortObserver, SortStats, SortStep,
+};
+pub use parallel_search::ParallelSearchable;
+pub use search_trace::{SearchStep, SearchTrace};
+pub use smart_search::SmartSearch;
\ No newline at end of file
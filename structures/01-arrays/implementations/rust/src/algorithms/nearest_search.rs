@@ -0,0 +1,556 @@
+//! Nearest-value queries on sorted arrays.
+//!
+//! Binary search answers "is `x` here?". These queries answer "what is
+//! around `x`?": the largest element not above it (floor), the smallest not
+//! below it (ceiling), the closest element, and the `k` closest elements.
+//! All of them start from the position where `x` would be inserted, found in
+//! O(log n).
+
+use super::float_search::TotalOrd;
+use super::precondition::{debug_assert_sorted_by, enforce, PreconditionError, PreconditionMode};
+use crate::sequence::Sequence;
+use std::ops::Range;
+
+/// Numeric types with a natural distance, used by `k_closest`.
+pub trait Distance {
+    /// The type distances are measured in.
+    type Output: PartialOrd;
+
+    /// Returns how far apart `self` and `other` are.
+    fn distance(&self, other: &Self) -> Self::Output;
+}
+
+macro_rules! impl_distance_int {
+    ($($type:ty => $output:ty),*) => {
+        $(
+            impl Distance for $type {
+                type Output = $output;
+
+                fn distance(&self, other: &Self) -> $output {
+                    self.abs_diff(*other)
+                }
+            }
+        )*
+    };
+}
+
+impl_distance_int!(
+    u8 => u8, u16 => u16, u32 => u32, u64 => u64, u128 => u128, usize => usize,
+    i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize
+);
+
+// Floats are not `Ord`, so they take part through their `TotalOrd` wrapper
+impl Distance for TotalOrd<f32> {
+    type Output = f32;
+
+    fn distance(&self, other: &Self) -> f32 {
+        (self.0 - other.0).abs()
+    }
+}
+
+impl Distance for TotalOrd<f64> {
+    type Output = f64;
+
+    fn distance(&self, other: &Self) -> f64 {
+        (self.0 - other.0).abs()
+    }
+}
+
+/// Trait for sorted sequences that support nearest-value queries.
+/// Requires sorted data for correct operation.
+///
+/// Every query panics in debug builds if the sequence is not sorted,
+/// following `PreconditionMode::DebugAssert`.
+pub trait NearestSearchable<T> {
+    /// Returns the index of the largest element less than or equal to
+    /// `target`, or `None` if every element is greater.
+    ///
+    /// With duplicates, the last equal element is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::core::Array;
+    /// use arrays::algorithms::NearestSearchable;
+    ///
+    /// let arr = Array::from_slice(&[10, 20, 30, 40], 10).unwrap();
+    /// assert_eq!(arr.floor(&25), Some(1));
+    /// assert_eq!(arr.floor(&30), Some(2));
+    /// assert_eq!(arr.floor(&5), None);
+    /// ```
+    fn floor(&self, target: &T) -> Option<usize>
+    where
+        T: Ord;
+
+    /// Returns the index of the smallest element greater than or equal to
+    /// `target`, or `None` if every element is less.
+    ///
+    /// With duplicates, the first equal element is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::core::Array;
+    /// use arrays::algorithms::NearestSearchable;
+    ///
+    /// let arr = Array::from_slice(&[10, 20, 30, 40], 10).unwrap();
+    /// assert_eq!(arr.ceiling(&25), Some(2));
+    /// assert_eq!(arr.ceiling(&30), Some(2));
+    /// assert_eq!(arr.ceiling(&45), None);
+    /// ```
+    fn ceiling(&self, target: &T) -> Option<usize>
+    where
+        T: Ord;
+
+    /// Like `floor`, but applies `mode` to the sortedness check instead of
+    /// the default debug assertion.
+    ///
+    /// # Errors
+    /// Returns `NotSorted` in `AlwaysCheck` mode if the array is unsorted.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::core::Array;
+    /// use arrays::algorithms::{NearestSearchable, PreconditionMode};
+    ///
+    /// let arr = Array::from_slice(&[10, 20, 30, 40], 10).unwrap();
+    /// assert_eq!(arr.floor_checked(&25, PreconditionMode::AlwaysCheck), Ok(Some(1)));
+    /// ```
+    fn floor_checked(
+        &self,
+        target: &T,
+        mode: PreconditionMode,
+    ) -> Result<Option<usize>, PreconditionError>
+    where
+        T: Ord;
+
+    /// Like `ceiling`, but applies `mode` to the sortedness check, see
+    /// `floor_checked`.
+    fn ceiling_checked(
+        &self,
+        target: &T,
+        mode: PreconditionMode,
+    ) -> Result<Option<usize>, PreconditionError>
+    where
+        T: Ord;
+
+    /// Returns the index of the element closest to `target` as measured by
+    /// `distance`. Ties go to the smaller element.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::core::Array;
+    /// use arrays::algorithms::NearestSearchable;
+    ///
+    /// let timestamps = Array::from_slice(&[100u64, 250, 400], 10).unwrap();
+    /// assert_eq!(timestamps.closest(&300, |a, b| a.abs_diff(*b)), Some(1));
+    /// assert_eq!(timestamps.closest(&325, |a, b| a.abs_diff(*b)), Some(1));
+    /// ```
+    fn closest<D, F>(&self, target: &T, distance: F) -> Option<usize>
+    where
+        T: Ord,
+        D: PartialOrd,
+        F: FnMut(&T, &T) -> D;
+
+    /// Returns the index range of the `k` elements closest to `target`.
+    ///
+    /// The closest elements of a sorted array are always contiguous. Ties go
+    /// to the smaller elements. If `k` exceeds the length, the whole array
+    /// is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::core::Array;
+    /// use arrays::algorithms::NearestSearchable;
+    ///
+    /// let arr = Array::from_slice(&[1, 2, 3, 4, 5], 10).unwrap();
+    /// assert_eq!(arr.k_closest(&3, 4), 0..4);
+    /// assert_eq!(arr.k_closest(&-1, 2), 0..2);
+    /// ```
+    fn k_closest(&self, target: &T, k: usize) -> Range<usize>
+    where
+        T: Ord + Distance;
+
+    /// Like `k_closest`, but measures closeness with `distance`.
+    fn k_closest_by<D, F>(&self, target: &T, k: usize, distance: F) -> Range<usize>
+    where
+        T: Ord,
+        D: PartialOrd,
+        F: FnMut(&T, &T) -> D;
+
+    /// Like `closest`, but applies `mode` to the sortedness check, see
+    /// `floor_checked`.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::core::Array;
+    /// use arrays::algorithms::{NearestSearchable, PreconditionMode};
+    ///
+    /// let arr = Array::from_slice(&[100u64, 250, 400], 10).unwrap();
+    /// let distance = |a: &u64, b: &u64| a.abs_diff(*b);
+    /// assert_eq!(arr.closest_checked(&300, distance, PreconditionMode::AlwaysCheck), Ok(Some(1)));
+    /// ```
+    fn closest_checked<D, F>(
+        &self,
+        target: &T,
+        distance: F,
+        mode: PreconditionMode,
+    ) -> Result<Option<usize>, PreconditionError>
+    where
+        T: Ord,
+        D: PartialOrd,
+        F: FnMut(&T, &T) -> D;
+
+    /// Like `k_closest`, but applies `mode` to the sortedness check, see
+    /// `floor_checked`.
+    fn k_closest_checked(
+        &self,
+        target: &T,
+        k: usize,
+        mode: PreconditionMode,
+    ) -> Result<Range<usize>, PreconditionError>
+    where
+        T: Ord + Distance;
+
+    /// Like `k_closest_by`, but applies `mode` to the sortedness check, see
+    /// `floor_checked`.
+    fn k_closest_by_checked<D, F>(
+        &self,
+        target: &T,
+        k: usize,
+        distance: F,
+        mode: PreconditionMode,
+    ) -> Result<Range<usize>, PreconditionError>
+    where
+        T: Ord,
+        D: PartialOrd,
+        F: FnMut(&T, &T) -> D;
+}
+
+// Every `Sequence` gets nearest-value queries through this blanket implementation
+impl<T, S> NearestSearchable<T> for S
+where
+    S: Sequence<T> + ?Sized,
+{
+    fn floor(&self, target: &T) -> Option<usize>
+    where
+        T: Ord,
+    {
+        debug_assert_sorted_by(self, T::cmp);
+        floor_index(self, target)
+    }
+
+    fn ceiling(&self, target: &T) -> Option<usize>
+    where
+        T: Ord,
+    {
+        debug_assert_sorted_by(self, T::cmp);
+        ceiling_index(self, target)
+    }
+
+    fn floor_checked(
+        &self,
+        target: &T,
+        mode: PreconditionMode,
+    ) -> Result<Option<usize>, PreconditionError>
+    where
+        T: Ord,
+    {
+        enforce(self, mode, T::cmp)?;
+        Ok(floor_index(self, target))
+    }
+
+    fn ceiling_checked(
+        &self,
+        target: &T,
+        mode: PreconditionMode,
+    ) -> Result<Option<usize>, PreconditionError>
+    where
+        T: Ord,
+    {
+        enforce(self, mode, T::cmp)?;
+        Ok(ceiling_index(self, target))
+    }
+
+    fn closest<D, F>(&self, target: &T, distance: F) -> Option<usize>
+    where
+        T: Ord,
+        D: PartialOrd,
+        F: FnMut(&T, &T) -> D,
+    {
+        debug_assert_sorted_by(self, T::cmp);
+        closest_index(self, target, distance)
+    }
+
+    fn k_closest(&self, target: &T, k: usize) -> Range<usize>
+    where
+        T: Ord + Distance,
+    {
+        self.k_closest_by(target, k, T::distance)
+    }
+
+    fn k_closest_by<D, F>(&self, target: &T, k: usize, distance: F) -> Range<usize>
+    where
+        T: Ord,
+        D: PartialOrd,
+        F: FnMut(&T, &T) -> D,
+    {
+        debug_assert_sorted_by(self, T::cmp);
+        k_closest_range(self, target, k, distance)
+    }
+
+    fn closest_checked<D, F>(
+        &self,
+        target: &T,
+        distance: F,
+        mode: PreconditionMode,
+    ) -> Result<Option<usize>, PreconditionError>
+    where
+        T: Ord,
+        D: PartialOrd,
+        F: FnMut(&T, &T) -> D,
+    {
+        enforce(self, mode, T::cmp)?;
+        Ok(closest_index(self, target, distance))
+    }
+
+    fn k_closest_checked(
+        &self,
+        target: &T,
+        k: usize,
+        mode: PreconditionMode,
+    ) -> Result<Range<usize>, PreconditionError>
+    where
+        T: Ord + Distance,
+    {
+        self.k_closest_by_checked(target, k, T::distance, mode)
+    }
+
+    fn k_closest_by_checked<D, F>(
+        &self,
+        target: &T,
+        k: usize,
+        distance: F,
+        mode: PreconditionMode,
+    ) -> Result<Range<usize>, PreconditionError>
+    where
+        T: Ord,
+        D: PartialOrd,
+        F: FnMut(&T, &T) -> D,
+    {
+        enforce(self, mode, T::cmp)?;
+        Ok(k_closest_range(self, target, k, distance))
+    }
+}
+
+// `closest` without the sortedness check.
+fn closest_index<T, S, D, F>(seq: &S, target: &T, mut distance: F) -> Option<usize>
+where
+    T: Ord,
+    S: Sequence<T> + ?Sized,
+    D: PartialOrd,
+    F: FnMut(&T, &T) -> D,
+{
+    match (floor_index(seq, target), ceiling_index(seq, target)) {
+        (Some(below), Some(above)) => {
+            let below_distance = distance(seq.get(below)?, target);
+            let above_distance = distance(seq.get(above)?, target);
+            if above_distance < below_distance {
+                Some(above)
+            } else {
+                Some(below)
+            }
+        }
+        (below, above) => below.or(above),
+    }
+}
+
+// `k_closest_by` without the sortedness check.
+fn k_closest_range<T, S, D, F>(seq: &S, target: &T, k: usize, mut distance: F) -> Range<usize>
+where
+    T: Ord,
+    S: Sequence<T> + ?Sized,
+    D: PartialOrd,
+    F: FnMut(&T, &T) -> D,
+{
+    let n = seq.len();
+    if k >= n {
+        return 0..n;
+    }
+
+    // Grow the window outwards from the insertion point, one element
+    // at a time, always taking the closer neighbour
+    let mut low = partition_point(seq, |elem| elem < target);
+    let mut high = low;
+
+    while high - low < k {
+        let take_left = match (low.checked_sub(1), high < n) {
+            (Some(left), true) => match (seq.get(left), seq.get(high)) {
+                (Some(l), Some(r)) => distance(l, target) <= distance(r, target),
+                _ => break,
+            },
+            (Some(_), false) => true,
+            (None, _) => false,
+        };
+
+        if take_left {
+            low -= 1;
+        } else {
+            high += 1;
+        }
+    }
+
+    low..high
+}
+
+// Index of the last element not greater than `target`.
+fn floor_index<T: Ord, S: Sequence<T> + ?Sized>(seq: &S, target: &T) -> Option<usize> {
+    partition_point(seq, |elem| elem <= target).checked_sub(1)
+}
+
+// Index of the first element not less than `target`.
+fn ceiling_index<T: Ord, S: Sequence<T> + ?Sized>(seq: &S, target: &T) -> Option<usize> {
+    let index = partition_point(seq, |elem| elem < target);
+    if index < seq.len() {
+        Some(index)
+    } else {
+        None
+    }
+}
+
+// Index of the first element for which `pred` is false, assuming all
+// elements satisfying `pred` come first.
+fn partition_point<T, S, P>(seq: &S, mut pred: P) -> usize
+where
+    S: Sequence<T> + ?Sized,
+    P: FnMut(&T) -> bool,
+{
+    let mut low = 0;
+    let mut high = seq.len();
+
+    while low < high {
+        let mid = low + (high - low) / 2;
+        match seq.get(mid) {
+            Some(elem) if pred(elem) => low = mid + 1,
+            _ => high = mid,
+        }
+    }
+
+    low
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Array;
+    use crate::dynamic_array::DynamicArray;
+
+    fn abs_diff(a: &i32, b: &i32) -> u32 {
+        a.abs_diff(*b)
+    }
+
+    #[test]
+    fn test_floor_and_ceiling_at_boundaries() {
+        let arr = Array::from_slice(&[10, 20, 30, 40, 50], 5).unwrap();
+
+        // Below the first element
+        assert_eq!(arr.floor(&9), None);
+        assert_eq!(arr.ceiling(&9), Some(0));
+
+        // Exactly the first and last elements
+        assert_eq!(arr.floor(&10), Some(0));
+        assert_eq!(arr.ceiling(&10), Some(0));
+        assert_eq!(arr.floor(&50), Some(4));
+        assert_eq!(arr.ceiling(&50), Some(4));
+
+        // Above the last element
+        assert_eq!(arr.floor(&51), Some(4));
+        assert_eq!(arr.ceiling(&51), None);
+
+        // Between elements
+        assert_eq!(arr.floor(&35), Some(2));
+        assert_eq!(arr.ceiling(&35), Some(3));
+    }
+
+    #[test]
+    fn test_floor_and_ceiling_with_duplicates() {
+        let arr = Array::from_slice(&[1, 2, 2, 2, 3], 5).unwrap();
+
+        assert_eq!(arr.floor(&2), Some(3));
+        assert_eq!(arr.ceiling(&2), Some(1));
+    }
+
+    #[test]
+    fn test_empty_array() {
+        let arr: DynamicArray<i32> = DynamicArray::new();
+
+        assert_eq!(arr.floor(&1), None);
+        assert_eq!(arr.ceiling(&1), None);
+        assert_eq!(arr.closest(&1, abs_diff), None);
+        assert_eq!(arr.k_closest(&1, 3), 0..0);
+    }
+
+    #[test]
+    fn test_closest() {
+        let arr = Array::from_slice(&[1, 5, 9, 20], 10).unwrap();
+
+        assert_eq!(arr.closest(&-100, abs_diff), Some(0));
+        assert_eq!(arr.closest(&6, abs_diff), Some(1));
+        assert_eq!(arr.closest(&8, abs_diff), Some(2));
+        assert_eq!(arr.closest(&100, abs_diff), Some(3));
+        // 7 is equally far from 5 and 9, the smaller one wins
+        assert_eq!(arr.closest(&7, abs_diff), Some(1));
+    }
+
+    #[test]
+    fn test_closest_with_custom_distance() {
+        // Closest on a 24-hour clock, where 23 is next to 0
+        let hours = Array::from_slice(&[2, 9, 15, 23], 10).unwrap();
+        let clock = |a: &i32, b: &i32| {
+            let d = (a - b).rem_euclid(24);
+            d.min(24 - d)
+        };
+
+        assert_eq!(hours.closest(&10, clock), Some(1));
+        // Only floor and ceiling are considered, so wrap-around is not
+        assert_eq!(hours.closest(&0, clock), Some(0));
+    }
+
+    #[test]
+    fn test_k_closest() {
+        let arr = Array::from_slice(&[1, 2, 3, 4, 5], 10).unwrap();
+
+        assert_eq!(arr.k_closest(&3, 0), 2..2);
+        assert_eq!(arr.k_closest(&3, 1), 2..3);
+        assert_eq!(arr.k_closest(&3, 2), 1..3);
+        assert_eq!(arr.k_closest(&3, 3), 1..4);
+        assert_eq!(arr.k_closest(&3, 5), 0..5);
+        assert_eq!(arr.k_closest(&3, 9), 0..5);
+
+        // Targets beyond either end take the nearest edge
+        assert_eq!(arr.k_closest(&-5, 3), 0..3);
+        assert_eq!(arr.k_closest(&50, 3), 2..5);
+    }
+
+    #[test]
+    fn test_k_closest_timestamps() {
+        let mut timestamps = DynamicArray::new();
+        timestamps.extend([100u64, 180, 200, 205, 290, 400, 1000]);
+
+        let range = timestamps.k_closest(&210, 3);
+        assert_eq!(range, 1..4);
+        let values: Vec<u64> = range.map(|i| timestamps[i]).collect();
+        assert_eq!(values, vec![180, 200, 205]);
+    }
+
+    #[test]
+    fn test_k_closest_floats() {
+        let values = [0.5f64, 1.0, 1.25, 3.0];
+        let arr = TotalOrd::wrap_slice(&values);
+
+        assert_eq!(arr.k_closest(&TotalOrd(1.2), 2), 1..3);
+        assert_eq!(arr.k_closest(&TotalOrd(2.9), 1), 3..4);
+        assert_eq!(
+            arr.k_closest_by(&TotalOrd(2.0), 2, |a, b| (a.0 - b.0).abs()),
+            1..3
+        );
+    }
+}
@@ -0,0 +1,253 @@
+//! Parallel linear search using scoped threads.
+//!
+//! The array is split into one contiguous chunk per worker and each chunk is
+//! scanned on its own thread with `std::thread::scope`, so workers can borrow
+//! the array directly without `Arc` or copying.
+//!
+//! Finding the *first* match needs coordination: a worker that finds the
+//! target publishes its index, and workers whose chunk starts after that
+//! index stop early, since nothing they find could be earlier. Workers on
+//! earlier chunks keep going, because they may still find a smaller index.
+
+use crate::sequence::Sequence;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+/// Arrays shorter than this per worker are not worth a thread.
+pub const MIN_CHUNK_LEN: usize = 16 * 1024;
+
+// How many elements a worker scans between checks of the shared result.
+const CHECK_INTERVAL: usize = 1024;
+
+/// Trait for sequences that can be searched by several threads at once.
+pub trait ParallelSearchable<T> {
+    /// Finds the index of the first occurrence of `target`, scanning chunks
+    /// of the array in parallel.
+    ///
+    /// Returns the same index as `linear_search`, not just any match.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::dynamic_array::DynamicArray;
+    /// use arrays::algorithms::ParallelSearchable;
+    ///
+    /// let mut arr = DynamicArray::new();
+    /// arr.extend((0..100_000).map(|i| i % 1000));
+    /// assert_eq!(arr.par_linear_search(&999), Some(999));
+    /// assert_eq!(arr.par_linear_search(&1000), None);
+    /// ```
+    fn par_linear_search(&self, target: &T) -> Option<usize>
+    where
+        T: PartialEq + Sync;
+
+    /// Finds all occurrences of `target` in ascending index order.
+    fn par_linear_search_all(&self, target: &T) -> Vec<usize>
+    where
+        T: PartialEq + Sync;
+
+    /// Counts the occurrences of `target`.
+    fn par_count_occurrences(&self, target: &T) -> usize
+    where
+        T: PartialEq + Sync;
+}
+
+impl<T, S> ParallelSearchable<T> for S
+where
+    S: Sequence<T> + Sync + ?Sized,
+{
+    fn par_linear_search(&self, target: &T) -> Option<usize>
+    where
+        T: PartialEq + Sync,
+    {
+        first_match(self, target, worker_count(self.len()))
+    }
+
+    fn par_linear_search_all(&self, target: &T) -> Vec<usize>
+    where
+        T: PartialEq + Sync,
+    {
+        all_matches(self, target, worker_count(self.len()))
+    }
+
+    fn par_count_occurrences(&self, target: &T) -> usize
+    where
+        T: PartialEq + Sync,
+    {
+        count_matches(self, target, worker_count(self.len()))
+    }
+}
+
+// Uses every available core, but never gives a worker less than
+// MIN_CHUNK_LEN elements.
+fn worker_count(len: usize) -> usize {
+    let cores = thread::available_parallelism().map_or(1, |n| n.get());
+    cores.min(len / MIN_CHUNK_LEN).max(1)
+}
+
+// Splits 0..len into `workers` contiguous ranges of nearly equal size.
+fn chunk_bounds(len: usize, workers: usize) -> Vec<(usize, usize)> {
+    let chunk_len = len.div_ceil(workers.max(1)).max(1);
+    (0..len)
+        .step_by(chunk_len)
+        .map(|start| (start, (start + chunk_len).min(len)))
+        .collect()
+}
+
+// Runs `work` on every chunk in its own scoped thread and returns the
+// results in chunk order.
+fn run_chunks<T, S, R, F>(seq: &S, workers: usize, work: F) -> Vec<R>
+where
+    S: Sequence<T> + Sync + ?Sized,
+    R: Send,
+    F: Fn(usize, usize) -> R + Sync,
+{
+    let chunks = chunk_bounds(seq.len(), workers);
+    if chunks.len() <= 1 {
+        return chunks
+            .into_iter()
+            .map(|(start, end)| work(start, end))
+            .collect();
+    }
+
+    let work = &work;
+    thread::scope(|scope| {
+        let handles: Vec<_> = chunks
+            .into_iter()
+            .map(|(start, end)| scope.spawn(move || work(start, end)))
+            .collect();
+
+        handles
+            .into_iter()
+            .map(|handle| handle.join().expect("search worker panicked"))
+            .collect()
+    })
+}
+
+fn first_match<T, S>(seq: &S, target: &T, workers: usize) -> Option<usize>
+where
+    T: PartialEq + Sync,
+    S: Sequence<T> + Sync + ?Sized,
+{
+    // Smallest matching index found so far, usize::MAX if none
+    let best = AtomicUsize::new(usize::MAX);
+
+    run_chunks(seq, workers, |start, end| {
+        for block_start in (start..end).step_by(CHECK_INTERVAL) {
+            // A match before this chunk makes the rest of it irrelevant
+            if best.load(Ordering::Relaxed) < start {
+                return;
+            }
+
+            let block_end = (block_start + CHECK_INTERVAL).min(end);
+            for i in block_start..block_end {
+                if seq.get(i) == Some(target) {
+                    best.fetch_min(i, Ordering::Relaxed);
+                    return;
+                }
+            }
+        }
+    });
+
+    match best.into_inner() {
+        usize::MAX => None,
+        index => Some(index),
+    }
+}
+
+fn all_matches<T, S>(seq: &S, target: &T, workers: usize) -> Vec<usize>
+where
+    T: PartialEq + Sync,
+    S: Sequence<T> + Sync + ?Sized,
+{
+    run_chunks(seq, workers, |start, end| {
+        (start..end)
+            .filter(|&i| seq.get(i) == Some(target))
+            .collect::<Vec<_>>()
+    })
+    .concat()
+}
+
+fn count_matches<T, S>(seq: &S, target: &T, workers: usize) -> usize
+where
+    T: PartialEq + Sync,
+    S: Sequence<T> + Sync + ?Sized,
+{
+    run_chunks(seq, workers, |start, end| {
+        (start..end).filter(|&i| seq.get(i) == Some(target)).count()
+    })
+    .into_iter()
+    .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::LinearSearchable;
+    use crate::core::Array;
+    use crate::dynamic_array::DynamicArray;
+
+    fn repeating(len: usize, period: u32) -> DynamicArray<u32> {
+        let mut arr = DynamicArray::with_capacity(len);
+        for i in 0..len as u32 {
+            arr.push(i % period);
+        }
+        arr
+    }
+
+    #[test]
+    fn test_chunk_bounds() {
+        assert_eq!(chunk_bounds(10, 3), vec![(0, 4), (4, 8), (8, 10)]);
+        assert_eq!(chunk_bounds(2, 4), vec![(0, 1), (1, 2)]);
+        assert!(chunk_bounds(0, 4).is_empty());
+    }
+
+    #[test]
+    fn test_first_match_is_global_minimum() {
+        let arr = repeating(100_000, 7_000);
+
+        for workers in [1, 2, 3, 8, 64] {
+            for target in [0, 1, 6_999, 7_000] {
+                assert_eq!(
+                    first_match(&arr, &target, workers),
+                    arr.linear_search(&target),
+                    "workers {workers}, target {target}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_match_only_in_last_chunk() {
+        let mut arr = DynamicArray::with_capacity(50_000);
+        arr.extend(std::iter::repeat_n(0, 49_999));
+        arr.push(1);
+
+        assert_eq!(first_match(&arr, &1, 4), Some(49_999));
+        assert_eq!(first_match(&arr, &2, 4), None);
+    }
+
+    #[test]
+    fn test_all_matches_in_order() {
+        let arr = repeating(30_000, 1_000);
+        let expected = arr.linear_search_all(&123);
+
+        for workers in [1, 4, 7] {
+            assert_eq!(all_matches(&arr, &123, workers), expected);
+            assert_eq!(count_matches(&arr, &123, workers), 30);
+        }
+    }
+
+    #[test]
+    fn test_trait_methods() {
+        let arr = Array::from_slice(&[4, 8, 15, 16, 23, 42, 15], 10).unwrap();
+
+        assert_eq!(arr.par_linear_search(&15), Some(2));
+        assert_eq!(arr.par_linear_search_all(&15), vec![2, 6]);
+        assert_eq!(arr.par_count_occurrences(&15), 2);
+        assert_eq!(arr.par_count_occurrences(&7), 0);
+
+        let empty: DynamicArray<i32> = DynamicArray::new();
+        assert_eq!(empty.par_linear_search(&1), None);
+        assert!(empty.par_linear_search_all(&1).is_empty());
+    }
+}
@@ -0,0 +1,578 @@
+//! Partial sorting: ordering only the part of an array that is needed.
+//!
+//! Sorting a million elements to read the ten largest does O(n log n) work
+//! for an answer that needs much less:
+//!
+//! - `partial_sort(k)` puts the k smallest elements, sorted, at the front.
+//!   It keeps a max-heap of the k best candidates seen so far; each later
+//!   element costs one comparison against the heap's root and is sifted in
+//!   only if it beats it. O(n log k), and close to n comparisons on random
+//!   input when k is small.
+//! - `nth_element(k)` puts the element that belongs at `k` there, with
+//!   nothing greater before it and nothing less after it. It uses the
+//!   introselect from `selection`, so it is O(n) in the worst case.
+//! - `sorted_top_k(k)` is `partial_sort` under the reversed order: the k
+//!   largest elements, largest first.
+//!
+//! None of them is stable.
+
+use super::heap_sort::{heapify_with, sift_down_with, sort_heap};
+use super::selection::select;
+use super::sort_observer::{SortObserver, SortOps, Sorter};
+use crate::sequence::SequenceMut;
+use std::cmp::Ordering;
+
+/// Trait for arrays that support partial sorting.
+pub trait PartialSortable<T> {
+    /// Moves the `k` smallest elements to the front of the array in
+    /// ascending order. The order of the rest is unspecified.
+    ///
+    /// A `k` of the array's length or more sorts the whole array.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::dynamic_array::DynamicArray;
+    /// use arrays::algorithms::PartialSortable;
+    ///
+    /// let mut arr = DynamicArray::new();
+    /// arr.extend([9, 4, 7, 1, 8, 2, 6]);
+    /// arr.partial_sort(3);
+    /// assert_eq!(&arr.as_slice()[..3], &[1, 2, 4]);
+    /// ```
+    fn partial_sort(&mut self, k: usize)
+    where
+        T: Ord;
+
+    /// Like `partial_sort`, using a custom comparator.
+    fn partial_sort_by<F>(&mut self, k: usize, compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering;
+
+    /// Like `partial_sort`, ordering by the key `f` extracts.
+    fn partial_sort_by_key<K, F>(&mut self, k: usize, f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K;
+
+    /// Reorders the array so the element at `k` is the one that would be
+    /// there if the array were sorted, and returns it.
+    ///
+    /// Everything before `k` is less than or equal to it and everything
+    /// after is greater than or equal to it. Returns `None`, leaving the
+    /// array unchanged, if `k` is out of bounds. O(n).
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::core::Array;
+    /// use arrays::algorithms::PartialSortable;
+    ///
+    /// let mut arr = Array::from_slice(&[5, 1, 4, 2, 3], 5).unwrap();
+    /// assert_eq!(arr.nth_element(1), Some(&2));
+    /// assert!(arr.as_slice()[..1].iter().all(|&x| x <= 2));
+    /// assert!(arr.as_slice()[2..].iter().all(|&x| x >= 2));
+    /// ```
+    fn nth_element(&mut self, k: usize) -> Option<&T>
+    where
+        T: Ord;
+
+    /// Like `nth_element`, using a custom comparator.
+    fn nth_element_by<F>(&mut self, k: usize, compare: F) -> Option<&T>
+    where
+        F: FnMut(&T, &T) -> Ordering;
+
+    /// Like `nth_element`, ordering by the key `f` extracts.
+    fn nth_element_by_key<K, F>(&mut self, k: usize, f: F) -> Option<&T>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K;
+
+    /// Moves the `k` largest elements to the front of the array, largest
+    /// first, and returns them.
+    ///
+    /// Returns the whole array, sorted in descending order, if `k` is at
+    /// least its length.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::dynamic_array::DynamicArray;
+    /// use arrays::algorithms::PartialSortable;
+    ///
+    /// let mut scores = DynamicArray::new();
+    /// scores.extend([72, 95, 61, 88, 95, 79]);
+    /// assert_eq!(scores.sorted_top_k(3), &[95, 95, 88]);
+    /// ```
+    fn sorted_top_k(&mut self, k: usize) -> &[T]
+    where
+        T: Ord;
+
+    /// Like `sorted_top_k`, using a custom comparator; the elements it
+    /// ranks greatest come first.
+    fn sorted_top_k_by<F>(&mut self, k: usize, compare: F) -> &[T]
+    where
+        F: FnMut(&T, &T) -> Ordering;
+
+    /// Like `sorted_top_k`, ordering by the key `f` extracts.
+    fn sorted_top_k_by_key<K, F>(&mut self, k: usize, f: F) -> &[T]
+    where
+        K: Ord,
+        F: FnMut(&T) -> K;
+
+    // Instrumented partial sorting, see `sort_observer`.
+
+    /// `partial_sort`, reporting every operation to `observer`.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::dynamic_array::DynamicArray;
+    /// use arrays::algorithms::{PartialSortable, SortStats};
+    ///
+    /// let mut arr = DynamicArray::new();
+    /// arr.extend([9, 4, 7, 1, 8]);
+    /// let mut stats = SortStats::default();
+    /// arr.partial_sort_observed(2, &mut stats);
+    /// assert_eq!(&arr.as_slice()[..2], &[1, 4]);
+    /// assert!(stats.comparisons > 0);
+    /// ```
+    fn partial_sort_observed<O>(&mut self, k: usize, observer: &mut O)
+    where
+        T: Ord,
+        O: SortObserver<T> + ?Sized;
+
+    /// `partial_sort_by`, reporting every operation to `observer`.
+    fn partial_sort_observed_by<F, O>(&mut self, k: usize, compare: F, observer: &mut O)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+        O: SortObserver<T> + ?Sized;
+
+    /// `nth_element`, reporting every operation to `observer`.
+    fn nth_element_observed<O>(&mut self, k: usize, observer: &mut O) -> Option<&T>
+    where
+        T: Ord,
+        O: SortObserver<T> + ?Sized;
+
+    /// `nth_element_by`, reporting every operation to `observer`.
+    fn nth_element_observed_by<F, O>(
+        &mut self,
+        k: usize,
+        compare: F,
+        observer: &mut O,
+    ) -> Option<&T>
+    where
+        F: FnMut(&T, &T) -> Ordering,
+        O: SortObserver<T> + ?Sized;
+
+    /// `sorted_top_k`, reporting every operation to `observer`.
+    fn sorted_top_k_observed<O>(&mut self, k: usize, observer: &mut O) -> &[T]
+    where
+        T: Ord,
+        O: SortObserver<T> + ?Sized;
+
+    /// `sorted_top_k_by`, reporting every operation to `observer`.
+    fn sorted_top_k_observed_by<F, O>(&mut self, k: usize, compare: F, observer: &mut O) -> &[T]
+    where
+        F: FnMut(&T, &T) -> Ordering,
+        O: SortObserver<T> + ?Sized;
+}
+
+// Every `SequenceMut` gets partial sorting through this blanket implementation
+impl<T, S> PartialSortable<T> for S
+where
+    S: SequenceMut<T> + ?Sized,
+{
+    fn partial_sort(&mut self, k: usize)
+    where
+        T: Ord,
+    {
+        partial(self.as_mut_slice(), k, &mut Sorter::new(T::cmp));
+    }
+
+    fn partial_sort_by<F>(&mut self, k: usize, compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        partial(self.as_mut_slice(), k, &mut Sorter::new(compare));
+    }
+
+    fn partial_sort_by_key<K, F>(&mut self, k: usize, mut f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        partial(
+            self.as_mut_slice(),
+            k,
+            &mut Sorter::new(|a: &T, b: &T| f(a).cmp(&f(b))),
+        );
+    }
+
+    fn nth_element(&mut self, k: usize) -> Option<&T>
+    where
+        T: Ord,
+    {
+        nth(self.as_mut_slice(), k, &mut Sorter::new(T::cmp))
+    }
+
+    fn nth_element_by<F>(&mut self, k: usize, compare: F) -> Option<&T>
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        nth(self.as_mut_slice(), k, &mut Sorter::new(compare))
+    }
+
+    fn nth_element_by_key<K, F>(&mut self, k: usize, mut f: F) -> Option<&T>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        nth(
+            self.as_mut_slice(),
+            k,
+            &mut Sorter::new(|a: &T, b: &T| f(a).cmp(&f(b))),
+        )
+    }
+
+    fn sorted_top_k(&mut self, k: usize) -> &[T]
+    where
+        T: Ord,
+    {
+        let v = self.as_mut_slice();
+        partial(v, k, &mut Sorter::new(|a: &T, b: &T| b.cmp(a)));
+        &v[..k.min(v.len())]
+    }
+
+    fn sorted_top_k_by<F>(&mut self, k: usize, mut compare: F) -> &[T]
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let v = self.as_mut_slice();
+        partial(v, k, &mut Sorter::new(|a: &T, b: &T| compare(b, a)));
+        &v[..k.min(v.len())]
+    }
+
+    fn sorted_top_k_by_key<K, F>(&mut self, k: usize, mut f: F) -> &[T]
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        let v = self.as_mut_slice();
+        partial(v, k, &mut Sorter::new(|a: &T, b: &T| f(b).cmp(&f(a))));
+        &v[..k.min(v.len())]
+    }
+
+    fn partial_sort_observed<O>(&mut self, k: usize, observer: &mut O)
+    where
+        T: Ord,
+        O: SortObserver<T> + ?Sized,
+    {
+        partial(
+            self.as_mut_slice(),
+            k,
+            &mut Sorter::observed(T::cmp, observer),
+        );
+    }
+
+    fn partial_sort_observed_by<F, O>(&mut self, k: usize, compare: F, observer: &mut O)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+        O: SortObserver<T> + ?Sized,
+    {
+        partial(
+            self.as_mut_slice(),
+            k,
+            &mut Sorter::observed(compare, observer),
+        );
+    }
+
+    fn nth_element_observed<O>(&mut self, k: usize, observer: &mut O) -> Option<&T>
+    where
+        T: Ord,
+        O: SortObserver<T> + ?Sized,
+    {
+        nth(
+            self.as_mut_slice(),
+            k,
+            &mut Sorter::observed(T::cmp, observer),
+        )
+    }
+
+    fn nth_element_observed_by<F, O>(
+        &mut self,
+        k: usize,
+        compare: F,
+        observer: &mut O,
+    ) -> Option<&T>
+    where
+        F: FnMut(&T, &T) -> Ordering,
+        O: SortObserver<T> + ?Sized,
+    {
+        nth(
+            self.as_mut_slice(),
+            k,
+            &mut Sorter::observed(compare, observer),
+        )
+    }
+
+    fn sorted_top_k_observed<O>(&mut self, k: usize, observer: &mut O) -> &[T]
+    where
+        T: Ord,
+        O: SortObserver<T> + ?Sized,
+    {
+        let v = self.as_mut_slice();
+        let s = &mut Sorter::observed(|a: &T, b: &T| b.cmp(a), observer);
+        partial(v, k, s);
+        &v[..k.min(v.len())]
+    }
+
+    fn sorted_top_k_observed_by<F, O>(&mut self, k: usize, mut compare: F, observer: &mut O) -> &[T]
+    where
+        F: FnMut(&T, &T) -> Ordering,
+        O: SortObserver<T> + ?Sized,
+    {
+        let v = self.as_mut_slice();
+        let s = &mut Sorter::observed(|a: &T, b: &T| compare(b, a), observer);
+        partial(v, k, s);
+        &v[..k.min(v.len())]
+    }
+}
+
+// v[..k] is a max-heap of the k smallest elements seen so far; anything
+// smaller than its root replaces the root.
+fn partial<T, S: SortOps<T>>(v: &mut [T], k: usize, s: &mut S) {
+    let k = k.min(v.len());
+    if k == 0 {
+        return;
+    }
+
+    heapify_with(&mut v[..k], s);
+    for i in k..v.len() {
+        if s.is_less(&v[i], &v[0]) {
+            s.swap(v, 0, i);
+            sift_down_with(&mut v[..k], 0, s);
+        }
+    }
+    sort_heap(&mut v[..k], s);
+}
+
+fn nth<'a, T, S: SortOps<T>>(v: &'a mut [T], k: usize, s: &mut S) -> Option<&'a T> {
+    if k >= v.len() {
+        return None;
+    }
+    select(v, k, s);
+    Some(&v[k])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::test_util::{random_inputs, random_values};
+    use crate::algorithms::{FrameRecorder, SortAlgorithm, SortStats, Sortable};
+    use crate::core::Array;
+    use crate::dynamic_array::DynamicArray;
+
+    fn partial_stats(values: &[u32], k: usize) -> SortStats {
+        let mut v = values.to_vec();
+        let mut stats = SortStats::default();
+        v.as_mut_slice().partial_sort_observed(k, &mut stats);
+        stats
+    }
+
+    fn nth_stats(values: &[u32], k: usize) -> SortStats {
+        let mut v = values.to_vec();
+        let mut stats = SortStats::default();
+        v.as_mut_slice().nth_element_observed(k, &mut stats);
+        stats
+    }
+
+    #[test]
+    fn test_partial_sort_matches_sorting() {
+        for (seed, values) in random_inputs(20, 300) {
+            let len = values.len();
+            let mut sorted = values.clone();
+            sorted.sort();
+
+            for k in [0, 1, len / 4, len / 2, len, len + 5] {
+                let mut arr = DynamicArray::new();
+                arr.extend(values.iter().copied());
+                arr.partial_sort(k);
+
+                let k = k.min(len);
+                assert_eq!(&arr.as_slice()[..k], &sorted[..k], "seed {seed}, k {k}");
+                // Nothing is lost or duplicated
+                let mut all = arr.as_slice().to_vec();
+                all.sort();
+                assert_eq!(all, sorted);
+            }
+        }
+    }
+
+    #[test]
+    fn test_partial_sort_by_and_by_key() {
+        let words = ["pear", "fig", "banana", "kiwi", "apple", "plum"];
+        let mut arr = Array::from_slice(&words, 6).unwrap();
+        arr.partial_sort_by(2, |a, b| b.cmp(a));
+        assert_eq!(&arr.as_slice()[..2], &["plum", "pear"]);
+
+        arr.partial_sort_by_key(3, |w| w.len());
+        assert_eq!(arr.as_slice()[0], "fig");
+        assert!(arr.as_slice()[1..3].iter().all(|w| w.len() == 4));
+    }
+
+    #[test]
+    fn test_nth_element_matches_sorting() {
+        for (seed, values) in random_inputs(20, 500) {
+            let len = values.len();
+            let mut sorted = values.clone();
+            sorted.sort();
+
+            for k in [0, len / 3, len / 2, len - 1] {
+                let mut arr = DynamicArray::new();
+                arr.extend(values.iter().copied());
+                assert_eq!(arr.nth_element(k), Some(&sorted[k]), "seed {seed}, k {k}");
+
+                let v = arr.as_slice();
+                assert!(v[..k].iter().all(|x| x <= &v[k]));
+                assert!(v[k + 1..].iter().all(|x| x >= &v[k]));
+            }
+        }
+    }
+
+    #[test]
+    fn test_nth_element_by_and_edge_cases() {
+        let mut empty: DynamicArray<i32> = DynamicArray::new();
+        assert_eq!(empty.nth_element(0), None);
+
+        let mut arr = Array::from_slice(&[3, 1, 2], 3).unwrap();
+        assert_eq!(arr.nth_element(3), None);
+        assert_eq!(arr.as_slice(), &[3, 1, 2]);
+
+        assert_eq!(arr.nth_element_by(0, |a, b| b.cmp(a)), Some(&3));
+
+        let mut pairs = DynamicArray::new();
+        pairs.extend([("c", 3), ("a", 1), ("d", 4), ("b", 2)]);
+        assert_eq!(pairs.nth_element_by_key(2, |&(_, n)| n), Some(&("c", 3)));
+    }
+
+    #[test]
+    fn test_sorted_top_k() {
+        let values = random_values(21, 1_000, 1_000_000);
+        let mut descending = values.clone();
+        descending.sort_by(|a, b| b.cmp(a));
+
+        let mut arr = DynamicArray::new();
+        arr.extend(values.iter().copied());
+        assert_eq!(arr.sorted_top_k(10), &descending[..10]);
+        assert_eq!(arr.sorted_top_k(5_000), descending);
+
+        let mut empty: DynamicArray<u32> = DynamicArray::new();
+        assert!(empty.sorted_top_k(3).is_empty());
+
+        let mut arr = Array::from_slice(&[-5, 3, -8, 1], 4).unwrap();
+        assert_eq!(arr.sorted_top_k_by_key(2, |x: &i32| x.abs()), &[-8, -5]);
+        assert_eq!(arr.sorted_top_k_by(1, |a, b| b.cmp(a)), &[-8]);
+    }
+
+    #[test]
+    fn test_observed_frames_replay_to_the_result() {
+        for (seed, values) in random_inputs(20, 200) {
+            let k = values.len() / 3;
+            let descending = |a: &u32, b: &u32| b.cmp(a);
+
+            let mut v = values.clone();
+            let mut recorder = FrameRecorder::new(&v);
+            v.as_mut_slice()
+                .partial_sort_observed_by(k, descending, &mut recorder);
+            assert_eq!(recorder.current(), v.as_slice(), "partial, seed {seed}");
+
+            let mut v = values.clone();
+            let mut recorder = FrameRecorder::new(&v);
+            v.as_mut_slice()
+                .nth_element_observed_by(k, descending, &mut recorder);
+            assert_eq!(recorder.current(), v.as_slice(), "nth, seed {seed}");
+
+            let mut v = values.clone();
+            let mut recorder = FrameRecorder::new(&v);
+            v.as_mut_slice()
+                .sorted_top_k_observed_by(k, descending, &mut recorder);
+            assert_eq!(recorder.current(), v.as_slice(), "top k, seed {seed}");
+        }
+    }
+
+    #[test]
+    fn test_partial_sort_is_n_log_k() {
+        let n: usize = 100_000;
+        let k: usize = 10;
+        let log_k = k.ilog2() as usize + 1;
+
+        // Random input: almost every element loses to the root at once
+        let random = random_values(22, n, u32::MAX);
+        let stats = partial_stats(&random, k);
+        assert!(stats.comparisons < n + n / 10, "{stats:?}");
+
+        // Descending input: every element enters the heap, the worst case
+        let descending: Vec<u32> = (0..n as u32).rev().collect();
+        let stats = partial_stats(&descending, k);
+        assert!(stats.comparisons <= n * (1 + 2 * log_k), "{stats:?}");
+        assert!(stats.swaps <= n * log_k, "{stats:?}");
+
+        // Far less than sorting everything
+        let mut arr = DynamicArray::new();
+        arr.extend(random.iter().copied());
+        let mut full = SortStats::default();
+        arr.sort_observed(SortAlgorithm::Heap, &mut full);
+        assert!(full.comparisons > 20 * partial_stats(&random, k).comparisons);
+    }
+
+    #[test]
+    fn test_nth_element_is_linear() {
+        let small = random_values(23, 50_000, u32::MAX);
+        let large = random_values(24, 100_000, u32::MAX);
+        let (small_stats, large_stats) = (
+            nth_stats(&small, small.len() / 2),
+            nth_stats(&large, large.len() / 2),
+        );
+        assert!(large_stats.comparisons < 4 * large.len(), "{large_stats:?}");
+        let ratio = large_stats.comparisons as f64 / small_stats.comparisons as f64;
+        assert!(ratio < 2.5, "{ratio}");
+
+        // Inputs that defeat median-of-three pivots fall back to median of
+        // medians, which has a larger constant but is still linear
+        let organ_pipe = |n: u32| (0..n).map(|i| i.min(n - i)).collect::<Vec<_>>();
+        let n: usize = 100_000;
+        let ascending: Vec<u32> = (0..n as u32).collect();
+        let descending: Vec<u32> = (0..n as u32).rev().collect();
+        for values in [ascending, descending, organ_pipe(n as u32)] {
+            for k in [0, n / 4, n / 2, n - 1] {
+                let stats = nth_stats(&values, k);
+                assert!(stats.comparisons < 32 * n, "k {k}: {stats:?}");
+            }
+        }
+        let ratio = nth_stats(&organ_pipe(2 * n as u32), n).comparisons as f64
+            / nth_stats(&organ_pipe(n as u32), n / 2).comparisons as f64;
+        assert!(ratio < 2.5, "{ratio}");
+    }
+
+    #[test]
+    fn test_top_k_beats_full_sort() {
+        // The 10 largest of 100,000 without sorting them all
+        let values = random_values(25, 100_000, u32::MAX);
+        let mut stats = SortStats::default();
+        let mut v = values.clone();
+        let top = v.as_mut_slice().sorted_top_k_observed(10, &mut stats);
+
+        let mut expected = values.clone();
+        expected.sort_by(|a, b| b.cmp(a));
+        assert_eq!(top, &expected[..10]);
+
+        let mut arr = DynamicArray::new();
+        arr.extend(values.iter().copied());
+        let mut comparisons = 0;
+        arr.heap_sort_by(|a, b| {
+            comparisons += 1;
+            a.cmp(b)
+        });
+        assert!(
+            stats.comparisons * 20 < comparisons,
+            "{stats:?} vs {comparisons}"
+        );
+    }
+}
@@ -0,0 +1,620 @@
+//! Sub-sequence (pattern) search for arrays.
+//!
+//! `LinearSearchable` looks for a single element. The searches here locate
+//! a whole run of elements, such as a delimiter in a `DynamicArray<u8>`
+//! buffer. Three algorithms are implemented:
+//!
+//! - Knuth-Morris-Pratt precomputes, for every pattern prefix, how far the
+//!   pattern can slide after a mismatch, so the text is never re-read.
+//!   O(n + m) time, O(m) space.
+//! - The Z-algorithm computes, for every position of `pattern ++ text`, the
+//!   length of the longest prefix of the pattern starting there.
+//!   O(n + m) time and space.
+//! - Boyer-Moore-Horspool compares from the end of the pattern and skips
+//!   ahead by a per-byte shift table, often jumping a whole pattern length.
+//!   O(n / m) on typical text, O(n · m) worst case. Needs a small alphabet
+//!   for its table, so it is only available for `u8`.
+//!
+//! `find`, `find_all` and `rfind` pick Naive or KMP from the pattern length.
+//! They never use Horspool, even on a `Sequence<u8>`: the generic methods
+//! cannot tell that `T` is `u8`, so byte buffers must call `find_bytes` and
+//! friends to get it. The Z-algorithm does the same work as KMP with an
+//! O(n + m) table instead of O(m), so it is never chosen automatically and
+//! is only run through `find_all_with`.
+//! Matches may overlap, and an empty pattern matches at every position.
+
+use crate::sequence::Sequence;
+
+/// Patterns up to this length are matched by brute force, which beats the
+/// preprocessing cost of the other algorithms.
+pub const SHORT_PATTERN_LEN: usize = 3;
+
+/// Pattern matching algorithms available for any `T: Eq`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PatternAlgorithm {
+    /// Compare the pattern at every position: O(n · m), no preprocessing.
+    Naive,
+    /// Knuth-Morris-Pratt with a prefix-function table.
+    Kmp,
+    /// Z-algorithm over the pattern followed by the text.
+    ///
+    /// Never picked by `for_pattern_len`; select it with `find_all_with`.
+    ZAlgorithm,
+}
+
+impl PatternAlgorithm {
+    /// The algorithm `find`, `find_all` and `rfind` use for a pattern of
+    /// `len` elements: `Naive` up to `SHORT_PATTERN_LEN`, `Kmp` beyond.
+    pub fn for_pattern_len(len: usize) -> Self {
+        if len <= SHORT_PATTERN_LEN {
+            Self::Naive
+        } else {
+            Self::Kmp
+        }
+    }
+}
+
+/// Trait for sequences that support searching for sub-sequences.
+///
+/// For `u8` buffers prefer `BytePatternSearchable`, which adds
+/// Boyer-Moore-Horspool; these methods only use Naive and KMP.
+pub trait PatternSearchable<T> {
+    /// Returns the start index of the first occurrence of `pattern`.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::core::Array;
+    /// use arrays::algorithms::PatternSearchable;
+    ///
+    /// let arr = Array::from_slice(&[1, 2, 3, 1, 2, 3, 4], 10).unwrap();
+    /// assert_eq!(arr.find(&[2, 3]), Some(1));
+    /// assert_eq!(arr.find(&[3, 4]), Some(5));
+    /// assert_eq!(arr.find(&[4, 1]), None);
+    /// ```
+    fn find(&self, pattern: &[T]) -> Option<usize>
+    where
+        T: Eq;
+
+    /// Returns the start indices of every occurrence of `pattern`,
+    /// including overlapping ones.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::core::Array;
+    /// use arrays::algorithms::PatternSearchable;
+    ///
+    /// let arr = Array::from_slice(&[0, 0, 0, 1, 0, 0], 10).unwrap();
+    /// assert_eq!(arr.find_all(&[0, 0]), vec![0, 1, 4]);
+    /// ```
+    fn find_all(&self, pattern: &[T]) -> Vec<usize>
+    where
+        T: Eq;
+
+    /// Returns the start index of the last occurrence of `pattern`.
+    ///
+    /// Scans from the end, so a match near the end is found quickly.
+    fn rfind(&self, pattern: &[T]) -> Option<usize>
+    where
+        T: Eq;
+
+    /// Like `find_all`, but with an explicitly chosen algorithm.
+    ///
+    /// Every algorithm returns the same matches.
+    fn find_all_with(&self, pattern: &[T], algorithm: PatternAlgorithm) -> Vec<usize>
+    where
+        T: Eq;
+}
+
+// Every `Sequence` gets pattern search through this blanket implementation
+impl<T, S> PatternSearchable<T> for S
+where
+    S: Sequence<T> + ?Sized,
+{
+    fn find(&self, pattern: &[T]) -> Option<usize>
+    where
+        T: Eq,
+    {
+        let algorithm = PatternAlgorithm::for_pattern_len(pattern.len());
+        let mut found = None;
+        run_generic(self, pattern, algorithm, false, |start| {
+            found = Some(start);
+            false
+        });
+        found
+    }
+
+    fn find_all(&self, pattern: &[T]) -> Vec<usize>
+    where
+        T: Eq,
+    {
+        self.find_all_with(pattern, PatternAlgorithm::for_pattern_len(pattern.len()))
+    }
+
+    fn rfind(&self, pattern: &[T]) -> Option<usize>
+    where
+        T: Eq,
+    {
+        let algorithm = PatternAlgorithm::for_pattern_len(pattern.len());
+        let mut found = None;
+        run_generic(self, pattern, algorithm, true, |start| {
+            found = Some(start);
+            false
+        });
+        found
+    }
+
+    fn find_all_with(&self, pattern: &[T], algorithm: PatternAlgorithm) -> Vec<usize>
+    where
+        T: Eq,
+    {
+        let mut matches = Vec::new();
+        run_generic(self, pattern, algorithm, false, |start| {
+            matches.push(start);
+            true
+        });
+        matches
+    }
+}
+
+/// Trait for byte sequences, adding Boyer-Moore-Horspool to pattern search.
+pub trait BytePatternSearchable {
+    /// Returns the start index of the first occurrence of `pattern`.
+    ///
+    /// Patterns longer than `SHORT_PATTERN_LEN` use Boyer-Moore-Horspool,
+    /// shorter ones a brute-force scan.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::dynamic_array::DynamicArray;
+    /// use arrays::algorithms::BytePatternSearchable;
+    ///
+    /// let mut buffer = DynamicArray::new();
+    /// buffer.extend(b"GET / HTTP/1.1\r\nHost: example.com\r\n\r\n".iter().copied());
+    /// assert_eq!(buffer.find_bytes(b"\r\n\r\n"), Some(33));
+    /// assert_eq!(buffer.find_bytes(b"Host"), Some(16));
+    /// ```
+    fn find_bytes(&self, pattern: &[u8]) -> Option<usize>;
+
+    /// Returns the start indices of every occurrence of `pattern`,
+    /// including overlapping ones.
+    fn find_all_bytes(&self, pattern: &[u8]) -> Vec<usize>;
+
+    /// Returns the start index of the last occurrence of `pattern`.
+    fn rfind_bytes(&self, pattern: &[u8]) -> Option<usize>;
+}
+
+impl<S> BytePatternSearchable for S
+where
+    S: Sequence<u8> + ?Sized,
+{
+    fn find_bytes(&self, pattern: &[u8]) -> Option<usize> {
+        let mut found = None;
+        run_bytes(self, pattern, false, |start| {
+            found = Some(start);
+            false
+        });
+        found
+    }
+
+    fn find_all_bytes(&self, pattern: &[u8]) -> Vec<usize> {
+        let mut matches = Vec::new();
+        run_bytes(self, pattern, false, |start| {
+            matches.push(start);
+            true
+        });
+        matches
+    }
+
+    fn rfind_bytes(&self, pattern: &[u8]) -> Option<usize> {
+        let mut found = None;
+        run_bytes(self, pattern, true, |start| {
+            found = Some(start);
+            false
+        });
+        found
+    }
+}
+
+// Runs `algorithm` and reports each match start to `on_match`, which
+// returns false to stop. With `reverse`, text and pattern are both read
+// back to front, so matches arrive from last to first.
+fn run_generic<T, S, F>(
+    seq: &S,
+    pattern: &[T],
+    algorithm: PatternAlgorithm,
+    reverse: bool,
+    on_match: F,
+) where
+    T: Eq,
+    S: Sequence<T> + ?Sized,
+    F: FnMut(usize) -> bool,
+{
+    let n = seq.len();
+    let m = pattern.len();
+    let text = |i: usize| {
+        let i = if reverse { n - 1 - i } else { i };
+        seq.get(i).expect("index within bounds")
+    };
+    let pat = |j: usize| {
+        if reverse {
+            &pattern[m - 1 - j]
+        } else {
+            &pattern[j]
+        }
+    };
+    let on_match = report(n, m, reverse, on_match);
+
+    if m == 0 {
+        return empty_pattern(n, on_match);
+    }
+
+    match algorithm {
+        PatternAlgorithm::Naive => naive(n, text, m, pat, on_match),
+        PatternAlgorithm::Kmp => kmp(n, text, m, pat, on_match),
+        PatternAlgorithm::ZAlgorithm => z_algorithm(n, text, m, pat, on_match),
+    }
+}
+
+fn run_bytes<S, F>(seq: &S, pattern: &[u8], reverse: bool, on_match: F)
+where
+    S: Sequence<u8> + ?Sized,
+    F: FnMut(usize) -> bool,
+{
+    if pattern.len() <= SHORT_PATTERN_LEN {
+        return run_generic(seq, pattern, PatternAlgorithm::Naive, reverse, on_match);
+    }
+
+    let n = seq.len();
+    let m = pattern.len();
+    let on_match = report(n, m, reverse, on_match);
+
+    // Both directions read the slice directly when the storage allows it
+    match (seq.as_slice(), reverse) {
+        (Some(slice), false) => horspool(n, |i| slice[i], m, |j| pattern[j], on_match),
+        (Some(slice), true) => {
+            horspool(n, |i| slice[n - 1 - i], m, |j| pattern[m - 1 - j], on_match)
+        }
+        (None, _) => {
+            let text = |i: usize| {
+                let i = if reverse { n - 1 - i } else { i };
+                *seq.get(i).expect("index within bounds")
+            };
+            let pat = |j: usize| {
+                if reverse {
+                    pattern[m - 1 - j]
+                } else {
+                    pattern[j]
+                }
+            };
+            horspool(n, text, m, pat, on_match)
+        }
+    }
+}
+
+// Translates match starts in the (possibly reversed) scan back to indices
+// in the original text.
+fn report<F>(n: usize, m: usize, reverse: bool, mut on_match: F) -> impl FnMut(usize) -> bool
+where
+    F: FnMut(usize) -> bool,
+{
+    move |start| {
+        if reverse {
+            on_match(n - m - start)
+        } else {
+            on_match(start)
+        }
+    }
+}
+
+// The empty pattern matches before every element and at the end.
+fn empty_pattern<F>(n: usize, mut on_match: F)
+where
+    F: FnMut(usize) -> bool,
+{
+    for start in 0..=n {
+        if !on_match(start) {
+            return;
+        }
+    }
+}
+
+fn naive<'a, T, X, P, F>(n: usize, text: X, m: usize, pattern: P, mut on_match: F)
+where
+    T: Eq + 'a,
+    X: Fn(usize) -> &'a T,
+    P: Fn(usize) -> &'a T,
+    F: FnMut(usize) -> bool,
+{
+    if m > n {
+        return;
+    }
+
+    for start in 0..=n - m {
+        if (0..m).all(|j| text(start + j) == pattern(j)) && !on_match(start) {
+            return;
+        }
+    }
+}
+
+fn kmp<'a, T, X, P, F>(n: usize, text: X, m: usize, pattern: P, mut on_match: F)
+where
+    T: Eq + 'a,
+    X: Fn(usize) -> &'a T,
+    P: Fn(usize) -> &'a T,
+    F: FnMut(usize) -> bool,
+{
+    if m > n {
+        return;
+    }
+
+    // prefix[j]: length of the longest proper prefix of pattern[..=j]
+    // that is also a suffix of it
+    let mut prefix = vec![0; m];
+    let mut len = 0;
+    for j in 1..m {
+        while len > 0 && pattern(j) != pattern(len) {
+            len = prefix[len - 1];
+        }
+        if pattern(j) == pattern(len) {
+            len += 1;
+        }
+        prefix[j] = len;
+    }
+
+    // `matched` pattern elements end just before text position i
+    let mut matched = 0;
+    for i in 0..n {
+        while matched > 0 && text(i) != pattern(matched) {
+            matched = prefix[matched - 1];
+        }
+        if text(i) == pattern(matched) {
+            matched += 1;
+        }
+        if matched == m {
+            if !on_match(i + 1 - m) {
+                return;
+            }
+            matched = prefix[m - 1];
+        }
+    }
+}
+
+fn z_algorithm<'a, T, X, P, F>(n: usize, text: X, m: usize, pattern: P, mut on_match: F)
+where
+    T: Eq + 'a,
+    X: Fn(usize) -> &'a T,
+    P: Fn(usize) -> &'a T,
+    F: FnMut(usize) -> bool,
+{
+    if m > n {
+        return;
+    }
+
+    // The combined sequence is pattern followed by text
+    let total = m + n;
+    let at = |i: usize| if i < m { pattern(i) } else { text(i - m) };
+
+    // z[i]: length of the longest common prefix of the combined sequence
+    // and its suffix starting at i. [left, right) is the rightmost window
+    // known to match a prefix.
+    let mut z = vec![0; total];
+    let (mut left, mut right) = (0, 0);
+    for i in 1..total {
+        if i < right {
+            z[i] = z[i - left].min(right - i);
+        }
+        while i + z[i] < total && at(z[i]) == at(i + z[i]) {
+            z[i] += 1;
+        }
+        if i + z[i] > right {
+            left = i;
+            right = i + z[i];
+        }
+
+        // A prefix of at least m starting inside the text is a full match
+        if i >= m && z[i] >= m && !on_match(i - m) {
+            return;
+        }
+    }
+}
+
+fn horspool<X, P, F>(n: usize, text: X, m: usize, pattern: P, mut on_match: F)
+where
+    X: Fn(usize) -> u8,
+    P: Fn(usize) -> u8,
+    F: FnMut(usize) -> bool,
+{
+    if m == 0 || m > n {
+        return;
+    }
+
+    // How far to slide when the byte under the pattern's last slot is b
+    let mut shift = [m; 256];
+    for j in 0..m - 1 {
+        shift[pattern(j) as usize] = m - 1 - j;
+    }
+
+    let mut start = 0;
+    while start + m <= n {
+        let last = text(start + m - 1);
+        if last == pattern(m - 1)
+            && (0..m - 1).rev().all(|j| text(start + j) == pattern(j))
+            && !on_match(start)
+        {
+            return;
+        }
+        start += shift[last as usize];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::quick_sort::xorshift64;
+    use crate::core::Array;
+    use crate::dynamic_array::DynamicArray;
+    use std::collections::VecDeque;
+
+    const ALGORITHMS: [PatternAlgorithm; 3] = [
+        PatternAlgorithm::Naive,
+        PatternAlgorithm::Kmp,
+        PatternAlgorithm::ZAlgorithm,
+    ];
+
+    fn bytes(text: &[u8]) -> DynamicArray<u8> {
+        let mut arr = DynamicArray::with_capacity(text.len());
+        arr.extend(text.iter().copied());
+        arr
+    }
+
+    // Reference answer straight from the definition
+    fn brute_force(text: &[u8], pattern: &[u8]) -> Vec<usize> {
+        if pattern.len() > text.len() {
+            return Vec::new();
+        }
+        (0..=text.len() - pattern.len())
+            .filter(|&i| &text[i..i + pattern.len()] == pattern)
+            .collect()
+    }
+
+    #[test]
+    fn test_find_basic() {
+        let arr = Array::from_slice(&[1, 2, 3, 1, 2, 3, 1, 2, 3, 4], 10).unwrap();
+
+        assert_eq!(arr.find(&[1, 2, 3]), Some(0));
+        assert_eq!(arr.rfind(&[1, 2, 3]), Some(6));
+        assert_eq!(arr.find_all(&[1, 2, 3]), vec![0, 3, 6]);
+        assert_eq!(arr.find(&[1, 2, 3, 1, 2, 3, 4]), Some(3));
+        assert_eq!(arr.find(&[3, 4, 5]), None);
+        assert_eq!(arr.rfind(&[3, 4, 5]), None);
+    }
+
+    #[test]
+    fn test_edge_cases() {
+        let arr = Array::from_slice(&[5, 6, 7], 5).unwrap();
+
+        // Empty pattern matches everywhere
+        assert_eq!(arr.find(&[]), Some(0));
+        assert_eq!(arr.rfind(&[]), Some(3));
+        for algorithm in ALGORITHMS {
+            assert_eq!(arr.find_all_with(&[], algorithm), vec![0, 1, 2, 3]);
+        }
+
+        // Pattern longer than the text, or equal to it
+        assert_eq!(arr.find(&[5, 6, 7, 8]), None);
+        assert_eq!(arr.find(&[5, 6, 7]), Some(0));
+        assert_eq!(arr.rfind(&[5, 6, 7]), Some(0));
+
+        let empty: DynamicArray<i32> = DynamicArray::new();
+        assert_eq!(empty.find(&[1]), None);
+        assert_eq!(empty.find(&[]), Some(0));
+
+        let no_bytes = bytes(b"");
+        assert_eq!(no_bytes.find_bytes(b"abcd"), None);
+        assert_eq!(no_bytes.find_all_bytes(b""), vec![0]);
+        assert_eq!(no_bytes.rfind_bytes(b""), Some(0));
+    }
+
+    #[test]
+    fn test_algorithms_agree_on_random_inputs() {
+        let mut state = 0x9e37_79b9_7f4a_7c15u64;
+        let mut next = move || xorshift64(&mut state);
+
+        for round in 0..300 {
+            // A two-letter alphabet produces many overlapping matches
+            let alphabet = if round % 2 == 0 { 2 } else { 4 };
+            let text: Vec<u8> = (0..next() % 200)
+                .map(|_| b'a' + (next() % alphabet) as u8)
+                .collect();
+            let pattern: Vec<u8> = (0..1 + next() % 8)
+                .map(|_| b'a' + (next() % alphabet) as u8)
+                .collect();
+
+            let expected = brute_force(&text, &pattern);
+            let arr = bytes(&text);
+
+            for algorithm in ALGORITHMS {
+                assert_eq!(
+                    arr.find_all_with(&pattern, algorithm),
+                    expected,
+                    "{algorithm:?}"
+                );
+            }
+            assert_eq!(arr.find_all(&pattern), expected);
+            assert_eq!(arr.find_all_bytes(&pattern), expected);
+            assert_eq!(arr.find(&pattern), expected.first().copied());
+            assert_eq!(arr.find_bytes(&pattern), expected.first().copied());
+            assert_eq!(arr.rfind(&pattern), expected.last().copied());
+            assert_eq!(arr.rfind_bytes(&pattern), expected.last().copied());
+        }
+    }
+
+    #[test]
+    fn test_overlapping_matches() {
+        let arr = bytes(b"aaaaa");
+
+        for algorithm in ALGORITHMS {
+            assert_eq!(arr.find_all_with(b"aa", algorithm), vec![0, 1, 2, 3]);
+        }
+        assert_eq!(arr.find_all_bytes(b"aaaa"), vec![0, 1]);
+        assert_eq!(arr.rfind_bytes(b"aaaa"), Some(1));
+    }
+
+    #[test]
+    fn test_byte_buffer_search() {
+        let buffer = bytes(b"key=value; path=/; key=other; HttpOnly");
+
+        assert_eq!(buffer.find_bytes(b"; "), Some(9));
+        assert_eq!(buffer.find_all_bytes(b"key="), vec![0, 19]);
+        assert_eq!(buffer.rfind_bytes(b"key="), Some(19));
+        assert_eq!(buffer.find_bytes(b"HttpOnly"), Some(30));
+        assert_eq!(buffer.find_bytes(b"Secure"), None);
+    }
+
+    #[test]
+    fn test_non_contiguous_sequences() {
+        let mut deque: VecDeque<u8> = b"xxneedlexxneedle".iter().copied().collect();
+        deque.rotate_left(3);
+        deque.rotate_right(3);
+
+        assert_eq!(deque.find_all_bytes(b"needle"), vec![2, 10]);
+        assert_eq!(deque.rfind_bytes(b"needle"), Some(10));
+        assert_eq!(deque.find(b"needle".as_slice()), Some(2));
+    }
+
+    #[test]
+    fn test_generic_elements() {
+        let mut words = DynamicArray::new();
+        for word in ["to", "be", "or", "not", "to", "be"] {
+            words.push(word.to_string());
+        }
+        let pattern = ["to".to_string(), "be".to_string()];
+
+        assert_eq!(words.find_all(&pattern), vec![0, 4]);
+        assert_eq!(words.rfind(&pattern), Some(4));
+    }
+
+    #[test]
+    fn test_algorithm_choice() {
+        assert_eq!(
+            PatternAlgorithm::for_pattern_len(0),
+            PatternAlgorithm::Naive
+        );
+        assert_eq!(
+            PatternAlgorithm::for_pattern_len(SHORT_PATTERN_LEN),
+            PatternAlgorithm::Naive
+        );
+        assert_eq!(
+            PatternAlgorithm::for_pattern_len(SHORT_PATTERN_LEN + 1),
+            PatternAlgorithm::Kmp
+        );
+
+        // The Z-algorithm is only reachable through find_all_with
+        for len in 0..256 {
+            assert_ne!(
+                PatternAlgorithm::for_pattern_len(len),
+                PatternAlgorithm::ZAlgorithm
+            );
+        }
+    }
+}
@@ -0,0 +1,555 @@
+//! Sortedness preconditions for sorted-input searches.
+//!
+//! Binary, jump, exponential, interpolation, nearest-value and float
+//! searches all assume ascending input. On unsorted input they do not fail,
+//! they return wrong answers. Checking costs O(n), more than the search
+//! itself, so the check is configurable with `PreconditionMode`.
+//!
+//! Every sorted-input search applies the default mode, `DebugAssert`: it
+//! asserts the input is sorted in debug builds and trusts it in release
+//! builds. Searches that compare with a caller-supplied closure, such as
+//! `binary_search_by`, cannot check and are exempt. The `*_checked`
+//! variants take an explicit mode instead and return
+//! `Result<_, PreconditionError>`:
+//!
+//! ```
+//! use arrays::core::Array;
+//! use arrays::algorithms::{BinarySearchable, PreconditionError, PreconditionMode};
+//!
+//! let unsorted = Array::from_slice(&[1, 5, 3, 7], 4).unwrap();
+//! assert_eq!(
+//!     unsorted.binary_search_checked(&5, PreconditionMode::AlwaysCheck),
+//!     Err(PreconditionError::NotSorted { first_violation: 2 })
+//! );
+//! ```
+//!
+//! To check once and then run many searches, wrap the data in a
+//! `SortedView`. The view implements `Sequence`, so every search in
+//! `algorithms` runs on it unchanged:
+//!
+//! ```
+//! use arrays::core::Array;
+//! use arrays::algorithms::{
+//!     BinarySearchable, JumpSearchable, PreconditionError, PreconditionMode, SortedPrecondition,
+//! };
+//!
+//! let sorted = Array::from_slice(&[1, 3, 5, 7], 4).unwrap();
+//! let view = sorted.sorted_view(PreconditionMode::AlwaysCheck).unwrap();
+//! assert_eq!(view.binary_search(&5), Some(2));
+//! assert_eq!(view.jump_search(&7), Some(3));
+//!
+//! let unsorted = Array::from_slice(&[1, 5, 3, 7], 4).unwrap();
+//! assert_eq!(
+//!     unsorted.sorted_view(PreconditionMode::AlwaysCheck).unwrap_err(),
+//!     PreconditionError::NotSorted { first_violation: 2 }
+//! );
+//! ```
+
+use crate::sequence::Sequence;
+use std::cmp::Ordering;
+use std::fmt;
+use std::marker::PhantomData;
+
+/// How strictly to verify that input is sorted before searching it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PreconditionMode {
+    /// Trust the caller; never check, even in debug builds.
+    Off,
+    /// Check in debug builds and panic on unsorted input; skip the check in
+    /// release builds.
+    #[default]
+    DebugAssert,
+    /// Always check, and report unsorted input as an error.
+    AlwaysCheck,
+}
+
+/// A violated search precondition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PreconditionError {
+    /// The element at `first_violation` is less than the one before it.
+    NotSorted {
+        /// Index of the first element that is out of order.
+        first_violation: usize,
+    },
+}
+
+impl fmt::Display for PreconditionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotSorted { first_violation } => write!(
+                f,
+                "input is not sorted: element {} is less than element {}",
+                first_violation,
+                first_violation - 1
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PreconditionError {}
+
+/// Checks if a sequence is sorted according to `compare`.
+///
+/// Equal neighbours are allowed. This is the check behind every other
+/// `is_sorted` helper in the crate.
+///
+/// # Examples
+/// ```
+/// use arrays::core::Array;
+/// use arrays::algorithms::is_sorted_by;
+///
+/// let arr = Array::from_slice(&[9, 7, 7, 2], 4).unwrap();
+/// assert!(!is_sorted_by(&arr, |a: &i32, b| a.cmp(b)));
+/// assert!(is_sorted_by(&arr, |a: &i32, b| b.cmp(a)));
+/// ```
+pub fn is_sorted_by<T, S, F>(seq: &S, compare: F) -> bool
+where
+    S: Sequence<T> + ?Sized,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    first_unsorted_by(seq, compare).is_none()
+}
+
+/// Returns the index of the first element that orders before its
+/// predecessor according to `compare`, or `None` if the sequence is sorted.
+pub fn first_unsorted_by<T, S, F>(seq: &S, mut compare: F) -> Option<usize>
+where
+    S: Sequence<T> + ?Sized,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let mut prev = seq.get(0)?;
+    for i in 1..seq.len() {
+        let curr = seq.get(i)?;
+        if compare(prev, curr) == Ordering::Greater {
+            return Some(i);
+        }
+        prev = curr;
+    }
+    None
+}
+
+// Applies `mode` to the result of a sortedness scan. The `*_checked`
+// searches call this before searching.
+pub(crate) fn enforce<T, S, F>(
+    seq: &S,
+    mode: PreconditionMode,
+    compare: F,
+) -> Result<(), PreconditionError>
+where
+    S: Sequence<T> + ?Sized,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let check = match mode {
+        PreconditionMode::Off => false,
+        PreconditionMode::DebugAssert => cfg!(debug_assertions),
+        PreconditionMode::AlwaysCheck => true,
+    };
+    if !check {
+        return Ok(());
+    }
+
+    match first_unsorted_by(seq, compare) {
+        None => Ok(()),
+        Some(first_violation) => {
+            let error = PreconditionError::NotSorted { first_violation };
+            if mode == PreconditionMode::DebugAssert {
+                panic!("sorted-input search on unsorted data: {error}");
+            }
+            Err(error)
+        }
+    }
+}
+
+// The default `PreconditionMode::DebugAssert`, applied by every sorted-input
+// search that is not given a mode. Compiled out of release builds.
+pub(crate) fn debug_assert_sorted_by<T, S, F>(seq: &S, compare: F)
+where
+    S: Sequence<T> + ?Sized,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    debug_assert!(
+        is_sorted_by(seq, compare),
+        "sorted-input search on unsorted data"
+    );
+}
+
+/// A sequence whose sortedness has been checked according to a
+/// `PreconditionMode`.
+///
+/// Implements `Sequence` by forwarding to the wrapped data, so any
+/// sorted-input search can run on it; indices are unchanged.
+pub struct SortedView<'a, T, S: ?Sized> {
+    seq: &'a S,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<'a, T, S> SortedView<'a, T, S>
+where
+    S: Sequence<T> + ?Sized,
+{
+    /// Wraps `seq` after checking it is in ascending order.
+    ///
+    /// # Errors
+    /// Returns `NotSorted` in `AlwaysCheck` mode if `seq` is unsorted.
+    ///
+    /// # Panics
+    /// Panics in `DebugAssert` mode in debug builds if `seq` is unsorted.
+    pub fn new(seq: &'a S, mode: PreconditionMode) -> Result<Self, PreconditionError>
+    where
+        T: Ord,
+    {
+        Self::new_by(seq, mode, T::cmp)
+    }
+
+    /// Like `new`, but checks the order given by `compare`, for data
+    /// searched with a custom comparator such as `binary_search_by`.
+    pub fn new_by<F>(
+        seq: &'a S,
+        mode: PreconditionMode,
+        compare: F,
+    ) -> Result<Self, PreconditionError>
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        enforce(seq, mode, compare)?;
+        Ok(SortedView {
+            seq,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Returns the wrapped sequence.
+    pub fn into_inner(self) -> &'a S {
+        self.seq
+    }
+}
+
+impl<T, S> Sequence<T> for SortedView<'_, T, S>
+where
+    S: Sequence<T> + ?Sized,
+{
+    fn len(&self) -> usize {
+        self.seq.len()
+    }
+
+    fn get(&self, index: usize) -> Option<&T> {
+        self.seq.get(index)
+    }
+
+    fn as_slice(&self) -> Option<&[T]> {
+        self.seq.as_slice()
+    }
+}
+
+impl<T, S: ?Sized> fmt::Debug for SortedView<'_, T, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SortedView").finish_non_exhaustive()
+    }
+}
+
+/// Trait for sequences that can have their sortedness checked before a search.
+pub trait SortedPrecondition<T> {
+    /// Checks that the sequence is in ascending order according to `mode`.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::dynamic_array::DynamicArray;
+    /// use arrays::algorithms::{PreconditionMode, SortedPrecondition};
+    ///
+    /// let mut arr = DynamicArray::new();
+    /// arr.extend([4, 2, 9]);
+    /// assert!(arr.check_sorted(PreconditionMode::AlwaysCheck).is_err());
+    /// assert!(arr.check_sorted(PreconditionMode::Off).is_ok());
+    /// ```
+    fn check_sorted(&self, mode: PreconditionMode) -> Result<(), PreconditionError>
+    where
+        T: Ord;
+
+    /// Checks sortedness according to `mode` and returns a `SortedView`
+    /// to run searches on.
+    fn sorted_view(
+        &self,
+        mode: PreconditionMode,
+    ) -> Result<SortedView<'_, T, Self>, PreconditionError>
+    where
+        T: Ord;
+}
+
+impl<T, S> SortedPrecondition<T> for S
+where
+    S: Sequence<T> + ?Sized,
+{
+    fn check_sorted(&self, mode: PreconditionMode) -> Result<(), PreconditionError>
+    where
+        T: Ord,
+    {
+        enforce(self, mode, T::cmp)
+    }
+
+    fn sorted_view(
+        &self,
+        mode: PreconditionMode,
+    ) -> Result<SortedView<'_, T, Self>, PreconditionError>
+    where
+        T: Ord,
+    {
+        SortedView::new(self, mode)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::float_search::NanPolicy;
+    use crate::algorithms::{
+        BinarySearchable, ExponentialSearchable, FloatSearchable, InterpolationSearchable,
+        JumpSearchable, NearestSearchable,
+    };
+    use crate::core::Array;
+    use crate::dynamic_array::DynamicArray;
+    use std::collections::VecDeque;
+
+    #[test]
+    fn test_is_sorted_by() {
+        let empty: [i32; 0] = [];
+        assert!(is_sorted_by(&empty[..], i32::cmp));
+        assert!(is_sorted_by(&[1][..], i32::cmp));
+        assert!(is_sorted_by(&[1, 1, 2, 2][..], i32::cmp));
+        assert!(!is_sorted_by(&[1, 2, 1][..], i32::cmp));
+
+        let by_len = Array::from_slice(&["a", "bb", "cc", "ddd"], 4).unwrap();
+        assert!(is_sorted_by(&by_len, |a: &&str, b| a.len().cmp(&b.len())));
+
+        let deque: VecDeque<i32> = [3, 2, 1].into_iter().collect();
+        assert!(is_sorted_by(&deque, |a, b| b.cmp(a)));
+    }
+
+    #[test]
+    fn test_first_unsorted_by() {
+        assert_eq!(first_unsorted_by(&[1, 2, 3][..], i32::cmp), None);
+        assert_eq!(first_unsorted_by(&[2, 1, 3][..], i32::cmp), Some(1));
+        assert_eq!(first_unsorted_by(&[1, 2, 3, 0][..], i32::cmp), Some(3));
+    }
+
+    #[test]
+    fn test_always_check() {
+        let unsorted = Array::from_slice(&[1, 4, 4, 2, 5], 5).unwrap();
+        assert_eq!(
+            unsorted.check_sorted(PreconditionMode::AlwaysCheck),
+            Err(PreconditionError::NotSorted { first_violation: 3 })
+        );
+
+        let sorted = Array::from_slice(&[1, 2, 4, 4, 5], 5).unwrap();
+        assert_eq!(sorted.check_sorted(PreconditionMode::AlwaysCheck), Ok(()));
+
+        let error = PreconditionError::NotSorted { first_violation: 3 };
+        assert_eq!(
+            error.to_string(),
+            "input is not sorted: element 3 is less than element 2"
+        );
+    }
+
+    #[test]
+    fn test_off_never_checks() {
+        let unsorted = Array::from_slice(&[3, 1, 2], 3).unwrap();
+        assert!(unsorted.check_sorted(PreconditionMode::Off).is_ok());
+        assert!(unsorted.sorted_view(PreconditionMode::Off).is_ok());
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "element 1 is less than element 0")]
+    fn test_debug_assert_panics_on_unsorted() {
+        let unsorted = Array::from_slice(&[3, 1, 2], 3).unwrap();
+        let _ = unsorted.check_sorted(PreconditionMode::DebugAssert);
+    }
+
+    #[test]
+    fn test_debug_assert_accepts_sorted() {
+        let mut arr = DynamicArray::new();
+        arr.extend(0..100);
+        assert!(arr.sorted_view(PreconditionMode::default()).is_ok());
+    }
+
+    #[test]
+    fn test_every_search_runs_through_view() {
+        let mut arr = DynamicArray::new();
+        arr.extend((0..50).map(|i| i * 2));
+        let view = arr.sorted_view(PreconditionMode::AlwaysCheck).unwrap();
+
+        assert_eq!(view.binary_search(&40), Some(20));
+        assert_eq!(view.binary_search_first(&40), Some(20));
+        assert_eq!(view.jump_search(&40), Some(20));
+        assert_eq!(view.exponential_search(&40), Some(20));
+        assert_eq!(view.interpolation_search(&40), Some(20));
+        assert_eq!(view.floor(&41), Some(20));
+        assert_eq!(view.binary_search(&41), None);
+        assert_eq!(view.as_slice(), Some(arr.as_slice()));
+        assert_eq!(view.into_inner().len(), 50);
+    }
+
+    #[test]
+    fn test_every_checked_search_rejects_unsorted() {
+        let unsorted = Array::from_slice(&[10, 20, 40, 30, 50], 5).unwrap();
+        let always = PreconditionMode::AlwaysCheck;
+        let violation = PreconditionError::NotSorted { first_violation: 3 };
+        let error = Err(violation);
+        let distance = |a: &i32, b: &i32| a.abs_diff(*b);
+
+        assert_eq!(unsorted.binary_search_checked(&30, always), error);
+        assert_eq!(unsorted.binary_search_first_checked(&30, always), error);
+        assert_eq!(unsorted.binary_search_last_checked(&30, always), error);
+        assert_eq!(unsorted.jump_search_checked(&30, always), error);
+        assert_eq!(unsorted.jump_search_first_checked(&30, always), error);
+        assert_eq!(unsorted.jump_search_last_checked(&30, always), error);
+        assert_eq!(unsorted.exponential_search_checked(&30, always), error);
+        assert_eq!(unsorted.interpolation_search_checked(&30, always), error);
+        assert_eq!(unsorted.floor_checked(&30, always), error);
+        assert_eq!(unsorted.ceiling_checked(&30, always), error);
+        assert_eq!(unsorted.closest_checked(&30, distance, always), error);
+        assert_eq!(unsorted.k_closest_checked(&30, 2, always), Err(violation));
+        assert_eq!(
+            unsorted.k_closest_by_checked(&30, 2, distance, always),
+            Err(violation)
+        );
+
+        let floats = Array::from_slice(&[1.0f64, 4.0, 2.0], 3).unwrap();
+        let error = Err(PreconditionError::NotSorted { first_violation: 2 });
+        assert_eq!(
+            floats.binary_search_by_total_cmp_checked(&2.0, always),
+            error
+        );
+        assert_eq!(floats.jump_search_by_total_cmp_checked(&2.0, always), error);
+        assert_eq!(
+            floats.exponential_search_by_total_cmp_checked(&2.0, always),
+            error
+        );
+
+        // Off trusts the caller, even in debug builds
+        let off = PreconditionMode::Off;
+        assert!(unsorted.binary_search_checked(&30, off).is_ok());
+        assert!(unsorted.jump_search_checked(&30, off).is_ok());
+        assert!(floats.binary_search_by_total_cmp_checked(&2.0, off).is_ok());
+    }
+
+    #[test]
+    fn test_every_checked_search_accepts_sorted() {
+        let sorted = Array::from_slice(&[10, 20, 30, 30, 50], 5).unwrap();
+        let always = PreconditionMode::AlwaysCheck;
+        let distance = |a: &i32, b: &i32| a.abs_diff(*b);
+
+        assert_eq!(sorted.binary_search_checked(&20, always), Ok(Some(1)));
+        assert_eq!(sorted.binary_search_first_checked(&30, always), Ok(Some(2)));
+        assert_eq!(sorted.binary_search_last_checked(&30, always), Ok(Some(3)));
+        assert_eq!(sorted.jump_search_checked(&50, always), Ok(Some(4)));
+        assert_eq!(sorted.jump_search_first_checked(&30, always), Ok(Some(2)));
+        assert_eq!(sorted.jump_search_last_checked(&30, always), Ok(Some(3)));
+        assert_eq!(sorted.exponential_search_checked(&10, always), Ok(Some(0)));
+        assert_eq!(sorted.interpolation_search_checked(&40, always), Ok(None));
+        assert_eq!(sorted.floor_checked(&45, always), Ok(Some(3)));
+        assert_eq!(sorted.ceiling_checked(&45, always), Ok(Some(4)));
+        assert_eq!(sorted.closest_checked(&38, distance, always), Ok(Some(3)));
+        assert_eq!(sorted.k_closest_checked(&12, 2, always), Ok(0..2));
+        assert_eq!(
+            sorted.k_closest_by_checked(&48, 2, distance, always),
+            Ok(3..5)
+        );
+
+        let floats = Array::from_slice(&[-0.0f64, 0.0, 2.5], 3).unwrap();
+        assert_eq!(
+            floats.binary_search_by_total_cmp_checked(&0.0, always),
+            Ok(Some(1))
+        );
+        assert_eq!(
+            floats.jump_search_by_total_cmp_checked(&2.5, always),
+            Ok(Some(2))
+        );
+        assert_eq!(
+            floats.exponential_search_by_total_cmp_checked(&-0.0, always),
+            Ok(Some(0))
+        );
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn test_every_search_asserts_sorted_in_debug() {
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+
+        fn panics<R>(search: impl FnOnce() -> R) -> bool {
+            catch_unwind(AssertUnwindSafe(search)).is_err()
+        }
+
+        let unsorted = Array::from_slice(&[10, 20, 40, 30, 50], 5).unwrap();
+        assert!(panics(|| unsorted.binary_search(&30)));
+        assert!(panics(|| unsorted.binary_search_first(&30)));
+        assert!(panics(|| unsorted.binary_search_last(&30)));
+        assert!(panics(|| unsorted.binary_search_insertion_point(&30)));
+        assert!(panics(|| unsorted.binary_search_traced(&30)));
+        assert!(panics(|| unsorted.binary_search_first_traced(&30)));
+        assert!(panics(|| unsorted.binary_search_last_traced(&30)));
+        assert!(panics(|| unsorted.binary_search_branchless(&30)));
+        assert!(panics(|| unsorted.binary_search_hybrid(&30, 4)));
+        assert!(panics(|| unsorted.jump_search(&30)));
+        assert!(panics(|| unsorted.jump_search_with_size(&30, 2)));
+        assert!(panics(|| unsorted.jump_search_first(&30)));
+        assert!(panics(|| unsorted.jump_search_last(&30)));
+        assert!(panics(|| unsorted.jump_search_traced(&30)));
+        assert!(panics(|| unsorted.jump_search_first_traced(&30)));
+        assert!(panics(|| unsorted.jump_search_last_traced(&30)));
+        assert!(panics(|| unsorted.exponential_search(&30)));
+        assert!(panics(|| unsorted.interpolation_search(&30)));
+        assert!(panics(|| unsorted.floor(&30)));
+        assert!(panics(|| unsorted.ceiling(&30)));
+        assert!(panics(|| unsorted.closest(&30, |a: &i32, b| a.abs_diff(*b))));
+        assert!(panics(|| unsorted.k_closest(&30, 2)));
+
+        let floats = Array::from_slice(&[1.0f64, 4.0, 2.0], 3).unwrap();
+        assert!(panics(|| floats.binary_search_by_total_cmp(&2.0)));
+        assert!(panics(|| floats.jump_search_by_total_cmp(&2.0)));
+        assert!(panics(|| floats.exponential_search_by_total_cmp(&2.0)));
+        for policy in [NanPolicy::SortLast, NanPolicy::SortFirst, NanPolicy::Reject] {
+            assert!(panics(|| floats.binary_search_with_nan_policy(&2.0, policy)));
+            assert!(panics(|| floats.jump_search_with_nan_policy(&2.0, policy)));
+            assert!(panics(
+                || floats.exponential_search_with_nan_policy(&2.0, policy)
+            ));
+        }
+
+        // A NaN at the wrong end breaks the order its policy promises
+        let nan_last = Array::from_slice(&[1.0f64, f64::NAN], 2).unwrap();
+        assert!(panics(
+            || nan_last.binary_search_with_nan_policy(&1.0, NanPolicy::SortFirst)
+        ));
+        assert!(!panics(
+            || nan_last.binary_search_with_nan_policy(&1.0, NanPolicy::SortLast)
+        ));
+
+        // Comparator-based searches cannot check, and the checked variants
+        // follow the mode they are given
+        assert!(!panics(|| unsorted.binary_search_by(|x| x.cmp(&30))));
+        assert!(!panics(
+            || unsorted.binary_search_checked(&30, PreconditionMode::Off)
+        ));
+    }
+
+    #[test]
+    fn test_new_by_custom_order() {
+        let descending = Array::from_slice(&[9, 7, 5, 3], 4).unwrap();
+
+        assert!(SortedView::new(&descending, PreconditionMode::AlwaysCheck).is_err());
+        let view = SortedView::new_by(&descending, PreconditionMode::AlwaysCheck, |a: &i32, b| {
+            b.cmp(a)
+        })
+        .unwrap();
+        assert_eq!(view.binary_search_by(|x| 5.cmp(x)), Some(2));
+    }
+
+    #[test]
+    fn test_float_order_check() {
+        let values = [-1.0f64, 0.0, 2.5, f64::NAN];
+        assert!(is_sorted_by(&values[..], f64::total_cmp));
+
+        let values = [0.0f64, -0.0];
+        assert_eq!(first_unsorted_by(&values[..], f64::total_cmp), Some(1));
+    }
+}
@@ -0,0 +1,462 @@
+//! Quicksort implementations for arrays.
+//!
+//! Quicksort picks a pivot, partitions the array into the elements before
+//! and after it, and sorts both sides. It is O(n log n) on average and in
+//! place, but not stable, and a poor pivot on unlucky input makes it O(n²).
+//! Every part of that is configurable through `QuickSortConfig`:
+//!
+//! - The partition scheme: Lomuto (simple, one scan), Hoare (two scans
+//!   meeting in the middle, fewer swaps and balanced on duplicates) or
+//!   three-way (groups every element equal to the pivot, so arrays with few
+//!   distinct values sort in close to linear time).
+//! - The pivot strategy: first, last, random from a seedable generator,
+//!   median-of-three or Tukey's ninther.
+//! - The length below which partitions are finished with insertion sort.
+//!
+//! Whatever the configuration, only the smaller side of each partition is
+//! sorted recursively; the larger side is handled by looping. Each recursive
+//! call therefore gets at most half the elements, and the stack depth stays
+//! O(log n) even when the running time degrades to O(n²).
+//!
+//! For theory and visual explanations, see:
+//! - Quick Sort on Arrays: `structures/01-arrays/algorithms/quick-sort.md`
+
+use super::insertion_sort::insertion;
+use super::sort_observer::SortOps;
+use std::cmp::Ordering;
+
+/// Partition length at or below which quicksort switches to insertion sort.
+pub const DEFAULT_INSERTION_THRESHOLD: usize = 16;
+
+/// Partitions at least this long pick their pivot as a ninther rather than a
+/// median of three, when `PivotStrategy::Ninther` is selected.
+pub const NINTHER_THRESHOLD: usize = 128;
+
+/// How a partition rearranges the elements around the pivot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PartitionScheme {
+    /// Single left-to-right scan that swaps smaller elements to the front.
+    ///
+    /// Degrades to O(n²) on arrays with many equal elements.
+    Lomuto,
+    /// Two scans from both ends that swap out-of-place pairs.
+    #[default]
+    Hoare,
+    /// Dijkstra's "Dutch national flag" partition into less, equal and
+    /// greater; the equal block is never visited again.
+    ThreeWay,
+}
+
+/// How the pivot of each partition is chosen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PivotStrategy {
+    /// The first element. O(n²) on sorted input.
+    First,
+    /// The last element. O(n²) on sorted input.
+    Last,
+    /// A uniformly random element.
+    Random {
+        /// Seed for the generator, so that runs are reproducible.
+        seed: u64,
+    },
+    /// The median of the first, middle and last elements.
+    #[default]
+    MedianOfThree,
+    /// The median of three medians of three, spread across the partition.
+    /// Shorter partitions than `NINTHER_THRESHOLD` use a median of three.
+    Ninther,
+}
+
+/// Configuration for `Sortable::quick_sort_with`.
+///
+/// # Examples
+/// ```
+/// use arrays::algorithms::{PartitionScheme, PivotStrategy, QuickSortConfig};
+///
+/// let config = QuickSortConfig {
+///     partition: PartitionScheme::ThreeWay,
+///     pivot: PivotStrategy::Random { seed: 42 },
+///     ..QuickSortConfig::default()
+/// };
+/// assert_eq!(config.insertion_threshold, 16);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuickSortConfig {
+    /// Partition scheme used at every level.
+    pub partition: PartitionScheme,
+    /// Pivot strategy used at every level.
+    pub pivot: PivotStrategy,
+    /// Partitions of at most this many elements are sorted with insertion
+    /// sort. 0 or 1 disables the cutoff.
+    pub insertion_threshold: usize,
+}
+
+impl Default for QuickSortConfig {
+    fn default() -> Self {
+        Self {
+            partition: PartitionScheme::default(),
+            pivot: PivotStrategy::default(),
+            insertion_threshold: DEFAULT_INSERTION_THRESHOLD,
+        }
+    }
+}
+
+// Sorts `v` and returns the deepest recursion level reached, which the
+// tests use to check the O(log n) stack bound.
+pub(crate) fn quick<T, S: SortOps<T>>(v: &mut [T], config: &QuickSortConfig, s: &mut S) -> usize {
+    let mut rng = match config.pivot {
+        // xorshift gets stuck at zero
+        PivotStrategy::Random { seed } if seed != 0 => seed,
+        _ => 0x9e37_79b9_7f4a_7c15,
+    };
+    let mut max_depth = 0;
+    quick_recursive(v, config, s, &mut rng, 0, &mut max_depth);
+    max_depth
+}
+
+fn quick_recursive<T, S: SortOps<T>>(
+    mut v: &mut [T],
+    config: &QuickSortConfig,
+    s: &mut S,
+    rng: &mut u64,
+    depth: usize,
+    max_depth: &mut usize,
+) {
+    *max_depth = (*max_depth).max(depth);
+
+    // Where `v` starts within the slice this call was given
+    let mut base = 0;
+    loop {
+        if v.len() <= config.insertion_threshold.max(1) {
+            s.within(base, |s| insertion(v, s));
+            return;
+        }
+
+        let pivot = choose_pivot(v, config.pivot, s, rng);
+        let (equal_start, equal_end) = s.within(base, |s| match config.partition {
+            PartitionScheme::Lomuto => {
+                let p = lomuto(v, pivot, s);
+                (p, p + 1)
+            }
+            PartitionScheme::Hoare => {
+                let p = hoare(v, pivot, s);
+                (p, p + 1)
+            }
+            PartitionScheme::ThreeWay => three_way(v, pivot, s),
+        });
+
+        // Recurse into the smaller side and loop on the larger one
+        let (less, rest) = std::mem::take(&mut v).split_at_mut(equal_start);
+        let greater = &mut rest[equal_end - equal_start..];
+        if less.len() < greater.len() {
+            s.within(base, |s| {
+                quick_recursive(less, config, s, rng, depth + 1, max_depth)
+            });
+            v = greater;
+            base += equal_end;
+        } else {
+            s.within(base + equal_end, |s| {
+                quick_recursive(greater, config, s, rng, depth + 1, max_depth)
+            });
+            v = less;
+        }
+    }
+}
+
+// One xorshift64 step. Never returns zero unless `state` starts at zero, so
+// callers must seed it with a nonzero value.
+pub(crate) fn xorshift64(state: &mut u64) -> u64 {
+    *state ^= *state << 13;
+    *state ^= *state >> 7;
+    *state ^= *state << 17;
+    *state
+}
+
+pub(crate) fn choose_pivot<T, S: SortOps<T>>(
+    v: &[T],
+    strategy: PivotStrategy,
+    s: &mut S,
+    rng: &mut u64,
+) -> usize {
+    let n = v.len();
+    let mid = n / 2;
+    match strategy {
+        PivotStrategy::First => 0,
+        PivotStrategy::Last => n - 1,
+        PivotStrategy::Random { .. } => (xorshift64(rng) % n as u64) as usize,
+        PivotStrategy::Ninther if n >= NINTHER_THRESHOLD => {
+            let step = n / 8;
+            let a = median_of_three(v, 0, step, 2 * step, s);
+            let b = median_of_three(v, mid - step, mid, mid + step, s);
+            let c = median_of_three(v, n - 1 - 2 * step, n - 1 - step, n - 1, s);
+            median_of_three(v, a, b, c, s)
+        }
+        PivotStrategy::MedianOfThree | PivotStrategy::Ninther => {
+            median_of_three(v, 0, mid, n - 1, s)
+        }
+    }
+}
+
+fn median_of_three<T, S: SortOps<T>>(v: &[T], a: usize, b: usize, c: usize, s: &mut S) -> usize {
+    let ab = s.is_less(&v[a], &v[b]);
+    let bc = s.is_less(&v[b], &v[c]);
+    if ab == bc {
+        return b;
+    }
+    let ac = s.is_less(&v[a], &v[c]);
+    if ab == ac {
+        c
+    } else {
+        a
+    }
+}
+
+// Returns the pivot's final index. Everything before it is less than the
+// pivot, everything after is not.
+fn lomuto<T, S: SortOps<T>>(v: &mut [T], pivot: usize, s: &mut S) -> usize {
+    let last = v.len() - 1;
+    s.swap(v, pivot, last);
+
+    let mut store = 0;
+    for i in 0..last {
+        if s.is_less(&v[i], &v[last]) {
+            s.swap(v, i, store);
+            store += 1;
+        }
+    }
+    s.swap(v, store, last);
+    store
+}
+
+// Returns the pivot's final index. Everything before it is not greater than
+// the pivot, everything after is not less. Both scans stop at elements equal
+// to the pivot, which splits runs of duplicates down the middle.
+pub(crate) fn hoare<T, S: SortOps<T>>(v: &mut [T], pivot: usize, s: &mut S) -> usize {
+    let n = v.len();
+    s.swap(v, 0, pivot);
+
+    let mut i = 0;
+    let mut j = n;
+    loop {
+        i += 1;
+        while i < n && s.is_less(&v[i], &v[0]) {
+            i += 1;
+        }
+        // The pivot itself stops this scan at 0
+        j -= 1;
+        while s.is_less(&v[0], &v[j]) {
+            j -= 1;
+        }
+        if i >= j {
+            break;
+        }
+        s.swap(v, i, j);
+    }
+    s.swap(v, 0, j);
+    j
+}
+
+// Returns the bounds of the block equal to the pivot: v[..lt] is less,
+// v[lt..gt] is equal and v[gt..] is greater.
+fn three_way<T, S: SortOps<T>>(v: &mut [T], pivot: usize, s: &mut S) -> (usize, usize) {
+    s.swap(v, 0, pivot);
+
+    // v[lt] is always the first of the equal block, so it stands in for the
+    // pivot
+    let mut lt = 0;
+    let mut i = 1;
+    let mut gt = v.len();
+    while i < gt {
+        match s.compare(&v[i], &v[lt]) {
+            Ordering::Less => {
+                s.swap(v, lt, i);
+                lt += 1;
+                i += 1;
+            }
+            Ordering::Greater => {
+                gt -= 1;
+                s.swap(v, i, gt);
+            }
+            Ordering::Equal => i += 1,
+        }
+    }
+    (lt, gt)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::sort_observer::Sorter;
+    use crate::algorithms::test_util::{random_inputs, random_values};
+    use crate::algorithms::Sortable;
+    use crate::core::Array;
+    use crate::dynamic_array::DynamicArray;
+
+    const SCHEMES: [PartitionScheme; 3] = [
+        PartitionScheme::Lomuto,
+        PartitionScheme::Hoare,
+        PartitionScheme::ThreeWay,
+    ];
+
+    const PIVOTS: [PivotStrategy; 5] = [
+        PivotStrategy::First,
+        PivotStrategy::Last,
+        PivotStrategy::Random { seed: 7 },
+        PivotStrategy::MedianOfThree,
+        PivotStrategy::Ninther,
+    ];
+
+    fn configs() -> impl Iterator<Item = QuickSortConfig> {
+        SCHEMES.into_iter().flat_map(|partition| {
+            PIVOTS.into_iter().flat_map(move |pivot| {
+                [0, DEFAULT_INSERTION_THRESHOLD]
+                    .into_iter()
+                    .map(move |insertion_threshold| QuickSortConfig {
+                        partition,
+                        pivot,
+                        insertion_threshold,
+                    })
+            })
+        })
+    }
+
+    fn comparisons(values: &[u32], config: &QuickSortConfig) -> usize {
+        let mut arr = DynamicArray::new();
+        arr.extend(values.iter().copied());
+        let mut count = 0;
+        arr.quick_sort_with_by(config, |a, b| {
+            count += 1;
+            a.cmp(b)
+        });
+        count
+    }
+
+    #[test]
+    fn test_all_configs_match_std_sort() {
+        let mut inputs: Vec<Vec<u32>> = random_inputs(12, 400).map(|(_, values)| values).collect();
+        inputs.push((0..300).collect());
+        inputs.push((0..300).rev().collect());
+        inputs.push(vec![5; 200]);
+        inputs.push(
+            (0..300)
+                .map(|i| if i % 2 == 0 { i } else { 300 - i })
+                .collect(),
+        );
+
+        for config in configs() {
+            for values in &inputs {
+                let mut expected = values.clone();
+                expected.sort();
+
+                let mut arr = DynamicArray::new();
+                arr.extend(values.iter().copied());
+                arr.quick_sort_with(&config);
+                assert_eq!(arr.as_slice(), expected.as_slice(), "{config:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_edge_cases() {
+        for config in configs() {
+            let mut empty: DynamicArray<i32> = DynamicArray::new();
+            empty.quick_sort_with(&config);
+            assert!(empty.is_empty());
+
+            let mut single = Array::from_slice(&[1], 1).unwrap();
+            single.quick_sort_with(&config);
+            assert_eq!(single.as_slice(), &[1]);
+
+            let mut pair = Array::from_slice(&[2, 1], 2).unwrap();
+            pair.quick_sort_with(&config);
+            assert_eq!(pair.as_slice(), &[1, 2], "{config:?}");
+        }
+    }
+
+    #[test]
+    fn test_by_and_by_key() {
+        let mut arr = Array::from_slice(&["pear", "fig", "banana", "kiwi"], 4).unwrap();
+        arr.quick_sort_by_key(|word| word.len());
+        assert_eq!(arr.as_slice()[0], "fig");
+        assert_eq!(arr.as_slice()[3], "banana");
+
+        let mut arr = DynamicArray::new();
+        arr.extend(random_values(3, 100, 50));
+        arr.quick_sort_by(|a, b| b.cmp(a));
+        assert!(arr.as_slice().windows(2).all(|w| w[0] >= w[1]));
+    }
+
+    #[test]
+    fn test_stack_depth_is_logarithmic() {
+        let n: usize = 1024;
+        let bound = n.ilog2() as usize;
+        let sorted: Vec<u32> = (0..n as u32).collect();
+        let equal = vec![1u32; n];
+
+        for config in configs() {
+            // Sorted input with an end pivot is the O(n²) worst case, and
+            // equal elements are for Lomuto; neither may grow the stack
+            for values in [&sorted, &equal] {
+                let mut v = values.clone();
+                let depth = quick(&mut v, &config, &mut Sorter::new(u32::cmp));
+                assert!(depth <= bound, "{config:?}: depth {depth}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_three_way_is_linear_on_few_distinct_values() {
+        let n = 2000;
+        let values = random_values(11, n, 3);
+
+        let three_way = QuickSortConfig {
+            partition: PartitionScheme::ThreeWay,
+            ..QuickSortConfig::default()
+        };
+        let lomuto = QuickSortConfig {
+            partition: PartitionScheme::Lomuto,
+            ..QuickSortConfig::default()
+        };
+
+        // One partition pass per distinct value
+        assert!(comparisons(&values, &three_way) < 4 * n);
+        assert!(comparisons(&values, &lomuto) > n * n / 10);
+    }
+
+    #[test]
+    fn test_pivot_strategies_on_sorted_input() {
+        let n = 1000;
+        let sorted: Vec<u32> = (0..n as u32).collect();
+        let with_pivot = |pivot| QuickSortConfig {
+            pivot,
+            insertion_threshold: 0,
+            ..QuickSortConfig::default()
+        };
+
+        let first = comparisons(&sorted, &with_pivot(PivotStrategy::First));
+        let median = comparisons(&sorted, &with_pivot(PivotStrategy::MedianOfThree));
+        let ninther = comparisons(&sorted, &with_pivot(PivotStrategy::Ninther));
+        assert!(first > n * n / 4, "{first}");
+        assert!(median < 20 * n, "{median}");
+        assert!(ninther < 20 * n, "{ninther}");
+    }
+
+    #[test]
+    fn test_random_pivot_is_reproducible() {
+        let values = random_values(5, 500, 1000);
+        let config = |seed| QuickSortConfig {
+            pivot: PivotStrategy::Random { seed },
+            ..QuickSortConfig::default()
+        };
+
+        assert_eq!(
+            comparisons(&values, &config(1)),
+            comparisons(&values, &config(1))
+        );
+        // Seed zero still produces a working generator
+        let mut arr = DynamicArray::new();
+        arr.extend(values.iter().copied());
+        arr.quick_sort_with(&config(0));
+        assert!(arr.as_slice().windows(2).all(|w| w[0] <= w[1]));
+    }
+}
@@ -0,0 +1,93 @@
+//! Execution traces for search algorithms.
+//!
+//! The `*_traced` search methods return a `SearchTrace` instead of a bare
+//! index. The trace records every element the algorithm looked at, so tests
+//! can assert complexity bounds and visualizations can replay the search
+//! step by step.
+
+/// A single probe made by a search algorithm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SearchStep {
+    /// Lowest index still considered a candidate when the probe was made.
+    pub low: usize,
+    /// Highest index (inclusive) still considered a candidate.
+    pub high: usize,
+    /// Index of the element that was compared against the target.
+    pub index: usize,
+}
+
+/// The outcome of a traced search together with the work it performed.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SearchTrace {
+    /// The index returned by the search, identical to the untraced variant.
+    pub result: Option<usize>,
+    /// Number of element comparisons performed.
+    pub comparisons: usize,
+    /// Probed indices in the order they were visited.
+    pub probes: Vec<usize>,
+    /// Candidate bounds at each probe, in the same order as `probes`.
+    pub steps: Vec<SearchStep>,
+}
+
+impl SearchTrace {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records one comparison of the element at `index` while the candidate
+    /// range was `low..=high`.
+    pub(crate) fn probe(&mut self, low: usize, high: usize, index: usize) {
+        self.comparisons += 1;
+        self.probes.push(index);
+        self.steps.push(SearchStep { low, high, index });
+    }
+
+    pub(crate) fn finish(mut self, result: Option<usize>) -> Self {
+        self.result = result;
+        self
+    }
+
+    /// Returns true if the search found the target.
+    pub fn found(&self) -> bool {
+        self.result.is_some()
+    }
+
+    /// Returns the number of distinct probes made.
+    pub fn probe_count(&self) -> usize {
+        self.probes.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_probe_records_step() {
+        let mut trace = SearchTrace::new();
+        trace.probe(0, 9, 4);
+        trace.probe(5, 9, 7);
+        let trace = trace.finish(Some(7));
+
+        assert!(trace.found());
+        assert_eq!(trace.comparisons, 2);
+        assert_eq!(trace.probes, vec![4, 7]);
+        assert_eq!(
+            trace.steps[1],
+            SearchStep {
+                low: 5,
+                high: 9,
+                index: 7
+            }
+        );
+    }
+
+    #[test]
+    fn test_empty_trace() {
+        let trace = SearchTrace::new().finish(None);
+
+        assert!(!trace.found());
+        assert_eq!(trace.probe_count(), 0);
+        assert!(trace.steps.is_empty());
+    }
+}
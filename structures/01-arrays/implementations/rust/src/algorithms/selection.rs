@@ -0,0 +1,481 @@
+//! Order statistics for unsorted arrays.
+//!
+//! `linear_search::utils` finds the minimum and maximum in one pass. This
+//! module finds any order statistic, such as the k-th smallest element, the
+//! median, a percentile or the k largest elements, without sorting.
+//!
+//! Selection uses introselect. Quickselect with median-of-three pivots runs
+//! in expected O(n), but crafted inputs can drive it to O(n²). Once the
+//! partitions have failed to shrink often enough, the pivot choice switches
+//! to median of medians, which guarantees O(n) in the worst case.
+//!
+//! The in-place methods reorder the array. The `_cloned` methods leave it
+//! untouched and select on a scratch copy instead.
+
+use super::insertion_sort::insertion;
+use super::sort_observer::{SortOps, Sorter};
+use crate::dynamic_array::DynamicArray;
+use crate::sequence::{Sequence, SequenceMut};
+
+/// Slices at or below this length are finished off with insertion sort.
+const INSERTION_SORT_LEN: usize = 10;
+
+/// Trait for arrays that support order-statistic selection.
+pub trait Selectable<T> {
+    /// Reorders the array so the element at `k` is the one that would be
+    /// there if the array were sorted, and returns it.
+    ///
+    /// Everything before `k` is less than or equal to it and everything
+    /// after is greater than or equal to it; neither side is sorted.
+    /// Returns `None` if `k` is out of bounds. Runs in O(n).
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::core::Array;
+    /// use arrays::algorithms::Selectable;
+    ///
+    /// let mut arr = Array::from_slice(&[9, 1, 8, 2, 7, 3], 10).unwrap();
+    /// assert_eq!(arr.select_nth(0), Some(&1));
+    /// assert_eq!(arr.select_nth(4), Some(&8));
+    /// assert_eq!(arr.select_nth(6), None);
+    /// ```
+    fn select_nth(&mut self, k: usize) -> Option<&T>
+    where
+        T: Ord;
+
+    /// Returns the median, reordering the array as `select_nth` does.
+    ///
+    /// For an even number of elements this is the lower median. Returns
+    /// `None` for an empty array.
+    fn median(&mut self) -> Option<&T>
+    where
+        T: Ord;
+
+    /// Moves the `k` largest elements to the end of the array and returns
+    /// them, in no particular order.
+    ///
+    /// If `k` exceeds the length, every element is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::dynamic_array::DynamicArray;
+    /// use arrays::algorithms::Selectable;
+    ///
+    /// let mut arr = DynamicArray::new();
+    /// arr.extend([5, 1, 9, 3, 7]);
+    ///
+    /// let mut top = arr.top_k(2).to_vec();
+    /// top.sort();
+    /// assert_eq!(top, vec![7, 9]);
+    /// ```
+    fn top_k(&mut self, k: usize) -> &[T]
+    where
+        T: Ord;
+
+    /// Returns the `p`-th percentile using the nearest-rank method,
+    /// reordering the array as `select_nth` does.
+    ///
+    /// `p` is in `0.0..=100.0`; the 0th percentile is the minimum and the
+    /// 100th is the maximum. Returns `None` for an empty array or a `p`
+    /// outside that range.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::core::Array;
+    /// use arrays::algorithms::Selectable;
+    ///
+    /// let mut latencies = Array::from_slice(&[15, 20, 35, 40, 50], 5).unwrap();
+    /// assert_eq!(latencies.percentile(40.0), Some(&20));
+    /// assert_eq!(latencies.percentile(100.0), Some(&50));
+    /// ```
+    fn percentile(&mut self, p: f64) -> Option<&T>
+    where
+        T: Ord;
+
+    /// Like `select_nth`, but leaves the array unchanged and returns a
+    /// clone of the element.
+    fn select_nth_cloned(&self, k: usize) -> Option<T>
+    where
+        T: Ord + Clone;
+
+    /// Like `median`, but leaves the array unchanged.
+    fn median_cloned(&self) -> Option<T>
+    where
+        T: Ord + Clone;
+
+    /// Like `top_k`, but leaves the array unchanged and returns copies of
+    /// the `k` largest elements.
+    fn top_k_cloned(&self, k: usize) -> DynamicArray<T>
+    where
+        T: Ord + Clone;
+
+    /// Like `percentile`, but leaves the array unchanged.
+    fn percentile_cloned(&self, p: f64) -> Option<T>
+    where
+        T: Ord + Clone;
+}
+
+// Every `SequenceMut` gets selection through this blanket implementation
+impl<T, S> Selectable<T> for S
+where
+    S: SequenceMut<T> + ?Sized,
+{
+    fn select_nth(&mut self, k: usize) -> Option<&T>
+    where
+        T: Ord,
+    {
+        select_in_slice(self.as_mut_slice(), k)
+    }
+
+    fn median(&mut self) -> Option<&T>
+    where
+        T: Ord,
+    {
+        let k = self.len().checked_sub(1)? / 2;
+        self.select_nth(k)
+    }
+
+    fn top_k(&mut self, k: usize) -> &[T]
+    where
+        T: Ord,
+    {
+        top_k_in_slice(self.as_mut_slice(), k)
+    }
+
+    fn percentile(&mut self, p: f64) -> Option<&T>
+    where
+        T: Ord,
+    {
+        let k = percentile_rank(self.len(), p)?;
+        self.select_nth(k)
+    }
+
+    fn select_nth_cloned(&self, k: usize) -> Option<T>
+    where
+        T: Ord + Clone,
+    {
+        scratch_copy(self).select_nth(k).cloned()
+    }
+
+    fn median_cloned(&self) -> Option<T>
+    where
+        T: Ord + Clone,
+    {
+        scratch_copy(self).median().cloned()
+    }
+
+    fn top_k_cloned(&self, k: usize) -> DynamicArray<T>
+    where
+        T: Ord + Clone,
+    {
+        let mut scratch = scratch_copy(self);
+        let mut top = DynamicArray::with_capacity(k.min(scratch.len()));
+        top.extend(scratch.top_k(k).iter().cloned());
+        top
+    }
+
+    fn percentile_cloned(&self, p: f64) -> Option<T>
+    where
+        T: Ord + Clone,
+    {
+        scratch_copy(self).percentile(p).cloned()
+    }
+}
+
+fn select_in_slice<T: Ord>(v: &mut [T], k: usize) -> Option<&T> {
+    if k >= v.len() {
+        return None;
+    }
+    select(v, k, &mut Sorter::new(T::cmp));
+    Some(&v[k])
+}
+
+fn top_k_in_slice<T: Ord>(v: &mut [T], k: usize) -> &[T] {
+    let start = v.len() - k.min(v.len());
+    if start < v.len() {
+        select(v, start, &mut Sorter::new(T::cmp));
+    }
+    &v[start..]
+}
+
+fn scratch_copy<T, S>(seq: &S) -> DynamicArray<T>
+where
+    T: Clone,
+    S: Sequence<T> + ?Sized,
+{
+    let mut scratch = DynamicArray::with_capacity(seq.len());
+    scratch.extend((0..seq.len()).filter_map(|i| seq.get(i)).cloned());
+    scratch
+}
+
+// Zero-based index of the nearest-rank percentile in a sorted array of `len`
+fn percentile_rank(len: usize, p: f64) -> Option<usize> {
+    if len == 0 || !(0.0..=100.0).contains(&p) {
+        return None;
+    }
+
+    let rank = (p / 100.0 * len as f64).ceil() as usize;
+    Some(rank.clamp(1, len) - 1)
+}
+
+// Introselect: places the k-th smallest element at `k`. Tolerates about
+// 2·log₂ n poor quickselect rounds before falling back to median of medians.
+pub(crate) fn select<T, S: SortOps<T>>(v: &mut [T], k: usize, s: &mut S) {
+    let budget = 2 * (usize::BITS - v.len().leading_zeros()) as usize;
+    introselect(v, k, budget, s);
+}
+
+// A `budget` of zero means every pivot comes from median of medians.
+fn introselect<T, S: SortOps<T>>(mut v: &mut [T], mut k: usize, mut budget: usize, s: &mut S) {
+    // Where `v` starts within the slice this call was given
+    let mut base = 0;
+    loop {
+        if v.len() <= INSERTION_SORT_LEN {
+            s.within(base, |s| insertion(v, s));
+            return;
+        }
+
+        let pivot = if budget > 0 {
+            median_of_three(v, s)
+        } else {
+            s.within(base, |s| median_of_medians(v, s))
+        };
+        let (less, greater) = s.within(base, |s| partition_three_way(v, pivot, s));
+        let before = v.len();
+
+        if k < less {
+            v = &mut v[..less];
+        } else if k >= greater {
+            v = &mut v[greater..];
+            k -= greater;
+            base += greater;
+        } else {
+            // k landed among the elements equal to the pivot
+            return;
+        }
+
+        // Spend budget on rounds that failed to discard a quarter
+        if v.len() > before - before / 4 {
+            budget = budget.saturating_sub(1);
+        }
+    }
+}
+
+// Index of the median of the first, middle and last elements
+fn median_of_three<T, S: SortOps<T>>(v: &[T], s: &mut S) -> usize {
+    let (a, b, c) = (0, v.len() / 2, v.len() - 1);
+    if s.is_less(&v[a], &v[b]) {
+        if s.is_less(&v[b], &v[c]) {
+            b
+        } else if s.is_less(&v[a], &v[c]) {
+            c
+        } else {
+            a
+        }
+    } else if s.is_less(&v[a], &v[c]) {
+        a
+    } else if s.is_less(&v[b], &v[c]) {
+        c
+    } else {
+        b
+    }
+}
+
+// Index of a pivot guaranteed to have at least 3/10 of the elements on
+// each side. Moves the median of each group of five to the front, then
+// selects the median of those medians recursively.
+fn median_of_medians<T, S: SortOps<T>>(v: &mut [T], s: &mut S) -> usize {
+    let groups = v.len() / 5;
+    for group in 0..groups {
+        let start = group * 5;
+        s.within(start, |s| insertion(&mut v[start..start + 5], s));
+        s.swap(v, group, start + 2);
+    }
+
+    let mid = groups / 2;
+    introselect(&mut v[..groups], mid, 0, s);
+    mid
+}
+
+// Dutch national flag partition around `v[pivot]`. Returns `(less, greater)`
+// such that `v[..less] < pivot`, `v[less..greater] == pivot` and
+// `v[greater..] > pivot`.
+fn partition_three_way<T, S: SortOps<T>>(v: &mut [T], pivot: usize, s: &mut S) -> (usize, usize) {
+    s.swap(v, 0, pivot);
+
+    // v[0] holds the pivot until the end; v[1..lt] < pivot,
+    // v[lt..i] == pivot, v[gt..] > pivot
+    let mut lt = 1;
+    let mut i = 1;
+    let mut gt = v.len();
+
+    while i < gt {
+        match s.compare(&v[i], &v[0]) {
+            std::cmp::Ordering::Less => {
+                s.swap(v, lt, i);
+                lt += 1;
+                i += 1;
+            }
+            std::cmp::Ordering::Equal => i += 1,
+            std::cmp::Ordering::Greater => {
+                gt -= 1;
+                s.swap(v, i, gt);
+            }
+        }
+    }
+
+    // Move the pivot into the equal block
+    s.swap(v, 0, lt - 1);
+    (lt - 1, gt)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::test_util::{random_inputs, random_values};
+    use crate::core::Array;
+
+    fn assert_selected<T: Ord + std::fmt::Debug>(v: &[T], k: usize) {
+        assert!(v[..k].iter().all(|x| x <= &v[k]), "left of {k}: {v:?}");
+        assert!(v[k + 1..].iter().all(|x| x >= &v[k]), "right of {k}: {v:?}");
+    }
+
+    #[test]
+    fn test_select_nth_matches_sorting() {
+        for (seed, values) in random_inputs(40, 400) {
+            let len = values.len();
+            let mut sorted = values.clone();
+            sorted.sort();
+
+            for k in [0, len / 3, len / 2, len - 1] {
+                let mut arr = DynamicArray::new();
+                arr.extend(values.iter().copied());
+
+                assert_eq!(arr.select_nth(k), Some(&sorted[k]), "seed {seed}, k {k}");
+                assert_selected(arr.as_slice(), k);
+            }
+        }
+    }
+
+    #[test]
+    fn test_median_of_medians_fallback() {
+        // A zero budget forces median of medians on every round
+        for seed in 1..=20u64 {
+            let mut values = random_values(seed, 500, 50);
+            let mut sorted = values.clone();
+            sorted.sort();
+
+            let k = (seed as usize * 31) % values.len();
+            introselect(&mut values, k, 0, &mut Sorter::new(u32::cmp));
+            assert_eq!(values[k], sorted[k]);
+            assert_selected(&values, k);
+        }
+
+        // Already sorted and reversed inputs, a classic quickselect trap
+        let mut ascending: Vec<u32> = (0..1000).collect();
+        introselect(&mut ascending, 500, 0, &mut Sorter::new(u32::cmp));
+        assert_eq!(ascending[500], 500);
+
+        let mut descending: Vec<u32> = (0..1000).rev().collect();
+        introselect(&mut descending, 10, 0, &mut Sorter::new(u32::cmp));
+        assert_eq!(descending[10], 10);
+    }
+
+    #[test]
+    fn test_select_edge_cases() {
+        let mut empty: DynamicArray<i32> = DynamicArray::new();
+        assert_eq!(empty.select_nth(0), None);
+        assert_eq!(empty.median(), None);
+        assert_eq!(empty.percentile(50.0), None);
+        assert!(empty.top_k(3).is_empty());
+
+        let mut single = Array::from_slice(&[42], 1).unwrap();
+        assert_eq!(single.select_nth(0), Some(&42));
+        assert_eq!(single.median(), Some(&42));
+        assert_eq!(single.select_nth(1), None);
+
+        let mut same = Array::from_slice(&[7; 50], 50).unwrap();
+        assert_eq!(same.median(), Some(&7));
+    }
+
+    #[test]
+    fn test_median() {
+        let mut odd = Array::from_slice(&[5, 3, 1, 4, 2], 5).unwrap();
+        assert_eq!(odd.median(), Some(&3));
+
+        // Even lengths give the lower median
+        let mut even = Array::from_slice(&[6, 1, 5, 2, 4, 3], 6).unwrap();
+        assert_eq!(even.median(), Some(&3));
+    }
+
+    #[test]
+    fn test_top_k() {
+        let values = random_values(7, 300, 1000);
+        let mut sorted = values.clone();
+        sorted.sort();
+
+        for k in [0, 1, 10, 299, 300, 500] {
+            let mut arr = DynamicArray::new();
+            arr.extend(values.iter().copied());
+
+            let mut top = arr.top_k(k).to_vec();
+            top.sort();
+            let expected = &sorted[300 - k.min(300)..];
+            assert_eq!(top, expected, "k {k}");
+        }
+    }
+
+    #[test]
+    fn test_percentile() {
+        let mut arr = DynamicArray::new();
+        arr.extend((1..=100).rev());
+
+        assert_eq!(arr.percentile(0.0), Some(&1));
+        assert_eq!(arr.percentile(1.0), Some(&1));
+        assert_eq!(arr.percentile(50.0), Some(&50));
+        assert_eq!(arr.percentile(99.5), Some(&100));
+        assert_eq!(arr.percentile(100.0), Some(&100));
+
+        assert_eq!(arr.percentile(-0.1), None);
+        assert_eq!(arr.percentile(100.1), None);
+        assert_eq!(arr.percentile(f64::NAN), None);
+    }
+
+    #[test]
+    fn test_cloned_variants_leave_array_unchanged() {
+        let original = [9, 4, 7, 1, 8, 2];
+        let arr = Array::from_slice(&original, 6).unwrap();
+
+        assert_eq!(arr.select_nth_cloned(1), Some(2));
+        assert_eq!(arr.median_cloned(), Some(4));
+        assert_eq!(arr.percentile_cloned(100.0), Some(9));
+
+        let top = arr.top_k_cloned(2);
+        let mut top: Vec<i32> = top.as_slice().to_vec();
+        top.sort();
+        assert_eq!(top, vec![8, 9]);
+
+        assert_eq!(arr.as_slice(), &original);
+    }
+
+    #[test]
+    fn test_select_with_strings() {
+        let mut arr = DynamicArray::new();
+        for word in ["pear", "apple", "fig", "kiwi", "banana"] {
+            arr.push(word.to_string());
+        }
+
+        assert_eq!(arr.median().map(String::as_str), Some("fig"));
+        assert_eq!(arr.select_nth_cloned(0).as_deref(), Some("apple"));
+    }
+
+    #[test]
+    fn test_select_on_slices() {
+        let mut values = [5, 1, 4, 2, 3];
+
+        assert_eq!(Selectable::median_cloned(&values[..]), Some(3));
+        assert_eq!(values, [5, 1, 4, 2, 3]);
+        assert_eq!(Selectable::median(&mut values[..]), Some(&3));
+        assert_selected(&values, 2);
+    }
+}
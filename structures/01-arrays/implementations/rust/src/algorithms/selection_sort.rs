@@ -0,0 +1,111 @@
+//! Selection sort implementations for arrays.
+//!
+//! Selection sort repeatedly finds the smallest remaining element and moves
+//! it to the end of the sorted prefix. It always makes n(n - 1)/2
+//! comparisons, but only n - 1 swaps, which matters when moving elements is
+//! far more expensive than comparing them.
+//!
+//! The classic version swaps the minimum into place, which can carry another
+//! element past its equals, so it is not stable. The stable version rotates
+//! the minimum into place instead, shifting the elements in between one slot
+//! right; that keeps equal elements in order at the cost of O(n²) moves.
+//!
+//! For theory and visual explanations, see:
+//! - Selection Sort Theory: `algorithms/sorting/selection-sort/`
+
+use super::sort_observer::SortOps;
+use std::cmp::Ordering;
+
+// With `stable`, the minimum is rotated into place rather than swapped.
+pub(crate) fn selection<T, S: SortOps<T>>(v: &mut [T], stable: bool, s: &mut S) {
+    let n = v.len();
+    for i in 0..n.saturating_sub(1) {
+        // The first of several equal minimums, so rotating keeps order
+        let mut min = i;
+        for j in i + 1..n {
+            if s.compare(&v[j], &v[min]) == Ordering::Less {
+                min = j;
+            }
+        }
+
+        if min == i {
+            continue;
+        }
+        if stable {
+            s.within(i, |s| s.rotate_right(&mut v[i..=min], 1));
+        } else {
+            s.swap(v, i, min);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::algorithms::test_util::assert_sorts_like_std;
+    use crate::algorithms::Sortable;
+    use crate::core::Array;
+    use crate::dynamic_array::DynamicArray;
+
+    #[test]
+    fn test_sorts_match_std_sort() {
+        assert_sorts_like_std("selection", 30, 100, |arr| arr.selection_sort());
+        assert_sorts_like_std("stable selection", 30, 100, |arr| {
+            arr.stable_selection_sort()
+        });
+        // Reversing a descending sort must give the ascending order
+        assert_sorts_like_std("selection by key", 30, 100, |arr| {
+            arr.selection_sort_by_key(|&x| std::cmp::Reverse(x));
+            arr.as_mut_slice().reverse();
+        });
+    }
+
+    #[test]
+    fn test_edge_cases() {
+        let mut empty: DynamicArray<i32> = DynamicArray::new();
+        empty.selection_sort();
+        empty.stable_selection_sort();
+        assert!(empty.is_empty());
+
+        let mut single = Array::from_slice(&[1], 1).unwrap();
+        single.selection_sort();
+        assert_eq!(single.as_slice(), &[1]);
+    }
+
+    #[test]
+    fn test_stability() {
+        let records = [(1, 'a'), (1, 'b'), (0, 'c')];
+
+        // Swapping the 0 to the front carries (1, 'a') past (1, 'b')
+        let mut arr = Array::from_slice(&records, 3).unwrap();
+        arr.selection_sort_by_key(|&(key, _)| key);
+        assert_eq!(arr.as_slice(), &[(0, 'c'), (1, 'b'), (1, 'a')]);
+
+        let mut arr = Array::from_slice(&records, 3).unwrap();
+        arr.stable_selection_sort_by_key(|&(key, _)| key);
+        assert_eq!(arr.as_slice(), &[(0, 'c'), (1, 'a'), (1, 'b')]);
+
+        let records = [(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd'), (0, 'e'), (2, 'f')];
+        let mut arr = Array::from_slice(&records, 6).unwrap();
+        arr.stable_selection_sort_by(|a, b| a.0.cmp(&b.0));
+        assert_eq!(
+            arr.as_slice(),
+            &[(0, 'e'), (1, 'b'), (1, 'd'), (2, 'a'), (2, 'c'), (2, 'f')]
+        );
+    }
+
+    #[test]
+    fn test_comparison_count_is_fixed() {
+        let n = 64;
+        for values in [(0..n).collect::<Vec<u32>>(), (0..n).rev().collect()] {
+            let mut arr = DynamicArray::new();
+            arr.extend(values);
+
+            let mut comparisons = 0;
+            arr.selection_sort_by(|a, b| {
+                comparisons += 1;
+                a.cmp(b)
+            });
+            assert_eq!(comparisons as u32, n * (n - 1) / 2);
+        }
+    }
+}
@@ -0,0 +1,284 @@
+//! Shell sort implementations for arrays.
+//!
+//! Shell sort runs insertion sort on elements `gap` apart, for a decreasing
+//! sequence of gaps that ends with 1. The early passes move elements long
+//! distances cheaply, so by the final plain insertion sort the array is
+//! nearly sorted. It sorts in place, is not stable, and its running time
+//! depends entirely on the gap sequence:
+//!
+//! | Sequence  | Gaps                          | Worst case    |
+//! |-----------|-------------------------------|---------------|
+//! | Shell     | n/2, n/4, …, 1                | O(n²)         |
+//! | Knuth     | 1, 4, 13, 40, … ((3ᵏ − 1)/2)  | O(n^(3/2))    |
+//! | Sedgewick | 1, 5, 19, 41, 109, …          | O(n^(4/3))    |
+//! | Tokuda    | 1, 4, 9, 20, 46, 103, …       | unknown       |
+//! | Ciura     | 1, 4, 10, 23, 57, 132, …      | unknown       |
+//!
+//! Tokuda's and Ciura's sequences were found empirically and are the
+//! fastest known in practice. `shell_sort_with` reports the comparisons and
+//! moves of every pass so that sequences can be compared on real data.
+
+use super::sort_observer::SortOps;
+use std::cmp::Ordering;
+
+// Ciura's experimentally determined gaps; larger gaps continue the sequence
+// by a factor of 2.25.
+const CIURA_GAPS: [usize; 9] = [1, 4, 10, 23, 57, 132, 301, 701, 1750];
+
+/// Gap sequences for shell sort.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GapSequence {
+    /// Shell's original halving sequence.
+    Shell,
+    /// Knuth's (3ᵏ − 1)/2, up to a third of the array length.
+    Knuth,
+    /// Sedgewick's 1986 interleaving of 9·4ᵏ − 9·2ᵏ + 1 and
+    /// 4ᵏ − 3·2ᵏ + 1.
+    Sedgewick,
+    /// Tokuda's ⌈(9·(9/4)ᵏ − 4)/5⌉.
+    Tokuda,
+    /// Ciura's empirical sequence, extended by a factor of 2.25.
+    #[default]
+    Ciura,
+}
+
+impl GapSequence {
+    /// Returns the gaps used for an array of length `len`, largest first.
+    ///
+    /// Every gap is less than `len`, and the last one is 1 unless `len` is
+    /// 0 or 1, in which case there are none.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::algorithms::GapSequence;
+    ///
+    /// assert_eq!(GapSequence::Shell.gaps(20), vec![10, 5, 2, 1]);
+    /// assert_eq!(GapSequence::Knuth.gaps(100), vec![13, 4, 1]);
+    /// assert_eq!(GapSequence::Ciura.gaps(100), vec![57, 23, 10, 4, 1]);
+    /// ```
+    pub fn gaps(self, len: usize) -> Vec<usize> {
+        if len < 2 {
+            return Vec::new();
+        }
+
+        let mut gaps = Vec::new();
+        match self {
+            Self::Shell => {
+                let mut gap = len / 2;
+                while gap > 0 {
+                    gaps.push(gap);
+                    gap /= 2;
+                }
+                return gaps;
+            }
+            Self::Knuth => {
+                let limit = len.div_ceil(3);
+                let mut gap = 1;
+                gaps.push(gap);
+                while 3 * gap + 1 < limit {
+                    gap = 3 * gap + 1;
+                    gaps.push(gap);
+                }
+            }
+            Self::Sedgewick => {
+                for k in 0.. {
+                    let even = 9 * (4usize.pow(k) - 2usize.pow(k)) + 1;
+                    if even >= len {
+                        break;
+                    }
+                    gaps.push(even);
+                    let odd = 4usize.pow(k + 2) - 3 * 2usize.pow(k + 2) + 1;
+                    if odd < len {
+                        gaps.push(odd);
+                    }
+                }
+            }
+            Self::Tokuda => {
+                let mut power = 1.0f64;
+                loop {
+                    let gap = ((9.0 * power - 4.0) / 5.0).ceil() as usize;
+                    if gap >= len {
+                        break;
+                    }
+                    gaps.push(gap);
+                    power *= 2.25;
+                }
+            }
+            Self::Ciura => {
+                gaps.extend(CIURA_GAPS.iter().copied().take_while(|&gap| gap < len));
+                let mut gap = CIURA_GAPS[CIURA_GAPS.len() - 1];
+                loop {
+                    gap = (gap as f64 * 2.25) as usize;
+                    if gap >= len {
+                        break;
+                    }
+                    gaps.push(gap);
+                }
+            }
+        }
+        gaps.sort_unstable_by(|a, b| b.cmp(a));
+        gaps
+    }
+}
+
+/// Work done by one shell sort pass.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PassStats {
+    /// Distance between the elements compared in this pass.
+    pub gap: usize,
+    /// Number of calls to the comparator.
+    pub comparisons: usize,
+    /// Number of times an element was moved one gap along.
+    pub moves: usize,
+}
+
+/// Work done by a whole shell sort, pass by pass.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ShellSortStats {
+    /// One entry per gap, in the order the passes ran.
+    pub passes: Vec<PassStats>,
+}
+
+impl ShellSortStats {
+    /// Total comparisons over all passes.
+    pub fn comparisons(&self) -> usize {
+        self.passes.iter().map(|pass| pass.comparisons).sum()
+    }
+
+    /// Total moves over all passes.
+    pub fn moves(&self) -> usize {
+        self.passes.iter().map(|pass| pass.moves).sum()
+    }
+}
+
+pub(crate) fn shell<T, S: SortOps<T>>(v: &mut [T], gaps: GapSequence, s: &mut S) -> ShellSortStats {
+    let mut stats = ShellSortStats::default();
+    for gap in gaps.gaps(v.len()) {
+        let mut pass = PassStats {
+            gap,
+            comparisons: 0,
+            moves: 0,
+        };
+
+        // Insertion sort on each of the `gap` interleaved subsequences
+        for i in gap..v.len() {
+            let mut j = i;
+            while j >= gap {
+                pass.comparisons += 1;
+                if s.compare(&v[j - gap], &v[j]) != Ordering::Greater {
+                    break;
+                }
+                s.swap(v, j - gap, j);
+                pass.moves += 1;
+                j -= gap;
+            }
+        }
+        stats.passes.push(pass);
+    }
+    stats
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::test_util::{assert_sorts_like_std, random_values};
+    use crate::algorithms::Sortable;
+    use crate::core::Array;
+    use crate::dynamic_array::DynamicArray;
+
+    const SEQUENCES: [GapSequence; 5] = [
+        GapSequence::Shell,
+        GapSequence::Knuth,
+        GapSequence::Sedgewick,
+        GapSequence::Tokuda,
+        GapSequence::Ciura,
+    ];
+
+    #[test]
+    fn test_gap_sequences() {
+        assert_eq!(
+            GapSequence::Sedgewick.gaps(1000),
+            vec![929, 505, 209, 109, 41, 19, 5, 1]
+        );
+        assert_eq!(
+            GapSequence::Tokuda.gaps(1000),
+            vec![525, 233, 103, 46, 20, 9, 4, 1]
+        );
+        assert_eq!(GapSequence::Knuth.gaps(1000), vec![121, 40, 13, 4, 1]);
+        assert_eq!(
+            GapSequence::Ciura.gaps(10_000),
+            vec![8858, 3937, 1750, 701, 301, 132, 57, 23, 10, 4, 1]
+        );
+
+        for sequence in SEQUENCES {
+            assert!(sequence.gaps(0).is_empty());
+            assert!(sequence.gaps(1).is_empty());
+            assert_eq!(sequence.gaps(2), vec![1], "{sequence:?}");
+            for len in [3, 10, 100, 12_345] {
+                let gaps = sequence.gaps(len);
+                assert_eq!(gaps.last(), Some(&1), "{sequence:?}");
+                assert!(gaps[0] < len, "{sequence:?}");
+                assert!(gaps.windows(2).all(|w| w[0] > w[1]), "{sequence:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_all_sequences_match_std_sort() {
+        for sequence in SEQUENCES {
+            assert_sorts_like_std(&format!("{sequence:?}"), 20, 2000, |arr| {
+                arr.shell_sort_with(sequence);
+            });
+        }
+    }
+
+    #[test]
+    fn test_by_and_by_key() {
+        let mut arr = Array::from_slice(&[3, 1, 2], 3).unwrap();
+        arr.shell_sort_by(|a, b| b.cmp(a));
+        assert_eq!(arr.as_slice(), &[3, 2, 1]);
+
+        let mut arr = DynamicArray::new();
+        arr.extend(["ccc", "a", "bb"]);
+        arr.shell_sort_by_key(|s| s.len());
+        assert_eq!(arr.as_slice(), &["a", "bb", "ccc"]);
+    }
+
+    #[test]
+    fn test_stats_count_every_comparison() {
+        let values = random_values(3, 1000, 10_000);
+        let mut arr = DynamicArray::new();
+        arr.extend(values);
+
+        let mut counted = 0;
+        let stats = arr.shell_sort_with_by(GapSequence::Tokuda, |a, b| {
+            counted += 1;
+            a.cmp(b)
+        });
+        assert_eq!(stats.comparisons(), counted);
+        assert_eq!(stats.passes.len(), GapSequence::Tokuda.gaps(1000).len());
+
+        // Sorted input needs no moves, and one comparison per element per pass
+        let stats = arr.shell_sort_with(GapSequence::Tokuda);
+        assert_eq!(stats.moves(), 0);
+        for pass in &stats.passes {
+            assert_eq!(pass.comparisons, 1000 - pass.gap);
+        }
+    }
+
+    #[test]
+    fn test_empirical_sequences_beat_shell() {
+        let values = random_values(8, 20_000, u32::MAX);
+        let comparisons = |sequence| {
+            let mut arr = DynamicArray::new();
+            arr.extend(values.iter().copied());
+            arr.shell_sort_with(sequence).comparisons()
+        };
+
+        let shell = comparisons(GapSequence::Shell);
+        let ciura = comparisons(GapSequence::Ciura);
+        let tokuda = comparisons(GapSequence::Tokuda);
+        assert!(ciura < shell, "{ciura} vs {shell}");
+        assert!(tokuda < shell, "{tokuda} vs {shell}");
+    }
+}
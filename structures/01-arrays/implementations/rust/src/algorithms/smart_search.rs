@@ -0,0 +1,459 @@
+//! Adaptive search that picks an algorithm from the data.
+//!
+//! `SmartSearch` applies the decision table from
+//! `algorithms/searching/README.md` automatically. It inspects the sequence
+//! once, caches the resulting `SearchProfile`, and sends every query to the
+//! algorithm that profile favours:
+//!
+//! | Profile                          | Strategy      |
+//! |----------------------------------|---------------|
+//! | Unsorted                         | Linear        |
+//! | Small (≤ `SMALL_INPUT_LEN`)      | Linear        |
+//! | Sorted, sequential access only   | Jump          |
+//! | Sorted, numeric, uniform keys    | Interpolation |
+//! | Sorted otherwise                 | Binary        |
+//!
+//! The profile is taken when the `SmartSearch` is created, so it must not
+//! outlive changes to the sequence. The borrow checker enforces this.
+
+use super::binary_search::bounded_search;
+use super::interpolation_search::interpolate;
+use super::jump_search::{jump_with_size, optimal_jump_size};
+use super::{is_sorted_by, InterpolationKey, LinearSearchable};
+use crate::sequence::Sequence;
+use std::fmt;
+
+/// Sequences up to this length are always scanned linearly.
+pub const SMALL_INPUT_LEN: usize = 64;
+
+/// Interpolation needs enough elements for O(log log n) to beat O(log n).
+pub const MIN_INTERPOLATION_LEN: usize = 1024;
+
+/// Largest sampled deviation from a straight line, as a fraction of the key
+/// range, for keys to count as uniformly distributed.
+pub const MAX_UNIFORM_SKEW: f64 = 0.05;
+
+/// Number of keys sampled to estimate the distribution.
+pub const DISTRIBUTION_SAMPLES: usize = 64;
+
+/// The search algorithm `SmartSearch` dispatches to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchStrategy {
+    /// `LinearSearchable::linear_search`
+    Linear,
+    /// `BinarySearchable::binary_search`
+    Binary,
+    /// `JumpSearchable::jump_search`
+    Jump,
+    /// `InterpolationSearchable::interpolation_search`
+    Interpolation,
+}
+
+/// Why `SmartSearch` picked its strategy.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StrategyReason {
+    /// Only linear search is correct on unsorted data.
+    Unsorted,
+    /// Too few elements for a smarter algorithm to pay off.
+    SmallInput,
+    /// Jump search only steps backwards once, which suits sequential media.
+    SequentialAccess,
+    /// Sampled keys lie close to a straight line.
+    UniformKeys { skew: f64 },
+    /// Sampled keys are clustered, so interpolation guesses would be poor.
+    SkewedKeys { skew: f64 },
+    /// Keys are numeric, but there are too few for interpolation to pay off.
+    TooFewForInterpolation,
+    /// Key values were not sampled, so interpolation was not considered.
+    ///
+    /// `SmartSearch::new` reports this for every key type, numeric or not;
+    /// use `SmartSearch::numeric` to enable interpolation for integer keys.
+    InterpolationNotEnabled,
+}
+
+impl fmt::Display for StrategyReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unsorted => write!(f, "data is unsorted, only linear search is correct"),
+            Self::SmallInput => write!(f, "at most {SMALL_INPUT_LEN} elements, a scan is fastest"),
+            Self::SequentialAccess => write!(f, "sequential access, jump search avoids back-seeks"),
+            Self::UniformKeys { skew } => {
+                write!(
+                    f,
+                    "keys are uniform (skew {skew:.3}), interpolation is O(log log n)"
+                )
+            }
+            Self::SkewedKeys { skew } => {
+                write!(
+                    f,
+                    "keys are skewed (skew {skew:.3}), binary search is safer"
+                )
+            }
+            Self::TooFewForInterpolation => {
+                write!(
+                    f,
+                    "fewer than {MIN_INTERPOLATION_LEN} keys, binary search is simpler"
+                )
+            }
+            Self::InterpolationNotEnabled => write!(
+                f,
+                "interpolation not enabled (see SmartSearch::numeric), binary search is O(log n)"
+            ),
+        }
+    }
+}
+
+/// What `SmartSearch` learned from one pass over the data.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SearchProfile {
+    /// Number of elements at profiling time.
+    pub len: usize,
+    /// Whether the elements are in ascending order.
+    pub sorted: bool,
+    /// Largest sampled deviation from uniform, or `None` if keys were not sampled.
+    pub skew: Option<f64>,
+    /// Whether the caller declared that only forward scans are cheap.
+    pub sequential_access: bool,
+}
+
+impl SearchProfile {
+    /// Chooses the strategy for this profile.
+    pub fn choose(&self) -> (SearchStrategy, StrategyReason) {
+        if !self.sorted {
+            return (SearchStrategy::Linear, StrategyReason::Unsorted);
+        }
+        if self.len <= SMALL_INPUT_LEN {
+            return (SearchStrategy::Linear, StrategyReason::SmallInput);
+        }
+        if self.sequential_access {
+            return (SearchStrategy::Jump, StrategyReason::SequentialAccess);
+        }
+
+        match self.skew {
+            None => (
+                SearchStrategy::Binary,
+                StrategyReason::InterpolationNotEnabled,
+            ),
+            Some(_) if self.len < MIN_INTERPOLATION_LEN => (
+                SearchStrategy::Binary,
+                StrategyReason::TooFewForInterpolation,
+            ),
+            Some(skew) if skew <= MAX_UNIFORM_SKEW => (
+                SearchStrategy::Interpolation,
+                StrategyReason::UniformKeys { skew },
+            ),
+            Some(skew) => (SearchStrategy::Binary, StrategyReason::SkewedKeys { skew }),
+        }
+    }
+}
+
+/// A search facade that profiles a sequence once and then dispatches every
+/// query to the best algorithm for it.
+///
+/// # Examples
+/// ```
+/// use arrays::dynamic_array::DynamicArray;
+/// use arrays::algorithms::smart_search::{SearchStrategy, SmartSearch};
+///
+/// let mut ids = DynamicArray::new();
+/// ids.extend((0..10_000u32).map(|i| i * 5));
+///
+/// let search = SmartSearch::numeric(&ids);
+/// assert_eq!(search.strategy(), SearchStrategy::Interpolation);
+/// assert_eq!(search.search(&4_995), Some(999));
+/// ```
+pub struct SmartSearch<'a, T, S: ?Sized> {
+    seq: &'a S,
+    profile: SearchProfile,
+    strategy: SearchStrategy,
+    reason: StrategyReason,
+    interpolate: fn(&S, &T) -> Option<usize>,
+}
+
+impl<'a, T, S> SmartSearch<'a, T, S>
+where
+    T: Ord,
+    S: Sequence<T> + ?Sized,
+{
+    /// Profiles `seq` without looking at key values.
+    ///
+    /// Interpolation is never chosen, even for numeric keys, and sorted
+    /// input reports `StrategyReason::InterpolationNotEnabled`. Use `numeric`
+    /// for integer keys.
+    pub fn new(seq: &'a S) -> Self {
+        Self::from_profile(seq, profile(seq, None), |_, _| None)
+    }
+
+    /// Declares that only forward scans are cheap, as on tape or other
+    /// sequential media, and re-chooses the strategy.
+    pub fn assume_sequential_access(self) -> Self {
+        let profile = SearchProfile {
+            sequential_access: true,
+            ..self.profile
+        };
+        Self::from_profile(self.seq, profile, self.interpolate)
+    }
+
+    /// Returns the cached profile.
+    pub fn profile(&self) -> &SearchProfile {
+        &self.profile
+    }
+
+    /// Returns the strategy every query is dispatched to.
+    pub fn strategy(&self) -> SearchStrategy {
+        self.strategy
+    }
+
+    /// Returns why the strategy was chosen.
+    pub fn reason(&self) -> StrategyReason {
+        self.reason
+    }
+
+    /// Searches for `target` with the chosen strategy.
+    ///
+    /// Like `binary_search`, any matching index may be returned when the
+    /// sequence contains duplicates.
+    pub fn search(&self, target: &T) -> Option<usize> {
+        // The profile already found the sequence sorted, so the searches
+        // skip their own sortedness checks
+        match self.strategy {
+            SearchStrategy::Linear => self.seq.linear_search(target),
+            SearchStrategy::Binary => bounded_search(self.seq, target, true),
+            SearchStrategy::Jump => {
+                jump_with_size(self.seq, target, optimal_jump_size(self.seq.len()))
+            }
+            SearchStrategy::Interpolation => (self.interpolate)(self.seq, target),
+        }
+    }
+
+    // `interpolate` is only called when the profile chose interpolation,
+    // which requires numeric keys.
+    fn from_profile(
+        seq: &'a S,
+        profile: SearchProfile,
+        interpolate: fn(&S, &T) -> Option<usize>,
+    ) -> Self {
+        let (strategy, reason) = profile.choose();
+        Self {
+            seq,
+            profile,
+            strategy,
+            reason,
+            interpolate,
+        }
+    }
+}
+
+impl<'a, T, S> SmartSearch<'a, T, S>
+where
+    T: InterpolationKey,
+    S: Sequence<T> + ?Sized,
+{
+    /// Profiles `seq`, including a sample of the key distribution.
+    pub fn numeric(seq: &'a S) -> Self {
+        let skew = sample_skew(seq);
+        Self::from_profile(seq, profile(seq, Some(skew)), interpolate)
+    }
+}
+
+fn profile<T: Ord, S: Sequence<T> + ?Sized>(seq: &S, skew: Option<f64>) -> SearchProfile {
+    let len = seq.len();
+    let sorted = is_sorted_by(seq, T::cmp);
+
+    SearchProfile {
+        len,
+        sorted,
+        skew,
+        sequential_access: false,
+    }
+}
+
+// Compares evenly spaced keys against the straight line from the first key
+// to the last, and returns the largest gap as a fraction of the key range.
+fn sample_skew<T: InterpolationKey, S: Sequence<T> + ?Sized>(seq: &S) -> f64 {
+    let len = seq.len();
+    let (Some(first), Some(last)) = (seq.get(0), seq.get(len.saturating_sub(1))) else {
+        return 0.0;
+    };
+
+    let (first, last) = (first.to_f64(), last.to_f64());
+    let range = last - first;
+    if len < 3 || range <= 0.0 {
+        return 0.0;
+    }
+
+    let samples = DISTRIBUTION_SAMPLES.min(len);
+    (0..samples)
+        .map(|s| s * (len - 1) / (samples - 1))
+        .filter_map(|i| {
+            let expected = first + range * i as f64 / (len - 1) as f64;
+            seq.get(i)
+                .map(|key| (key.to_f64() - expected).abs() / range)
+        })
+        .fold(0.0, f64::max)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Array;
+    use crate::dynamic_array::DynamicArray;
+    use std::cell::Cell;
+
+    // Counts how many elements the searches read
+    struct Probed<'a> {
+        values: &'a [u64],
+        reads: Cell<usize>,
+    }
+
+    impl Sequence<u64> for Probed<'_> {
+        fn len(&self) -> usize {
+            self.values.len()
+        }
+
+        fn get(&self, index: usize) -> Option<&u64> {
+            self.reads.set(self.reads.get() + 1);
+            self.values.get(index)
+        }
+    }
+
+    fn multiples(len: u64, step: u64) -> DynamicArray<u64> {
+        let mut arr = DynamicArray::with_capacity(len as usize);
+        arr.extend((0..len).map(|i| i * step));
+        arr
+    }
+
+    #[test]
+    fn test_unsorted_uses_linear() {
+        let mut arr = multiples(1000, 3);
+        arr.push(1);
+
+        let search = SmartSearch::numeric(&arr);
+        assert_eq!(search.strategy(), SearchStrategy::Linear);
+        assert_eq!(search.reason(), StrategyReason::Unsorted);
+        assert_eq!(search.search(&1), Some(1000));
+        assert!(!search.profile().sorted);
+    }
+
+    #[test]
+    fn test_small_input_uses_linear() {
+        let arr = Array::from_slice(&[1, 2, 3, 4, 5], 10).unwrap();
+
+        let search = SmartSearch::new(&arr);
+        assert_eq!(search.strategy(), SearchStrategy::Linear);
+        assert_eq!(search.reason(), StrategyReason::SmallInput);
+        assert_eq!(search.search(&4), Some(3));
+    }
+
+    #[test]
+    fn test_uniform_numeric_uses_interpolation() {
+        let arr = multiples(5000, 7);
+
+        let search = SmartSearch::numeric(&arr);
+        assert_eq!(search.strategy(), SearchStrategy::Interpolation);
+        assert!(matches!(
+            search.reason(),
+            StrategyReason::UniformKeys { .. }
+        ));
+        assert_eq!(search.search(&(700 * 7)), Some(700));
+        assert_eq!(search.search(&1), None);
+    }
+
+    #[test]
+    fn test_skewed_numeric_uses_binary() {
+        let mut arr = DynamicArray::new();
+        arr.extend((0..2000u64).map(|i| i * i * i));
+
+        let search = SmartSearch::numeric(&arr);
+        assert_eq!(search.strategy(), SearchStrategy::Binary);
+        match search.reason() {
+            StrategyReason::SkewedKeys { skew } => assert!(skew > MAX_UNIFORM_SKEW),
+            other => panic!("unexpected reason {other:?}"),
+        }
+        assert_eq!(search.search(&(10 * 10 * 10)), Some(10));
+    }
+
+    #[test]
+    fn test_non_numeric_uses_binary() {
+        let mut words = DynamicArray::new();
+        words.extend((0..100).map(|i| format!("word{i:03}")));
+
+        let search = SmartSearch::new(&words);
+        assert_eq!(search.strategy(), SearchStrategy::Binary);
+        assert_eq!(search.reason(), StrategyReason::InterpolationNotEnabled);
+        assert_eq!(search.search(&"word042".to_string()), Some(42));
+        assert_eq!(search.profile().skew, None);
+    }
+
+    #[test]
+    fn test_new_does_not_claim_numeric_keys_are_non_numeric() {
+        let arr = multiples(5000, 7);
+
+        let search = SmartSearch::new(&arr);
+        assert_eq!(search.strategy(), SearchStrategy::Binary);
+        assert_eq!(search.reason(), StrategyReason::InterpolationNotEnabled);
+        assert!(search.reason().to_string().contains("SmartSearch::numeric"));
+        assert_eq!(search.search(&(700 * 7)), Some(700));
+    }
+
+    #[test]
+    fn test_mid_sized_numeric_uses_binary() {
+        let arr = multiples(500, 2);
+
+        let search = SmartSearch::numeric(&arr);
+        assert_eq!(search.reason(), StrategyReason::TooFewForInterpolation);
+        assert_eq!(search.search(&998), Some(499));
+    }
+
+    #[test]
+    fn test_sequential_access_uses_jump() {
+        let arr = multiples(5000, 7);
+
+        let search = SmartSearch::numeric(&arr).assume_sequential_access();
+        assert_eq!(search.strategy(), SearchStrategy::Jump);
+        assert_eq!(search.reason(), StrategyReason::SequentialAccess);
+        assert_eq!(search.search(&35), Some(5));
+
+        // Unsorted data still needs a linear scan
+        let unsorted = Array::from_slice(&[3, 1, 2], 3).unwrap();
+        let search = SmartSearch::new(&unsorted).assume_sequential_access();
+        assert_eq!(search.strategy(), SearchStrategy::Linear);
+    }
+
+    #[test]
+    fn test_reason_is_printable() {
+        let arr = multiples(5000, 7);
+        let search = SmartSearch::numeric(&arr);
+
+        assert!(search.reason().to_string().contains("interpolation"));
+    }
+
+    #[test]
+    fn test_empty_sequence() {
+        let arr: DynamicArray<u64> = DynamicArray::new();
+        let search = SmartSearch::numeric(&arr);
+
+        assert_eq!(search.strategy(), SearchStrategy::Linear);
+        assert_eq!(search.search(&1), None);
+    }
+
+    #[test]
+    fn test_queries_do_not_rescan_the_sequence() {
+        let arr = multiples(5000, 7);
+        let probed = Probed {
+            values: arr.as_slice(),
+            reads: Cell::new(0),
+        };
+
+        for search in [
+            SmartSearch::new(&probed),
+            SmartSearch::numeric(&probed),
+            SmartSearch::numeric(&probed).assume_sequential_access(),
+        ] {
+            probed.reads.set(0);
+            assert_eq!(search.search(&(700 * 7)), Some(700));
+            let reads = probed.reads.get();
+            assert!(reads < 200, "{:?} read {reads} elements", search.strategy());
+        }
+    }
+}
@@ -0,0 +1,703 @@
+//! Instrumentation for the sorting algorithms.
+//!
+//! Every sort in `algorithms` does its work through three operations:
+//! comparing two elements, swapping two elements, and writing an element
+//! into a slot. A `SortObserver` hears about each of them as it happens,
+//! which is enough to count the work a sort does or to replay it step by
+//! step:
+//!
+//! - `SortStats` counts comparisons, swaps and writes, for checking that a
+//!   sort really does the O(n²) or O(n log n) work its analysis claims.
+//! - `FrameRecorder` replays the swaps and writes on its own copy of the
+//!   array and keeps a snapshot after each one, ready to draw as the
+//!   frames of an animation.
+//!
+//! Comparison sorts run with an observer through `Sortable::sort_observed`, which
+//! picks the algorithm with a `SortAlgorithm`. The merge sorts and Timsort,
+//! which copy elements into a scratch buffer and so need `T: Clone`, are
+//! picked with a `BufferedSortAlgorithm` and run through
+//! `Sortable::sort_observed_buffered` instead. The partial sorts in
+//! `PartialSortable` and the distribution sorts have `*_observed` variants
+//! of their own.
+//!
+//! Indices in events always refer to the whole array, even while an
+//! algorithm works on a part of it. Comparisons are reported without
+//! indices, since merges and partitions also compare against elements held
+//! outside the array. Sorts that shuffle elements through a scratch buffer
+//! report a write for each element they copy back.
+
+use super::bubble_sort::{bubble, bubble_early_exit, cocktail_shaker};
+use super::heap_sort::heap;
+use super::hybrid_sort::{introsort, timsort};
+use super::insertion_sort::{binary_insertion_from, insertion};
+use super::merge_sort::{bottom_up, in_place, top_down};
+use super::quick_sort::{quick, QuickSortConfig};
+use super::selection_sort::selection;
+use super::shell_sort::{shell, GapSequence};
+use std::cmp::Ordering;
+use std::ops::Range;
+
+/// Receives the operations a sort performs, as it performs them.
+///
+/// Every method does nothing by default, so an observer only implements
+/// the events it cares about. `()` is the observer that ignores everything.
+pub trait SortObserver<T> {
+    /// Called for each comparison between two elements.
+    fn on_compare(&mut self) {}
+
+    /// Called after the elements at `i` and `j` have been swapped.
+    fn on_swap(&mut self, i: usize, j: usize) {
+        let _ = (i, j);
+    }
+
+    /// Called after `value` has been written to `index`, replacing the
+    /// element that was there.
+    fn on_write(&mut self, index: usize, value: &T) {
+        let _ = (index, value);
+    }
+}
+
+impl<T> SortObserver<T> for () {}
+
+impl<T, O: SortObserver<T> + ?Sized> SortObserver<T> for &mut O {
+    fn on_compare(&mut self) {
+        (**self).on_compare();
+    }
+
+    fn on_swap(&mut self, i: usize, j: usize) {
+        (**self).on_swap(i, j);
+    }
+
+    fn on_write(&mut self, index: usize, value: &T) {
+        (**self).on_write(index, value);
+    }
+}
+
+/// Both observers hear every event, so a sort can be counted and recorded
+/// in one run.
+impl<T, A: SortObserver<T>, B: SortObserver<T>> SortObserver<T> for (A, B) {
+    fn on_compare(&mut self) {
+        self.0.on_compare();
+        self.1.on_compare();
+    }
+
+    fn on_swap(&mut self, i: usize, j: usize) {
+        self.0.on_swap(i, j);
+        self.1.on_swap(i, j);
+    }
+
+    fn on_write(&mut self, index: usize, value: &T) {
+        self.0.on_write(index, value);
+        self.1.on_write(index, value);
+    }
+}
+
+/// Counts the operations of a sort.
+///
+/// # Examples
+/// ```
+/// use arrays::dynamic_array::DynamicArray;
+/// use arrays::algorithms::{SortAlgorithm, SortStats, Sortable};
+///
+/// let mut arr = DynamicArray::new();
+/// arr.extend((0..100).rev());
+/// let mut stats = SortStats::default();
+/// arr.sort_observed(SortAlgorithm::Bubble, &mut stats);
+///
+/// // Reversed input is bubble sort's worst case
+/// assert_eq!(stats.comparisons, 100 * 99 / 2);
+/// assert_eq!(stats.swaps, 100 * 99 / 2);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SortStats {
+    /// Number of comparisons.
+    pub comparisons: usize,
+    /// Number of swaps.
+    pub swaps: usize,
+    /// Number of single-element writes.
+    pub writes: usize,
+}
+
+impl<T> SortObserver<T> for SortStats {
+    fn on_compare(&mut self) {
+        self.comparisons += 1;
+    }
+
+    fn on_swap(&mut self, _i: usize, _j: usize) {
+        self.swaps += 1;
+    }
+
+    fn on_write(&mut self, _index: usize, _value: &T) {
+        self.writes += 1;
+    }
+}
+
+/// A change to the array, as recorded in a `Frame`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortStep {
+    /// The elements at the two indices were swapped.
+    Swap(usize, usize),
+    /// A new value was written to the index.
+    Write(usize),
+}
+
+/// The state of the array after one step of a sort.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Frame<T> {
+    /// The step that produced this state.
+    pub step: SortStep,
+    /// Comparisons made since the start of the sort.
+    pub comparisons: usize,
+    /// The whole array after the step.
+    pub array: Vec<T>,
+}
+
+/// Records a snapshot of the array after every swap and write, for
+/// animating a sort.
+///
+/// The recorder never sees the array being sorted. It starts from a copy of
+/// the input and applies each reported step to it, so the frames show
+/// exactly what the sort did. Every frame holds a full copy of the array:
+/// keep inputs small.
+///
+/// # Examples
+/// ```
+/// use arrays::core::Array;
+/// use arrays::algorithms::{FrameRecorder, SortAlgorithm, SortStep, Sortable};
+///
+/// let mut arr = Array::from_slice(&[3, 1, 2], 3).unwrap();
+/// let mut recorder = FrameRecorder::new(arr.as_slice());
+/// arr.sort_observed(SortAlgorithm::Insertion, &mut recorder);
+///
+/// let frames = recorder.frames();
+/// assert_eq!(frames[0].step, SortStep::Swap(0, 1));
+/// assert_eq!(frames[0].array, vec![1, 3, 2]);
+/// assert_eq!(frames.last().unwrap().array, vec![1, 2, 3]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct FrameRecorder<T> {
+    current: Vec<T>,
+    comparisons: usize,
+    frames: Vec<Frame<T>>,
+}
+
+impl<T: Clone> FrameRecorder<T> {
+    /// Creates a recorder for a sort of `initial`.
+    pub fn new(initial: &[T]) -> Self {
+        Self {
+            current: initial.to_vec(),
+            comparisons: 0,
+            frames: Vec::new(),
+        }
+    }
+
+    /// The array as of the last recorded step.
+    pub fn current(&self) -> &[T] {
+        &self.current
+    }
+
+    /// The recorded frames, in order.
+    pub fn frames(&self) -> &[Frame<T>] {
+        &self.frames
+    }
+
+    /// Consumes the recorder and returns its frames.
+    pub fn into_frames(self) -> Vec<Frame<T>> {
+        self.frames
+    }
+
+    fn record(&mut self, step: SortStep) {
+        self.frames.push(Frame {
+            step,
+            comparisons: self.comparisons,
+            array: self.current.clone(),
+        });
+    }
+}
+
+impl<T: Clone> SortObserver<T> for FrameRecorder<T> {
+    fn on_compare(&mut self) {
+        self.comparisons += 1;
+    }
+
+    fn on_swap(&mut self, i: usize, j: usize) {
+        self.current.swap(i, j);
+        self.record(SortStep::Swap(i, j));
+    }
+
+    fn on_write(&mut self, index: usize, value: &T) {
+        self.current[index] = value.clone();
+        self.record(SortStep::Write(index));
+    }
+}
+
+/// The comparison sorts that can run under an observer without a scratch
+/// buffer, so for any element type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortAlgorithm {
+    /// `Sortable::bubble_sort`.
+    Bubble,
+    /// `Sortable::bubble_sort_early_exit`.
+    BubbleEarlyExit,
+    /// `Sortable::cocktail_shaker_sort`.
+    CocktailShaker,
+    /// `Sortable::insertion_sort`.
+    Insertion,
+    /// `Sortable::binary_insertion_sort`.
+    BinaryInsertion,
+    /// `Sortable::selection_sort`.
+    Selection,
+    /// `Sortable::stable_selection_sort`.
+    StableSelection,
+    /// `Sortable::quick_sort_with`.
+    Quick(QuickSortConfig),
+    /// `Sortable::in_place_merge_sort`.
+    MergeInPlace,
+    /// `Sortable::heap_sort`.
+    Heap,
+    /// `Sortable::shell_sort_with`.
+    Shell(GapSequence),
+    /// `Sortable::sort_unstable`.
+    Introsort,
+}
+
+/// The comparison sorts that merge through a scratch buffer, and so can
+/// only run under an observer when the elements are `Clone`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BufferedSortAlgorithm {
+    /// `Sortable::merge_sort`.
+    MergeTopDown,
+    /// `Sortable::bottom_up_merge_sort`.
+    MergeBottomUp,
+    /// `Sortable::sort`.
+    Timsort,
+}
+
+pub(crate) fn run<T, S: SortOps<T>>(v: &mut [T], algorithm: SortAlgorithm, s: &mut S) {
+    match algorithm {
+        SortAlgorithm::Bubble => bubble(v, s),
+        SortAlgorithm::BubbleEarlyExit => bubble_early_exit(v, s),
+        SortAlgorithm::CocktailShaker => cocktail_shaker(v, s),
+        SortAlgorithm::Insertion => insertion(v, s),
+        SortAlgorithm::BinaryInsertion => binary_insertion_from(v, 1, s),
+        SortAlgorithm::Selection => selection(v, false, s),
+        SortAlgorithm::StableSelection => selection(v, true, s),
+        SortAlgorithm::Quick(config) => {
+            quick(v, &config, s);
+        }
+        SortAlgorithm::MergeInPlace => in_place(v, s),
+        SortAlgorithm::Heap => heap(v, s),
+        SortAlgorithm::Shell(gaps) => {
+            shell(v, gaps, s);
+        }
+        SortAlgorithm::Introsort => introsort(v, s),
+    }
+}
+
+pub(crate) fn run_buffered<T: Clone, S: SortOps<T>>(
+    v: &mut [T],
+    algorithm: BufferedSortAlgorithm,
+    s: &mut S,
+) {
+    match algorithm {
+        BufferedSortAlgorithm::MergeTopDown => top_down(v, s),
+        BufferedSortAlgorithm::MergeBottomUp => bottom_up(v, s),
+        BufferedSortAlgorithm::Timsort => timsort(v, s),
+    }
+}
+
+/// The operations a sort performs on the array, routed through an observer.
+///
+/// The algorithms are written against this trait rather than comparing and
+/// swapping directly. Algorithms that work on a subslice wrap the work in
+/// `within`, so that reported indices stay relative to the whole array.
+pub(crate) trait SortOps<T> {
+    /// Compares two elements.
+    fn compare(&mut self, a: &T, b: &T) -> Ordering;
+
+    /// Swaps `v[i]` and `v[j]`.
+    fn swap(&mut self, v: &mut [T], i: usize, j: usize);
+
+    /// Writes `value` to `v[index]`.
+    fn set(&mut self, v: &mut [T], index: usize, value: T);
+
+    /// Reports writes to `v[range]` made without `swap` or `set`, such as a
+    /// rotation or a bulk copy.
+    fn wrote(&mut self, v: &[T], range: Range<usize>);
+
+    /// Runs `f` on a subslice starting at `offset` within the current one.
+    fn within<R>(&mut self, offset: usize, f: impl FnOnce(&mut Self) -> R) -> R
+    where
+        Self: Sized;
+
+    fn is_less(&mut self, a: &T, b: &T) -> bool {
+        self.compare(a, b) == Ordering::Less
+    }
+
+    fn rotate_left(&mut self, v: &mut [T], mid: usize) {
+        v.rotate_left(mid);
+        self.wrote(v, 0..v.len());
+    }
+
+    fn rotate_right(&mut self, v: &mut [T], k: usize) {
+        v.rotate_right(k);
+        self.wrote(v, 0..v.len());
+    }
+
+    fn reverse(&mut self, v: &mut [T]) {
+        let n = v.len();
+        for i in 0..n / 2 {
+            self.swap(v, i, n - 1 - i);
+        }
+    }
+}
+
+/// A comparator paired with an observer.
+pub(crate) struct Sorter<F, O> {
+    compare: F,
+    observer: O,
+    offset: usize,
+}
+
+impl<F> Sorter<F, ()> {
+    /// Sorts with `compare` and no observer.
+    pub(crate) fn new(compare: F) -> Self {
+        Self::observed(compare, ())
+    }
+}
+
+impl<F, O> Sorter<F, O> {
+    pub(crate) fn observed(compare: F, observer: O) -> Self {
+        Self {
+            compare,
+            observer,
+            offset: 0,
+        }
+    }
+}
+
+impl<T, F, O> SortOps<T> for Sorter<F, O>
+where
+    F: FnMut(&T, &T) -> Ordering,
+    O: SortObserver<T>,
+{
+    fn compare(&mut self, a: &T, b: &T) -> Ordering {
+        self.observer.on_compare();
+        (self.compare)(a, b)
+    }
+
+    fn swap(&mut self, v: &mut [T], i: usize, j: usize) {
+        v.swap(i, j);
+        self.observer.on_swap(self.offset + i, self.offset + j);
+    }
+
+    fn set(&mut self, v: &mut [T], index: usize, value: T) {
+        v[index] = value;
+        self.observer.on_write(self.offset + index, &v[index]);
+    }
+
+    fn wrote(&mut self, v: &[T], range: Range<usize>) {
+        for index in range {
+            self.observer.on_write(self.offset + index, &v[index]);
+        }
+    }
+
+    fn within<R>(&mut self, offset: usize, f: impl FnOnce(&mut Self) -> R) -> R {
+        self.offset += offset;
+        let result = f(self);
+        self.offset -= offset;
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::test_util::random_values;
+    use crate::algorithms::Sortable;
+    use crate::algorithms::{DistributionSortable, PartitionScheme, PivotStrategy};
+    use crate::core::Array;
+    use crate::dynamic_array::DynamicArray;
+
+    // Either kind of observed sort, so the tests can run them all in one loop
+    #[derive(Debug, Clone, Copy)]
+    enum Algorithm {
+        InPlace(SortAlgorithm),
+        Buffered(BufferedSortAlgorithm),
+    }
+
+    impl From<SortAlgorithm> for Algorithm {
+        fn from(algorithm: SortAlgorithm) -> Self {
+            Algorithm::InPlace(algorithm)
+        }
+    }
+
+    impl From<BufferedSortAlgorithm> for Algorithm {
+        fn from(algorithm: BufferedSortAlgorithm) -> Self {
+            Algorithm::Buffered(algorithm)
+        }
+    }
+
+    fn in_place_algorithms() -> Vec<SortAlgorithm> {
+        let mut algorithms = vec![
+            SortAlgorithm::Bubble,
+            SortAlgorithm::BubbleEarlyExit,
+            SortAlgorithm::CocktailShaker,
+            SortAlgorithm::Insertion,
+            SortAlgorithm::BinaryInsertion,
+            SortAlgorithm::Selection,
+            SortAlgorithm::StableSelection,
+            SortAlgorithm::MergeInPlace,
+            SortAlgorithm::Heap,
+            SortAlgorithm::Shell(GapSequence::Ciura),
+            SortAlgorithm::Introsort,
+        ];
+        for partition in [
+            PartitionScheme::Lomuto,
+            PartitionScheme::Hoare,
+            PartitionScheme::ThreeWay,
+        ] {
+            algorithms.push(SortAlgorithm::Quick(QuickSortConfig {
+                partition,
+                pivot: PivotStrategy::Ninther,
+                insertion_threshold: 4,
+            }));
+        }
+        algorithms
+    }
+
+    fn algorithms() -> Vec<Algorithm> {
+        let buffered = [
+            BufferedSortAlgorithm::MergeTopDown,
+            BufferedSortAlgorithm::MergeBottomUp,
+            BufferedSortAlgorithm::Timsort,
+        ];
+        in_place_algorithms()
+            .into_iter()
+            .map(Algorithm::from)
+            .chain(buffered.into_iter().map(Algorithm::from))
+            .collect()
+    }
+
+    fn sort_observed_by<F, O>(
+        arr: &mut DynamicArray<u32>,
+        algorithm: Algorithm,
+        compare: F,
+        observer: &mut O,
+    ) where
+        F: FnMut(&u32, &u32) -> Ordering,
+        O: SortObserver<u32> + ?Sized,
+    {
+        match algorithm {
+            Algorithm::InPlace(algorithm) => arr.sort_observed_by(algorithm, compare, observer),
+            Algorithm::Buffered(algorithm) => {
+                arr.sort_observed_buffered_by(algorithm, compare, observer)
+            }
+        }
+    }
+
+    fn stats_for(algorithm: impl Into<Algorithm>, values: &[u32]) -> SortStats {
+        let mut arr = DynamicArray::new();
+        arr.extend(values.iter().copied());
+        let mut stats = SortStats::default();
+        sort_observed_by(&mut arr, algorithm.into(), u32::cmp, &mut stats);
+        stats
+    }
+
+    #[test]
+    fn test_frames_replay_every_sort() {
+        // Long enough for Timsort to find several runs and for the ninther,
+        // so every algorithm works on subslices at nonzero offsets
+        for (seed, max) in [(1, 50), (2, 1_000), (3, 4)] {
+            let values = random_values(seed, 300, max);
+            let mut expected = values.clone();
+            expected.sort();
+
+            for algorithm in algorithms() {
+                let mut arr = DynamicArray::new();
+                arr.extend(values.iter().copied());
+                let mut recorder = FrameRecorder::new(arr.as_slice());
+                sort_observed_by(&mut arr, algorithm, u32::cmp, &mut recorder);
+
+                assert_eq!(arr.as_slice(), expected, "{algorithm:?}");
+                assert_eq!(recorder.current(), expected, "{algorithm:?}");
+                assert_eq!(
+                    recorder.frames().last().unwrap().array,
+                    expected,
+                    "{algorithm:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_stats_count_every_comparison() {
+        let values = random_values(4, 500, 100);
+        for algorithm in algorithms() {
+            let mut arr = DynamicArray::new();
+            arr.extend(values.iter().copied());
+            let mut comparisons = 0;
+            let mut stats = SortStats::default();
+            sort_observed_by(
+                &mut arr,
+                algorithm,
+                |a, b| {
+                    comparisons += 1;
+                    a.cmp(b)
+                },
+                &mut stats,
+            );
+            assert_eq!(stats.comparisons, comparisons, "{algorithm:?}");
+        }
+    }
+
+    #[test]
+    fn test_quadratic_and_linearithmic_growth() {
+        let small = random_values(5, 1_000, u32::MAX);
+        let large = random_values(6, 2_000, u32::MAX);
+
+        // Doubling n quadruples the work of the quadratic sorts...
+        for algorithm in [SortAlgorithm::Bubble, SortAlgorithm::Selection] {
+            let ratio = stats_for(algorithm, &large).comparisons as f64
+                / stats_for(algorithm, &small).comparisons as f64;
+            assert!((3.9..4.1).contains(&ratio), "{algorithm:?}: {ratio}");
+        }
+
+        // ...and only slightly more than doubles it for the n log n ones
+        for algorithm in [
+            Algorithm::Buffered(BufferedSortAlgorithm::MergeTopDown),
+            Algorithm::InPlace(SortAlgorithm::Heap),
+            Algorithm::InPlace(SortAlgorithm::Introsort),
+        ] {
+            let ratio = stats_for(algorithm, &large).comparisons as f64
+                / stats_for(algorithm, &small).comparisons as f64;
+            assert!((2.0..2.4).contains(&ratio), "{algorithm:?}: {ratio}");
+        }
+    }
+
+    #[test]
+    fn test_textbook_counts() {
+        let n = 256;
+        let reversed: Vec<u32> = (0..n as u32).rev().collect();
+        let sorted: Vec<u32> = (0..n as u32).collect();
+
+        let stats = stats_for(SortAlgorithm::Bubble, &reversed);
+        assert_eq!(stats.comparisons, n * (n - 1) / 2);
+        assert_eq!(stats.swaps, n * (n - 1) / 2);
+
+        // Selection sort makes at most n - 1 swaps whatever the input
+        let stats = stats_for(SortAlgorithm::Selection, &random_values(7, n, 1_000));
+        assert_eq!(stats.comparisons, n * (n - 1) / 2);
+        assert!(stats.swaps < n);
+
+        let stats = stats_for(SortAlgorithm::Insertion, &sorted);
+        assert_eq!(stats.comparisons, n - 1);
+        assert_eq!(stats.swaps + stats.writes, 0);
+
+        // Merge sort never makes more than n log2 n comparisons
+        let stats = stats_for(
+            BufferedSortAlgorithm::MergeTopDown,
+            &random_values(8, n, 1_000),
+        );
+        assert!(stats.comparisons <= n * 8);
+    }
+
+    #[test]
+    fn test_frames_follow_the_steps() {
+        let mut arr = Array::from_slice(&[2, 3, 1], 3).unwrap();
+        let mut recorder = FrameRecorder::new(arr.as_slice());
+        arr.sort_observed(SortAlgorithm::Bubble, &mut recorder);
+
+        let frames = recorder.into_frames();
+        let steps: Vec<_> = frames.iter().map(|frame| frame.step).collect();
+        assert_eq!(steps, [SortStep::Swap(1, 2), SortStep::Swap(0, 1)]);
+        assert_eq!(frames[0].array, [2, 1, 3]);
+        assert_eq!(frames[1].array, [1, 2, 3]);
+        // Both swaps follow the comparison that triggered them
+        assert_eq!(frames[0].comparisons, 2);
+        assert_eq!(frames[1].comparisons, 3);
+    }
+
+    #[test]
+    fn test_in_place_sorts_do_not_need_clone() {
+        #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+        struct Token(u32);
+
+        let values = random_values(11, 200, 50);
+        let mut expected = values.clone();
+        expected.sort();
+
+        for algorithm in in_place_algorithms() {
+            let mut arr = DynamicArray::new();
+            arr.extend(values.iter().copied().map(Token));
+            let mut stats = SortStats::default();
+            arr.sort_observed(algorithm, &mut stats);
+
+            let sorted: Vec<u32> = arr.as_slice().iter().map(|token| token.0).collect();
+            assert_eq!(sorted, expected, "{algorithm:?}");
+            assert!(stats.comparisons > 0, "{algorithm:?}");
+        }
+    }
+
+    #[test]
+    fn test_pair_of_observers() {
+        let values = random_values(9, 200, 1_000);
+        let mut arr = DynamicArray::new();
+        arr.extend(values.iter().copied());
+        let mut both = (SortStats::default(), FrameRecorder::new(arr.as_slice()));
+        arr.sort_observed_buffered(BufferedSortAlgorithm::MergeBottomUp, &mut both);
+
+        let (stats, recorder) = both;
+        assert_eq!(recorder.frames().len(), stats.swaps + stats.writes);
+        assert_eq!(recorder.current(), arr.as_slice());
+    }
+
+    #[test]
+    fn test_distribution_sorts_replay() {
+        let values = random_values(10, 500, 100_000);
+        let mut expected = values.clone();
+        expected.sort();
+
+        for radix in [false, true] {
+            let mut arr = DynamicArray::new();
+            arr.extend(values.iter().copied());
+            let mut recorder = FrameRecorder::new(arr.as_slice());
+            if radix {
+                arr.radix_sort_observed(&mut recorder);
+            } else {
+                arr.counting_sort_observed(&mut recorder).unwrap();
+            }
+            assert_eq!(arr.as_slice(), expected);
+            assert_eq!(recorder.current(), expected);
+        }
+
+        let pairs: Vec<(u32, u32)> = values.iter().map(|&x| (x % 10, x)).collect();
+        let mut by_digit = pairs.clone();
+        by_digit.sort_by_key(|&(digit, _)| digit);
+        let mut arr = DynamicArray::new();
+        arr.extend(pairs.iter().copied());
+        let mut recorder = FrameRecorder::new(arr.as_slice());
+        arr.counting_sort_by_key_observed(|&(digit, _)| digit, &mut recorder)
+            .unwrap();
+        assert_eq!(arr.as_slice(), by_digit);
+        assert_eq!(recorder.current(), by_digit);
+
+        let words = ["banana", "band", "ban", "apple", "bandana", "app", "b", ""];
+        let mut words = words.repeat(5);
+        let mut arr = DynamicArray::new();
+        arr.extend(words.iter().copied());
+        let mut recorder = FrameRecorder::new(arr.as_slice());
+        arr.msd_radix_sort_observed(&mut recorder);
+        words.sort();
+        assert_eq!(arr.as_slice(), words);
+        assert_eq!(recorder.current(), words);
+
+        let floats: Vec<f64> = values.iter().map(|&x| x as f64 / 7.0).collect();
+        let mut arr = DynamicArray::new();
+        arr.extend(floats.iter().copied());
+        let mut recorder = FrameRecorder::new(arr.as_slice());
+        arr.bucket_sort_observed(&mut recorder);
+        assert_eq!(recorder.current(), arr.as_slice());
+        assert!(arr.as_slice().windows(2).all(|w| w[0] <= w[1]));
+    }
+}
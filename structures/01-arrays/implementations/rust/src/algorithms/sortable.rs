@@ -0,0 +1,1023 @@
+//! The comparison sorts, behind one trait.
+//!
+//! `Sortable` gives every `SequenceMut`, `Array` and `DynamicArray` among
+//! them, every comparison sort in `algorithms`. Each algorithm lives in its
+//! own module, which documents how it works and what it costs; this module
+//! only wires them up. Sorts that stop short of a full sort are in
+//! `PartialSortable`, and the counting, radix and bucket sorts, which never
+//! compare elements, are in `DistributionSortable`.
+
+use super::bubble_sort::{bubble, bubble_early_exit, cocktail_shaker};
+use super::heap_sort::heap;
+use super::hybrid_sort::{introsort, timsort};
+use super::insertion_sort::{binary_insertion_from, insertion};
+use super::merge_sort::{bottom_up, in_place, top_down};
+use super::quick_sort::{quick, QuickSortConfig};
+use super::selection_sort::selection;
+use super::shell_sort::{shell, GapSequence, ShellSortStats};
+use super::sort_observer::{
+    run, run_buffered, BufferedSortAlgorithm, SortAlgorithm, SortObserver, Sorter,
+};
+use crate::sequence::SequenceMut;
+use std::cmp::Ordering;
+
+/// Trait for arrays that can be sorted in place with a comparison sort.
+///
+/// Every algorithm comes in three forms: by `Ord`, by a comparator, and by
+/// a key, as in the standard library. Not every combination exists; the
+/// sorts with tunable variants take their configuration in `*_with` forms.
+pub trait Sortable<T> {
+    // General-purpose hybrid sorts, see `hybrid_sort`.
+
+    /// Sorts the array in ascending order, keeping equal elements in order.
+    ///
+    /// O(n log n) worst case and O(n) on input that is already sorted or
+    /// reversed. Allocates a scratch buffer for merging, which is why the
+    /// elements must be `Clone`; see `Sortable::in_place_merge_sort`
+    /// for a stable sort without that requirement.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::dynamic_array::DynamicArray;
+    /// use arrays::algorithms::Sortable;
+    ///
+    /// let mut arr = DynamicArray::new();
+    /// arr.extend([5, 1, 4, 2, 8, 0, 2]);
+    /// arr.sort();
+    /// assert_eq!(arr.as_slice(), &[0, 1, 2, 2, 4, 5, 8]);
+    /// ```
+    fn sort(&mut self)
+    where
+        T: Ord + Clone;
+
+    /// Stable sort with a custom comparator.
+    fn sort_by<F>(&mut self, compare: F)
+    where
+        T: Clone,
+        F: FnMut(&T, &T) -> Ordering;
+
+    /// Stable sort by the key `f` extracts.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::core::Array;
+    /// use arrays::algorithms::Sortable;
+    ///
+    /// let people = [("ann", 31), ("bob", 25), ("cat", 31), ("dan", 25)];
+    /// let mut arr = Array::from_slice(&people, 4).unwrap();
+    /// arr.sort_by_key(|&(_, age)| age);
+    /// assert_eq!(
+    ///     arr.as_slice(),
+    ///     &[("bob", 25), ("dan", 25), ("ann", 31), ("cat", 31)]
+    /// );
+    /// ```
+    fn sort_by_key<K, F>(&mut self, f: F)
+    where
+        T: Clone,
+        K: Ord,
+        F: FnMut(&T) -> K;
+
+    /// Sorts the array in ascending order without preserving the order of
+    /// equal elements.
+    ///
+    /// In place, with no allocation and an O(n log n) worst case.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::core::Array;
+    /// use arrays::algorithms::Sortable;
+    ///
+    /// let mut arr = Array::from_slice(&[-5, 4, 1, -3, 2], 5).unwrap();
+    /// arr.sort_unstable();
+    /// assert_eq!(arr.as_slice(), &[-5, -3, 1, 2, 4]);
+    /// ```
+    fn sort_unstable(&mut self)
+    where
+        T: Ord;
+
+    /// Unstable sort with a custom comparator.
+    fn sort_unstable_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering;
+
+    /// Unstable sort by the key `f` extracts.
+    fn sort_unstable_by_key<K, F>(&mut self, f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K;
+
+    // Exchange sorts, see `bubble_sort`.
+
+    /// Sorts the array in ascending order with classic bubble sort.
+    ///
+    /// Always makes n - 1 passes, even over sorted input. The sort is
+    /// stable: equal elements keep their relative order.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::core::Array;
+    /// use arrays::algorithms::Sortable;
+    ///
+    /// let mut arr = Array::from_slice(&[5, 2, 9, 1, 5], 10).unwrap();
+    /// arr.bubble_sort();
+    /// assert_eq!(arr.as_slice(), &[1, 2, 5, 5, 9]);
+    /// ```
+    fn bubble_sort(&mut self)
+    where
+        T: Ord;
+
+    /// Sorts the array with classic bubble sort using a custom comparator.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::dynamic_array::DynamicArray;
+    /// use arrays::algorithms::Sortable;
+    ///
+    /// let mut arr = DynamicArray::new();
+    /// arr.extend([3, 1, 2]);
+    /// arr.bubble_sort_by(|a, b| b.cmp(a));
+    /// assert_eq!(arr.as_slice(), &[3, 2, 1]);
+    /// ```
+    fn bubble_sort_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering;
+
+    /// Sorts the array with bubble sort, stopping as soon as a pass makes
+    /// no swaps.
+    ///
+    /// Each pass also ends at the previous pass's last swap, since
+    /// everything after it is already in place. O(n) on sorted input.
+    fn bubble_sort_early_exit(&mut self)
+    where
+        T: Ord;
+
+    /// Like `bubble_sort_early_exit`, but with a custom comparator.
+    fn bubble_sort_early_exit_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering;
+
+    /// Sorts the array with cocktail shaker sort, a bubble sort that
+    /// alternates forward and backward passes.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::core::Array;
+    /// use arrays::algorithms::Sortable;
+    ///
+    /// let mut arr = Array::from_slice(&[2, 3, 4, 5, 1], 5).unwrap();
+    /// arr.cocktail_shaker_sort();
+    /// assert_eq!(arr.as_slice(), &[1, 2, 3, 4, 5]);
+    /// ```
+    fn cocktail_shaker_sort(&mut self)
+    where
+        T: Ord;
+
+    /// Like `cocktail_shaker_sort`, but with a custom comparator.
+    fn cocktail_shaker_sort_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering;
+
+    // Insertion sorts, see `insertion_sort`.
+
+    /// Sorts the array in ascending order with insertion sort.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::core::Array;
+    /// use arrays::algorithms::Sortable;
+    ///
+    /// let mut arr = Array::from_slice(&[4, 1, 3, 1, 2], 10).unwrap();
+    /// arr.insertion_sort();
+    /// assert_eq!(arr.as_slice(), &[1, 1, 2, 3, 4]);
+    /// ```
+    fn insertion_sort(&mut self)
+    where
+        T: Ord;
+
+    /// Sorts the array with insertion sort using a custom comparator.
+    fn insertion_sort_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering;
+
+    /// Sorts the array with insertion sort by the key `f` extracts.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::dynamic_array::DynamicArray;
+    /// use arrays::algorithms::Sortable;
+    ///
+    /// let mut words = DynamicArray::new();
+    /// words.extend(["kiwi", "fig", "banana"]);
+    /// words.insertion_sort_by_key(|word| word.len());
+    /// assert_eq!(words.as_slice(), &["fig", "kiwi", "banana"]);
+    /// ```
+    fn insertion_sort_by_key<K, F>(&mut self, f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K;
+
+    /// Sorts the array with binary insertion sort.
+    ///
+    /// Each slot is found by a binary search of the sorted prefix for the
+    /// first element greater than the one being inserted, so equal elements
+    /// keep their order and long runs of duplicates cost no extra
+    /// comparisons. The plain, `_by` and `_by_key` forms share that search.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::core::Array;
+    /// use arrays::algorithms::Sortable;
+    ///
+    /// let mut arr = Array::from_slice(&[9, 3, 7, 1], 10).unwrap();
+    /// arr.binary_insertion_sort();
+    /// assert_eq!(arr.as_slice(), &[1, 3, 7, 9]);
+    /// ```
+    fn binary_insertion_sort(&mut self)
+    where
+        T: Ord;
+
+    /// Sorts the array with binary insertion sort using a custom comparator.
+    fn binary_insertion_sort_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering;
+
+    /// Sorts the array with binary insertion sort by the key `f` extracts.
+    fn binary_insertion_sort_by_key<K, F>(&mut self, f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K;
+
+    // Selection sorts, see `selection_sort`.
+
+    /// Sorts the array in ascending order with selection sort.
+    ///
+    /// Not stable: equal elements may change their relative order.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::core::Array;
+    /// use arrays::algorithms::Sortable;
+    ///
+    /// let mut arr = Array::from_slice(&[64, 25, 12, 22, 11], 10).unwrap();
+    /// arr.selection_sort();
+    /// assert_eq!(arr.as_slice(), &[11, 12, 22, 25, 64]);
+    /// ```
+    fn selection_sort(&mut self)
+    where
+        T: Ord;
+
+    /// Sorts the array with selection sort using a custom comparator.
+    fn selection_sort_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering;
+
+    /// Sorts the array with selection sort by the key `f` extracts.
+    fn selection_sort_by_key<K, F>(&mut self, f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K;
+
+    /// Sorts the array with stable selection sort.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::dynamic_array::DynamicArray;
+    /// use arrays::algorithms::Sortable;
+    ///
+    /// let mut cards = DynamicArray::new();
+    /// cards.extend([(5, 'h'), (2, 's'), (5, 's'), (2, 'h')]);
+    /// cards.stable_selection_sort_by_key(|&(rank, _)| rank);
+    /// assert_eq!(cards.as_slice(), &[(2, 's'), (2, 'h'), (5, 'h'), (5, 's')]);
+    /// ```
+    fn stable_selection_sort(&mut self)
+    where
+        T: Ord;
+
+    /// Sorts the array with stable selection sort using a custom comparator.
+    fn stable_selection_sort_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering;
+
+    /// Sorts the array with stable selection sort by the key `f` extracts.
+    fn stable_selection_sort_by_key<K, F>(&mut self, f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K;
+
+    // Quicksort, see `quick_sort`.
+
+    /// Sorts the array in ascending order with quicksort, using the default
+    /// configuration.
+    ///
+    /// Not stable: equal elements may change their relative order.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::core::Array;
+    /// use arrays::algorithms::Sortable;
+    ///
+    /// let mut arr = Array::from_slice(&[3, 7, 1, 9, 4, 1], 10).unwrap();
+    /// arr.quick_sort();
+    /// assert_eq!(arr.as_slice(), &[1, 1, 3, 4, 7, 9]);
+    /// ```
+    fn quick_sort(&mut self)
+    where
+        T: Ord;
+
+    /// Sorts the array with quicksort using a custom comparator.
+    fn quick_sort_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering;
+
+    /// Sorts the array with quicksort by the key `f` extracts.
+    fn quick_sort_by_key<K, F>(&mut self, f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K;
+
+    /// Sorts the array with quicksort using the given configuration.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::dynamic_array::DynamicArray;
+    /// use arrays::algorithms::{PartitionScheme, QuickSortConfig, Sortable};
+    ///
+    /// let mut arr = DynamicArray::new();
+    /// arr.extend([2, 0, 1, 2, 0, 1, 2]);
+    /// let config = QuickSortConfig {
+    ///     partition: PartitionScheme::ThreeWay,
+    ///     ..QuickSortConfig::default()
+    /// };
+    /// arr.quick_sort_with(&config);
+    /// assert_eq!(arr.as_slice(), &[0, 0, 1, 1, 2, 2, 2]);
+    /// ```
+    fn quick_sort_with(&mut self, config: &QuickSortConfig)
+    where
+        T: Ord;
+
+    /// Sorts the array with quicksort using the given configuration and a
+    /// custom comparator.
+    fn quick_sort_with_by<F>(&mut self, config: &QuickSortConfig, compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering;
+
+    // Merge sorts, see `merge_sort`.
+
+    /// Sorts the array in ascending order with top-down merge sort.
+    ///
+    /// Stable; allocates one scratch buffer of half the array's length.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::dynamic_array::DynamicArray;
+    /// use arrays::algorithms::Sortable;
+    ///
+    /// let mut arr = DynamicArray::new();
+    /// arr.extend([38, 27, 43, 3, 9, 82, 10]);
+    /// arr.merge_sort();
+    /// assert_eq!(arr.as_slice(), &[3, 9, 10, 27, 38, 43, 82]);
+    /// ```
+    fn merge_sort(&mut self)
+    where
+        T: Ord + Clone;
+
+    /// Sorts the array with top-down merge sort using a custom comparator.
+    fn merge_sort_by<F>(&mut self, compare: F)
+    where
+        T: Clone,
+        F: FnMut(&T, &T) -> Ordering;
+
+    /// Sorts the array with top-down merge sort by the key `f` extracts.
+    fn merge_sort_by_key<K, F>(&mut self, f: F)
+    where
+        T: Clone,
+        K: Ord,
+        F: FnMut(&T) -> K;
+
+    /// Sorts the array with bottom-up merge sort.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::core::Array;
+    /// use arrays::algorithms::Sortable;
+    ///
+    /// let mut arr = Array::from_slice(&[5, 1, 4, 2, 3], 5).unwrap();
+    /// arr.bottom_up_merge_sort();
+    /// assert_eq!(arr.as_slice(), &[1, 2, 3, 4, 5]);
+    /// ```
+    fn bottom_up_merge_sort(&mut self)
+    where
+        T: Ord + Clone;
+
+    /// Sorts the array with bottom-up merge sort using a custom comparator.
+    fn bottom_up_merge_sort_by<F>(&mut self, compare: F)
+    where
+        T: Clone,
+        F: FnMut(&T, &T) -> Ordering;
+
+    /// Sorts the array with merge sort, merging in place by rotation.
+    ///
+    /// Stable, allocates nothing and does not need `Clone`, but takes
+    /// O(n log² n) time.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::core::Array;
+    /// use arrays::algorithms::Sortable;
+    ///
+    /// let names = [String::from("eve"), String::from("bob"), String::from("amy")];
+    /// let mut arr = Array::from_slice(&names, 3).unwrap();
+    /// arr.in_place_merge_sort();
+    /// assert_eq!(arr.as_slice(), &["amy", "bob", "eve"]);
+    /// ```
+    fn in_place_merge_sort(&mut self)
+    where
+        T: Ord;
+
+    /// Sorts the array with in-place merge sort using a custom comparator.
+    fn in_place_merge_sort_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering;
+
+    // Heap sort, see `heap_sort`.
+
+    /// Sorts the array in ascending order with heap sort.
+    ///
+    /// O(n log n) in the worst case and in place, but not stable.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::core::Array;
+    /// use arrays::algorithms::Sortable;
+    ///
+    /// let mut arr = Array::from_slice(&[12, 11, 13, 5, 6, 7], 6).unwrap();
+    /// arr.heap_sort();
+    /// assert_eq!(arr.as_slice(), &[5, 6, 7, 11, 12, 13]);
+    /// ```
+    fn heap_sort(&mut self)
+    where
+        T: Ord;
+
+    /// Sorts the array with heap sort using a custom comparator.
+    fn heap_sort_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering;
+
+    /// Sorts the array with heap sort by the key `f` extracts.
+    fn heap_sort_by_key<K, F>(&mut self, f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K;
+
+    // Shell sort, see `shell_sort`.
+
+    /// Sorts the array in ascending order with shell sort, using Ciura's
+    /// gap sequence.
+    ///
+    /// Not stable: equal elements may change their relative order.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::core::Array;
+    /// use arrays::algorithms::Sortable;
+    ///
+    /// let mut arr = Array::from_slice(&[23, 29, 15, 19, 31, 7, 9, 5, 2], 10).unwrap();
+    /// arr.shell_sort();
+    /// assert_eq!(arr.as_slice(), &[2, 5, 7, 9, 15, 19, 23, 29, 31]);
+    /// ```
+    fn shell_sort(&mut self)
+    where
+        T: Ord;
+
+    /// Sorts the array with shell sort using a custom comparator.
+    fn shell_sort_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering;
+
+    /// Sorts the array with shell sort by the key `f` extracts.
+    fn shell_sort_by_key<K, F>(&mut self, f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K;
+
+    /// Sorts the array with shell sort using the given gap sequence, and
+    /// reports the work done by each pass.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::dynamic_array::DynamicArray;
+    /// use arrays::algorithms::{GapSequence, Sortable};
+    ///
+    /// let mut arr = DynamicArray::new();
+    /// arr.extend((0..100).rev());
+    /// let stats = arr.shell_sort_with(GapSequence::Knuth);
+    ///
+    /// let gaps: Vec<usize> = stats.passes.iter().map(|pass| pass.gap).collect();
+    /// assert_eq!(gaps, vec![13, 4, 1]);
+    /// assert!(stats.comparisons() < 100 * 99 / 2);
+    /// ```
+    fn shell_sort_with(&mut self, gaps: GapSequence) -> ShellSortStats
+    where
+        T: Ord;
+
+    /// Like `shell_sort_with`, but with a custom comparator.
+    fn shell_sort_with_by<F>(&mut self, gaps: GapSequence, compare: F) -> ShellSortStats
+    where
+        F: FnMut(&T, &T) -> Ordering;
+
+    // Instrumented sorting, see `sort_observer`.
+
+    /// Sorts the array in ascending order with `algorithm`, reporting every
+    /// operation to `observer`.
+    fn sort_observed<O>(&mut self, algorithm: SortAlgorithm, observer: &mut O)
+    where
+        T: Ord,
+        O: SortObserver<T> + ?Sized;
+
+    /// Sorts the array with `algorithm` and a custom comparator, reporting
+    /// every operation to `observer`.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::dynamic_array::DynamicArray;
+    /// use arrays::algorithms::{GapSequence, SortAlgorithm, SortStats, Sortable};
+    ///
+    /// let mut arr = DynamicArray::new();
+    /// arr.extend([4, 9, 1, 7]);
+    /// let mut stats = SortStats::default();
+    /// arr.sort_observed_by(SortAlgorithm::Shell(GapSequence::Knuth), |a, b| b.cmp(a), &mut stats);
+    /// assert_eq!(arr.as_slice(), &[9, 7, 4, 1]);
+    /// assert!(stats.comparisons > 0);
+    /// ```
+    fn sort_observed_by<F, O>(&mut self, algorithm: SortAlgorithm, compare: F, observer: &mut O)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+        O: SortObserver<T> + ?Sized;
+
+    /// Like `sort_observed`, for the sorts that merge through a scratch
+    /// buffer.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::dynamic_array::DynamicArray;
+    /// use arrays::algorithms::{BufferedSortAlgorithm, SortStats, Sortable};
+    ///
+    /// let mut arr = DynamicArray::new();
+    /// arr.extend((0..64).rev());
+    /// let mut stats = SortStats::default();
+    /// arr.sort_observed_buffered(BufferedSortAlgorithm::Timsort, &mut stats);
+    ///
+    /// // Timsort finds a single descending run and reverses it
+    /// assert_eq!(stats.comparisons, 63);
+    /// assert_eq!(arr.as_slice()[0], 0);
+    /// ```
+    fn sort_observed_buffered<O>(&mut self, algorithm: BufferedSortAlgorithm, observer: &mut O)
+    where
+        T: Ord + Clone,
+        O: SortObserver<T> + ?Sized;
+
+    /// Like `sort_observed_by`, for the sorts that merge through a scratch
+    /// buffer.
+    fn sort_observed_buffered_by<F, O>(
+        &mut self,
+        algorithm: BufferedSortAlgorithm,
+        compare: F,
+        observer: &mut O,
+    ) where
+        T: Clone,
+        F: FnMut(&T, &T) -> Ordering,
+        O: SortObserver<T> + ?Sized;
+}
+
+// Every `SequenceMut` gets the comparison sorts through this blanket implementation
+impl<T, S> Sortable<T> for S
+where
+    S: SequenceMut<T> + ?Sized,
+{
+    fn sort(&mut self)
+    where
+        T: Ord + Clone,
+    {
+        timsort(self.as_mut_slice(), &mut Sorter::new(T::cmp));
+    }
+
+    fn sort_by<F>(&mut self, compare: F)
+    where
+        T: Clone,
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        timsort(self.as_mut_slice(), &mut Sorter::new(compare));
+    }
+
+    fn sort_by_key<K, F>(&mut self, mut f: F)
+    where
+        T: Clone,
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        timsort(
+            self.as_mut_slice(),
+            &mut Sorter::new(|a: &T, b: &T| f(a).cmp(&f(b))),
+        );
+    }
+
+    fn sort_unstable(&mut self)
+    where
+        T: Ord,
+    {
+        introsort(self.as_mut_slice(), &mut Sorter::new(T::cmp));
+    }
+
+    fn sort_unstable_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        introsort(self.as_mut_slice(), &mut Sorter::new(compare));
+    }
+
+    fn sort_unstable_by_key<K, F>(&mut self, mut f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        introsort(
+            self.as_mut_slice(),
+            &mut Sorter::new(|a: &T, b: &T| f(a).cmp(&f(b))),
+        );
+    }
+
+    fn bubble_sort(&mut self)
+    where
+        T: Ord,
+    {
+        bubble(self.as_mut_slice(), &mut Sorter::new(T::cmp));
+    }
+
+    fn bubble_sort_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        bubble(self.as_mut_slice(), &mut Sorter::new(compare));
+    }
+
+    fn bubble_sort_early_exit(&mut self)
+    where
+        T: Ord,
+    {
+        bubble_early_exit(self.as_mut_slice(), &mut Sorter::new(T::cmp));
+    }
+
+    fn bubble_sort_early_exit_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        bubble_early_exit(self.as_mut_slice(), &mut Sorter::new(compare));
+    }
+
+    fn cocktail_shaker_sort(&mut self)
+    where
+        T: Ord,
+    {
+        cocktail_shaker(self.as_mut_slice(), &mut Sorter::new(T::cmp));
+    }
+
+    fn cocktail_shaker_sort_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        cocktail_shaker(self.as_mut_slice(), &mut Sorter::new(compare));
+    }
+
+    fn insertion_sort(&mut self)
+    where
+        T: Ord,
+    {
+        insertion(self.as_mut_slice(), &mut Sorter::new(T::cmp));
+    }
+
+    fn insertion_sort_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        insertion(self.as_mut_slice(), &mut Sorter::new(compare));
+    }
+
+    fn insertion_sort_by_key<K, F>(&mut self, mut f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        insertion(
+            self.as_mut_slice(),
+            &mut Sorter::new(|a: &T, b: &T| f(a).cmp(&f(b))),
+        );
+    }
+
+    fn binary_insertion_sort(&mut self)
+    where
+        T: Ord,
+    {
+        binary_insertion_from(self.as_mut_slice(), 1, &mut Sorter::new(T::cmp));
+    }
+
+    fn binary_insertion_sort_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        binary_insertion_from(self.as_mut_slice(), 1, &mut Sorter::new(compare));
+    }
+
+    fn binary_insertion_sort_by_key<K, F>(&mut self, mut f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        binary_insertion_from(
+            self.as_mut_slice(),
+            1,
+            &mut Sorter::new(|a: &T, b: &T| f(a).cmp(&f(b))),
+        );
+    }
+
+    fn selection_sort(&mut self)
+    where
+        T: Ord,
+    {
+        selection(self.as_mut_slice(), false, &mut Sorter::new(T::cmp));
+    }
+
+    fn selection_sort_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        selection(self.as_mut_slice(), false, &mut Sorter::new(compare));
+    }
+
+    fn selection_sort_by_key<K, F>(&mut self, mut f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        selection(
+            self.as_mut_slice(),
+            false,
+            &mut Sorter::new(|a: &T, b: &T| f(a).cmp(&f(b))),
+        );
+    }
+
+    fn stable_selection_sort(&mut self)
+    where
+        T: Ord,
+    {
+        selection(self.as_mut_slice(), true, &mut Sorter::new(T::cmp));
+    }
+
+    fn stable_selection_sort_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        selection(self.as_mut_slice(), true, &mut Sorter::new(compare));
+    }
+
+    fn stable_selection_sort_by_key<K, F>(&mut self, mut f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        selection(
+            self.as_mut_slice(),
+            true,
+            &mut Sorter::new(|a: &T, b: &T| f(a).cmp(&f(b))),
+        );
+    }
+
+    fn quick_sort(&mut self)
+    where
+        T: Ord,
+    {
+        quick(
+            self.as_mut_slice(),
+            &QuickSortConfig::default(),
+            &mut Sorter::new(T::cmp),
+        );
+    }
+
+    fn quick_sort_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        quick(
+            self.as_mut_slice(),
+            &QuickSortConfig::default(),
+            &mut Sorter::new(compare),
+        );
+    }
+
+    fn quick_sort_by_key<K, F>(&mut self, mut f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        quick(
+            self.as_mut_slice(),
+            &QuickSortConfig::default(),
+            &mut Sorter::new(|a: &T, b: &T| f(a).cmp(&f(b))),
+        );
+    }
+
+    fn quick_sort_with(&mut self, config: &QuickSortConfig)
+    where
+        T: Ord,
+    {
+        quick(self.as_mut_slice(), config, &mut Sorter::new(T::cmp));
+    }
+
+    fn quick_sort_with_by<F>(&mut self, config: &QuickSortConfig, compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        quick(self.as_mut_slice(), config, &mut Sorter::new(compare));
+    }
+
+    fn merge_sort(&mut self)
+    where
+        T: Ord + Clone,
+    {
+        top_down(self.as_mut_slice(), &mut Sorter::new(T::cmp));
+    }
+
+    fn merge_sort_by<F>(&mut self, compare: F)
+    where
+        T: Clone,
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        top_down(self.as_mut_slice(), &mut Sorter::new(compare));
+    }
+
+    fn merge_sort_by_key<K, F>(&mut self, mut f: F)
+    where
+        T: Clone,
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        top_down(
+            self.as_mut_slice(),
+            &mut Sorter::new(|a: &T, b: &T| f(a).cmp(&f(b))),
+        );
+    }
+
+    fn bottom_up_merge_sort(&mut self)
+    where
+        T: Ord + Clone,
+    {
+        bottom_up(self.as_mut_slice(), &mut Sorter::new(T::cmp));
+    }
+
+    fn bottom_up_merge_sort_by<F>(&mut self, compare: F)
+    where
+        T: Clone,
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        bottom_up(self.as_mut_slice(), &mut Sorter::new(compare));
+    }
+
+    fn in_place_merge_sort(&mut self)
+    where
+        T: Ord,
+    {
+        in_place(self.as_mut_slice(), &mut Sorter::new(T::cmp));
+    }
+
+    fn in_place_merge_sort_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        in_place(self.as_mut_slice(), &mut Sorter::new(compare));
+    }
+
+    fn heap_sort(&mut self)
+    where
+        T: Ord,
+    {
+        heap(self.as_mut_slice(), &mut Sorter::new(T::cmp));
+    }
+
+    fn heap_sort_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        heap(self.as_mut_slice(), &mut Sorter::new(compare));
+    }
+
+    fn heap_sort_by_key<K, F>(&mut self, mut f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        heap(
+            self.as_mut_slice(),
+            &mut Sorter::new(|a: &T, b: &T| f(a).cmp(&f(b))),
+        );
+    }
+
+    fn shell_sort(&mut self)
+    where
+        T: Ord,
+    {
+        shell(
+            self.as_mut_slice(),
+            GapSequence::default(),
+            &mut Sorter::new(T::cmp),
+        );
+    }
+
+    fn shell_sort_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        shell(
+            self.as_mut_slice(),
+            GapSequence::default(),
+            &mut Sorter::new(compare),
+        );
+    }
+
+    fn shell_sort_by_key<K, F>(&mut self, mut f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        shell(
+            self.as_mut_slice(),
+            GapSequence::default(),
+            &mut Sorter::new(|a: &T, b: &T| f(a).cmp(&f(b))),
+        );
+    }
+
+    fn shell_sort_with(&mut self, gaps: GapSequence) -> ShellSortStats
+    where
+        T: Ord,
+    {
+        shell(self.as_mut_slice(), gaps, &mut Sorter::new(T::cmp))
+    }
+
+    fn shell_sort_with_by<F>(&mut self, gaps: GapSequence, compare: F) -> ShellSortStats
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        shell(self.as_mut_slice(), gaps, &mut Sorter::new(compare))
+    }
+
+    fn sort_observed<O>(&mut self, algorithm: SortAlgorithm, observer: &mut O)
+    where
+        T: Ord,
+        O: SortObserver<T> + ?Sized,
+    {
+        run(
+            self.as_mut_slice(),
+            algorithm,
+            &mut Sorter::observed(T::cmp, observer),
+        );
+    }
+
+    fn sort_observed_by<F, O>(&mut self, algorithm: SortAlgorithm, compare: F, observer: &mut O)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+        O: SortObserver<T> + ?Sized,
+    {
+        run(
+            self.as_mut_slice(),
+            algorithm,
+            &mut Sorter::observed(compare, observer),
+        );
+    }
+
+    fn sort_observed_buffered<O>(&mut self, algorithm: BufferedSortAlgorithm, observer: &mut O)
+    where
+        T: Ord + Clone,
+        O: SortObserver<T> + ?Sized,
+    {
+        run_buffered(
+            self.as_mut_slice(),
+            algorithm,
+            &mut Sorter::observed(T::cmp, observer),
+        );
+    }
+
+    fn sort_observed_buffered_by<F, O>(
+        &mut self,
+        algorithm: BufferedSortAlgorithm,
+        compare: F,
+        observer: &mut O,
+    ) where
+        T: Clone,
+        F: FnMut(&T, &T) -> Ordering,
+        O: SortObserver<T> + ?Sized,
+    {
+        run_buffered(
+            self.as_mut_slice(),
+            algorithm,
+            &mut Sorter::observed(compare, observer),
+        );
+    }
+}
@@ -0,0 +1,49 @@
+//! Helpers shared by the algorithm tests.
+
+use super::quick_sort::xorshift64;
+use crate::dynamic_array::DynamicArray;
+
+/// `len` pseudo-random values below `max`, the same for every run with the
+/// same nonzero `seed` so the differential tests are reproducible.
+pub(crate) fn random_values(seed: u64, len: usize, max: u32) -> Vec<u32> {
+    let mut state = seed;
+    (0..len)
+        .map(|_| (xorshift64(&mut state) % u64::from(max)) as u32)
+        .collect()
+}
+
+/// `len` pseudo-random `u64`s spanning the whole range, reproducible like
+/// `random_values`.
+pub(crate) fn random_u64s(seed: u64, len: usize) -> Vec<u64> {
+    let mut state = seed;
+    (0..len).map(|_| xorshift64(&mut state)).collect()
+}
+
+/// The inputs of a differential test: for each seed in `1..=rounds`, a
+/// random array of between 1 and `max_len` values. Even seeds draw from a
+/// handful of values, which forces plenty of duplicates, odd seeds from a
+/// wide range.
+pub(crate) fn random_inputs(rounds: u64, max_len: usize) -> impl Iterator<Item = (u64, Vec<u32>)> {
+    (1..=rounds).map(move |seed| {
+        let len = (seed as usize * 37) % max_len + 1;
+        let max = if seed % 2 == 0 { 8 } else { 1_000_000 };
+        (seed, random_values(seed, len, max))
+    })
+}
+
+/// Checks that `sort` leaves every array from `random_inputs(rounds,
+/// max_len)` in the order the standard library's sort gives.
+pub(crate) fn assert_sorts_like_std<F>(name: &str, rounds: u64, max_len: usize, mut sort: F)
+where
+    F: FnMut(&mut DynamicArray<u32>),
+{
+    for (seed, values) in random_inputs(rounds, max_len) {
+        let mut expected = values.clone();
+        expected.sort();
+
+        let mut arr = DynamicArray::new();
+        arr.extend(values);
+        sort(&mut arr);
+        assert_eq!(arr.as_slice(), expected.as_slice(), "{name}, seed {seed}");
+    }
+}
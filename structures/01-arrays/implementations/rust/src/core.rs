@@ -116,6 +116,22 @@ impl<T> Array<T> {
         unsafe { Some(&mut *self.ptr.add(index)) }
     }
 
+    /// Returns the initialized elements as a slice.
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { std::slice::from_raw_parts(self.ptr, self.len) }
+    }
+
+    /// Returns the initialized elements as a mutable slice.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe { std::slice::from_raw_parts_mut(self.ptr, self.len) }
+    }
+
+    /// Returns a raw pointer to the start of the buffer, including the
+    /// uninitialized slots between `len` and `capacity`.
+    pub(crate) fn as_mut_ptr(&mut self) -> *mut T {
+        self.ptr
+    }
+
     /// Sets the value at the given index.
     ///
     /// # Panics
@@ -80,6 +80,19 @@ impl<T> DynamicArray<T> {
         self.len += 1;
     }
 
+    /// Ensures room for at least `additional` more elements without
+    /// further reallocation.
+    pub fn reserve(&mut self, additional: usize) {
+        let required = self
+            .len
+            .checked_add(additional)
+            .expect("Cannot grow array beyond maximum capacity");
+
+        while self.capacity < required {
+            self.grow();
+        }
+    }
+
     fn grow(&mut self) {
         let new_capacity = if self.capacity == 0 {
             1
@@ -146,6 +159,30 @@ impl<T> DynamicArray<T> {
         while self.pop().is_some() {}
     }
 
+    /// Returns the initialized elements as a slice.
+    pub fn as_slice(&self) -> &[T] {
+        if self.ptr.is_null() {
+            return &[];
+        }
+
+        unsafe { std::slice::from_raw_parts(self.ptr, self.len) }
+    }
+
+    /// Returns the initialized elements as a mutable slice.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        if self.ptr.is_null() {
+            return &mut [];
+        }
+
+        unsafe { std::slice::from_raw_parts_mut(self.ptr, self.len) }
+    }
+
+    /// Returns a raw pointer to the start of the buffer, including the
+    /// uninitialized slots between `len` and `capacity`.
+    pub(crate) fn as_mut_ptr(&mut self) -> *mut T {
+        self.ptr
+    }
+
     /// Shrinks the capacity to reduce memory usage.
     ///
     /// Uses smart shrinking to prevent thrashing. Only shrinks when
@@ -414,6 +451,37 @@ mod tests {
         assert_eq!(arr.capacity(), shrunk_capacity);
     }
 
+    #[test]
+    fn test_reserve() {
+        let mut arr = DynamicArray::new();
+        arr.push(1);
+
+        arr.reserve(5);
+        assert!(arr.capacity() >= 6);
+        let reserved = arr.capacity();
+
+        for i in 2..=6 {
+            arr.push(i);
+        }
+        assert_eq!(arr.capacity(), reserved);
+
+        // Enough room already, nothing changes
+        arr.reserve(0);
+        assert_eq!(arr.capacity(), reserved);
+    }
+
+    #[test]
+    fn test_as_mut_slice() {
+        let mut empty: DynamicArray<i32> = DynamicArray::new();
+        assert!(empty.as_mut_slice().is_empty());
+
+        let mut arr = DynamicArray::new();
+        arr.extend([3, 1, 2]);
+        arr.as_mut_slice().swap(0, 2);
+        arr.as_mut_slice()[1] = 5;
+        assert_eq!(arr.as_slice(), &[2, 5, 3]);
+    }
+
     #[test]
     fn test_iterator() {
         let mut arr = DynamicArray::new();
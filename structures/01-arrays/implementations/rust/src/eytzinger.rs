@@ -0,0 +1,343 @@
+//! Sorted array stored in Eytzinger (BFS) layout.
+//!
+//! Binary search over a plain sorted array jumps across memory: the first few
+//! probes are far apart and each one is likely a cache miss. The Eytzinger
+//! layout stores the implicit search tree level by level, so the root sits at
+//! index 1, its children at 2 and 3, their children at 4..=7, and so on. The
+//! top of the tree shares a handful of cache lines, and the children of any
+//! node are adjacent, which lets the search prefetch several levels ahead.
+//!
+//! For the cache background, see
+//! `structures/00-fundamentals/diagrams/cache-hierarchy-effects.png`.
+
+use crate::dynamic_array::DynamicArray;
+use crate::sequence::Sequence;
+use std::mem::size_of;
+
+/// Bytes in a cache line on mainstream hardware.
+const CACHE_LINE: usize = 64;
+
+/// A read-only sorted collection laid out in Eytzinger order.
+///
+/// Positions returned by `search` and `lower_bound` are indices into this
+/// layout; use `get` to read the element or `rank` for its sorted position.
+pub struct EytzingerArray<T> {
+    // Node k of the 1-based tree lives at `data[k - 1]`
+    data: DynamicArray<T>,
+}
+
+impl<T> EytzingerArray<T> {
+    /// Builds the layout from a sequence sorted in ascending order.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrays::core::Array;
+    /// use arrays::eytzinger::EytzingerArray;
+    ///
+    /// let sorted = Array::from_slice(&[1, 3, 5, 7, 9, 11], 10).unwrap();
+    /// let tree = EytzingerArray::from_sorted(&sorted);
+    /// let pos = tree.search(&7).unwrap();
+    /// assert_eq!(tree.get(pos), Some(&7));
+    /// assert_eq!(tree.rank(pos), 3);
+    /// ```
+    pub fn from_sorted<S>(sorted: &S) -> Self
+    where
+        T: Clone,
+        S: Sequence<T> + ?Sized,
+    {
+        let n = sorted.len();
+        let mut order = vec![0; n];
+        let mut next = 0;
+        fill_in_order(&mut order, &mut next, 1);
+
+        let mut data = DynamicArray::with_capacity(n);
+        for &sorted_index in &order {
+            let elem = sorted.get(sorted_index).expect("index within sorted input");
+            data.push(elem.clone());
+        }
+
+        Self { data }
+    }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns true if there are no elements.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Returns the element at `position` in Eytzinger order.
+    pub fn get(&self, position: usize) -> Option<&T> {
+        self.data.get(position)
+    }
+
+    /// Returns the elements in Eytzinger (BFS) order.
+    pub fn as_slice(&self) -> &[T] {
+        self.data.as_slice()
+    }
+
+    /// Finds the position of the first element not less than `target`.
+    ///
+    /// The descent has no data-dependent branches: each level turns the
+    /// comparison result into the child index. Returns `None` if every
+    /// element is less than `target`.
+    pub fn lower_bound(&self, target: &T) -> Option<usize>
+    where
+        T: Ord,
+    {
+        let data = self.data.as_slice();
+        let n = data.len();
+        let mut k = 1;
+
+        while k <= n {
+            prefetch(data, k * prefetch_stride::<T>());
+            k = 2 * k + usize::from(data[k - 1] < *target);
+        }
+
+        // Every right turn after the last left turn is undone: strip the
+        // trailing ones, then the left turn itself.
+        k >>= k.trailing_ones() + 1;
+
+        if k == 0 {
+            None
+        } else {
+            Some(k - 1)
+        }
+    }
+
+    /// Searches for `target` and returns its position in Eytzinger order.
+    ///
+    /// With duplicates, the position of the leftmost (in sorted order)
+    /// occurrence is returned.
+    pub fn search(&self, target: &T) -> Option<usize>
+    where
+        T: Ord,
+    {
+        let position = self.lower_bound(target)?;
+        if self.data.as_slice()[position] == *target {
+            Some(position)
+        } else {
+            None
+        }
+    }
+
+    /// Returns true if `target` is present.
+    pub fn contains(&self, target: &T) -> bool
+    where
+        T: Ord,
+    {
+        self.search(target).is_some()
+    }
+
+    /// Converts an Eytzinger position to the element's index in sorted order.
+    ///
+    /// Runs in O(log n) by counting the nodes that precede `position` in an
+    /// in-order walk of the implicit tree.
+    ///
+    /// # Panics
+    /// Panics if `position` is out of bounds.
+    pub fn rank(&self, position: usize) -> usize {
+        let n = self.len();
+        assert!(
+            position < n,
+            "Position {} out of bounds for length {}",
+            position,
+            n
+        );
+
+        let mut k = position + 1;
+        let mut rank = subtree_size(2 * k, n);
+        while k > 1 {
+            if k % 2 == 1 {
+                // Right child: the parent and its left subtree come first
+                rank += 1 + subtree_size(k - 1, n);
+            }
+            k /= 2;
+        }
+        rank
+    }
+
+    /// Returns the elements in ascending order.
+    pub fn to_sorted(&self) -> DynamicArray<T>
+    where
+        T: Clone,
+    {
+        let n = self.len();
+        let mut order = vec![0; n];
+        let mut next = 0;
+        fill_in_order(&mut order, &mut next, 1);
+
+        // order[slot] is the sorted index of slot; invert it
+        let mut slots = vec![0; n];
+        for (slot, &sorted_index) in order.iter().enumerate() {
+            slots[sorted_index] = slot;
+        }
+
+        let data = self.data.as_slice();
+        let mut sorted = DynamicArray::with_capacity(n);
+        for slot in slots {
+            sorted.push(data[slot].clone());
+        }
+        sorted
+    }
+}
+
+impl<T: std::fmt::Debug> std::fmt::Debug for EytzingerArray<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "EytzingerArray({:?})", self.data)
+    }
+}
+
+// Assigns sorted indices to tree nodes with an in-order walk, so that
+// `order[k - 1]` is the sorted index stored at node k.
+fn fill_in_order(order: &mut [usize], next: &mut usize, k: usize) {
+    if k > order.len() {
+        return;
+    }
+
+    fill_in_order(order, next, 2 * k);
+    order[k - 1] = *next;
+    *next += 1;
+    fill_in_order(order, next, 2 * k + 1);
+}
+
+// Number of nodes in the subtree rooted at node k of a tree with n nodes.
+fn subtree_size(k: usize, n: usize) -> usize {
+    let mut size = 0;
+    let mut first = k;
+    let mut width = 1;
+
+    while first <= n {
+        let last = first + width - 1;
+        size += last.min(n) - first + 1;
+        first *= 2;
+        width *= 2;
+    }
+    size
+}
+
+// Node k's descendants d levels down start at k * 2^d. Prefetching the
+// block whose width is one cache line loads all of them in one go.
+fn prefetch_stride<T>() -> usize {
+    (CACHE_LINE / size_of::<T>().max(1)).max(1)
+}
+
+#[cfg(target_arch = "x86_64")]
+fn prefetch<T>(data: &[T], k: usize) {
+    use std::arch::x86_64::{_mm_prefetch, _MM_HINT_T0};
+
+    // The address may lie past the end of `data`, so it is computed with
+    // wrapping arithmetic rather than `add`, which requires staying in bounds
+    let addr = data.as_ptr().wrapping_add(k.wrapping_sub(1));
+    // SAFETY: `_mm_prefetch` never dereferences `addr`. A prefetch is a hint
+    // that cannot fault, so any address is fine, even an unmapped one or
+    // one that wrapped around.
+    unsafe { _mm_prefetch::<_MM_HINT_T0>(addr as *const i8) };
+}
+
+#[cfg(not(target_arch = "x86_64"))]
+fn prefetch<T>(_data: &[T], _k: usize) {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::BinarySearchable;
+    use crate::core::Array;
+
+    fn sorted_dynamic(n: usize) -> DynamicArray<i32> {
+        let mut arr = DynamicArray::with_capacity(n);
+        for i in 0..n as i32 {
+            arr.push(i * 2);
+        }
+        arr
+    }
+
+    #[test]
+    fn test_bfs_layout() {
+        let sorted = Array::from_slice(&[1, 2, 3, 4, 5, 6, 7], 7).unwrap();
+        let tree = EytzingerArray::from_sorted(&sorted);
+
+        assert_eq!(tree.as_slice(), &[4, 2, 6, 1, 3, 5, 7]);
+    }
+
+    #[test]
+    fn test_search_matches_binary_search() {
+        for n in [0, 1, 2, 3, 7, 8, 100, 1000] {
+            let sorted = sorted_dynamic(n);
+            let tree = EytzingerArray::from_sorted(&sorted);
+
+            for target in -1..=(2 * n as i32 + 1) {
+                let expected = sorted.binary_search(&target);
+                let found = tree.search(&target);
+                assert_eq!(found.is_some(), expected.is_some());
+
+                if let Some(position) = found {
+                    assert_eq!(tree.get(position), Some(&target));
+                    assert_eq!(Some(tree.rank(position)), expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_lower_bound() {
+        let sorted = Array::from_slice(&[10, 20, 30, 40, 50], 5).unwrap();
+        let tree = EytzingerArray::from_sorted(&sorted);
+
+        let value = |target| tree.lower_bound(&target).map(|p| tree.as_slice()[p]);
+        assert_eq!(value(5), Some(10));
+        assert_eq!(value(10), Some(10));
+        assert_eq!(value(25), Some(30));
+        assert_eq!(value(50), Some(50));
+        assert_eq!(value(51), None);
+    }
+
+    #[test]
+    fn test_duplicates_return_leftmost() {
+        let sorted = Array::from_slice(&[1, 2, 2, 2, 3, 4], 6).unwrap();
+        let tree = EytzingerArray::from_sorted(&sorted);
+
+        let position = tree.search(&2).unwrap();
+        assert_eq!(tree.rank(position), 1);
+        assert!(!tree.contains(&5));
+    }
+
+    #[test]
+    fn test_round_trip_to_sorted() {
+        let sorted = sorted_dynamic(37);
+        let tree = EytzingerArray::from_sorted(&sorted);
+
+        assert_eq!(tree.len(), 37);
+        assert_eq!(tree.to_sorted().as_slice(), sorted.as_slice());
+    }
+
+    #[test]
+    fn test_empty() {
+        let sorted: DynamicArray<i32> = DynamicArray::new();
+        let tree = EytzingerArray::from_sorted(&sorted);
+
+        assert!(tree.is_empty());
+        assert_eq!(tree.search(&1), None);
+        assert_eq!(tree.lower_bound(&1), None);
+        assert!(tree.to_sorted().is_empty());
+    }
+
+    #[test]
+    fn test_strings() {
+        let words = ["apple", "banana", "cherry", "date"].map(String::from);
+        let tree = EytzingerArray::from_sorted(&words[..]);
+
+        let position = tree.search(&"cherry".to_string()).unwrap();
+        assert_eq!(tree.rank(position), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Position 3 out of bounds for length 3")]
+    fn test_rank_out_of_bounds() {
+        let tree = EytzingerArray::from_sorted(&[1, 2, 3][..]);
+        tree.rank(3);
+    }
+}
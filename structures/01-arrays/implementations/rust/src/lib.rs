@@ -2,4 +2,6 @@
 pub mod algorithms;
 pub mod core;
 pub mod dynamic_array;
+pub mod eytzinger;
 pub mod problems;
+pub mod sequence;
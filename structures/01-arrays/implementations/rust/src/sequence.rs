@@ -0,0 +1,200 @@
+//! Indexed sequences.
+//!
+//! The search algorithms only need two things from a container: its length
+//! and random access by index. `Sequence<T>` captures exactly that, so any
+//! type implementing it gets every search trait in `algorithms` for free.
+//! The sorts and selections rearrange elements in place, so they need a
+//! contiguous mutable slice on top of that, which `SequenceMut<T>` provides.
+
+use crate::core::Array;
+use crate::dynamic_array::DynamicArray;
+use std::collections::VecDeque;
+
+/// A container with a length and random access to its elements.
+pub trait Sequence<T> {
+    /// Returns the number of elements in the sequence.
+    fn len(&self) -> usize;
+
+    /// Returns a reference to the element at `index`, or `None` if out of bounds.
+    fn get(&self, index: usize) -> Option<&T>;
+
+    /// Returns true if the sequence contains no elements.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the elements as a contiguous slice, if the storage allows it.
+    ///
+    /// Non-contiguous containers such as `VecDeque` return `None`.
+    fn as_slice(&self) -> Option<&[T]> {
+        None
+    }
+}
+
+/// A `Sequence` whose elements can be rearranged in place.
+///
+/// Not implemented for `Vec`, whose slice methods such as `sort` the sort
+/// traits would otherwise shadow.
+pub trait SequenceMut<T>: Sequence<T> {
+    /// Returns the elements as a mutable contiguous slice.
+    fn as_mut_slice(&mut self) -> &mut [T];
+}
+
+impl<T> Sequence<T> for Array<T> {
+    fn len(&self) -> usize {
+        Array::len(self)
+    }
+
+    fn get(&self, index: usize) -> Option<&T> {
+        Array::get(self, index)
+    }
+
+    fn as_slice(&self) -> Option<&[T]> {
+        Some(Array::as_slice(self))
+    }
+}
+
+impl<T> Sequence<T> for DynamicArray<T> {
+    fn len(&self) -> usize {
+        DynamicArray::len(self)
+    }
+
+    fn get(&self, index: usize) -> Option<&T> {
+        DynamicArray::get(self, index)
+    }
+
+    fn as_slice(&self) -> Option<&[T]> {
+        Some(DynamicArray::as_slice(self))
+    }
+}
+
+impl<T> Sequence<T> for [T] {
+    fn len(&self) -> usize {
+        <[T]>::len(self)
+    }
+
+    fn get(&self, index: usize) -> Option<&T> {
+        <[T]>::get(self, index)
+    }
+
+    fn as_slice(&self) -> Option<&[T]> {
+        Some(self)
+    }
+}
+
+impl<T> Sequence<T> for Vec<T> {
+    fn len(&self) -> usize {
+        Vec::len(self)
+    }
+
+    fn get(&self, index: usize) -> Option<&T> {
+        <[T]>::get(self, index)
+    }
+
+    fn as_slice(&self) -> Option<&[T]> {
+        Some(Vec::as_slice(self))
+    }
+}
+
+impl<T> SequenceMut<T> for Array<T> {
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        Array::as_mut_slice(self)
+    }
+}
+
+impl<T> SequenceMut<T> for DynamicArray<T> {
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        DynamicArray::as_mut_slice(self)
+    }
+}
+
+impl<T> SequenceMut<T> for [T] {
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+impl<T> Sequence<T> for VecDeque<T> {
+    fn len(&self) -> usize {
+        VecDeque::len(self)
+    }
+
+    fn get(&self, index: usize) -> Option<&T> {
+        VecDeque::get(self, index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::{BinarySearchable, JumpSearchable, LinearSearchable};
+
+    #[test]
+    fn test_array_sequence() {
+        let arr = Array::from_slice(&[1, 2, 3], 5).unwrap();
+
+        assert_eq!(Sequence::len(&arr), 3);
+        assert_eq!(Sequence::get(&arr, 1), Some(&2));
+        assert_eq!(Sequence::get(&arr, 3), None);
+        assert_eq!(Sequence::as_slice(&arr), Some(&[1, 2, 3][..]));
+    }
+
+    #[test]
+    fn test_empty_dynamic_array_slice() {
+        let arr: DynamicArray<i32> = DynamicArray::new();
+
+        assert!(Sequence::is_empty(&arr));
+        assert_eq!(Sequence::as_slice(&arr), Some(&[][..]));
+    }
+
+    #[test]
+    fn test_vec_deque_has_no_slice() {
+        let mut deque = VecDeque::new();
+        deque.push_back(2);
+        deque.push_front(1);
+
+        assert_eq!(Sequence::len(&deque), 2);
+        assert_eq!(Sequence::get(&deque, 0), Some(&1));
+        assert!(Sequence::as_slice(&deque).is_none());
+    }
+
+    #[test]
+    fn test_searches_on_std_containers() {
+        let values = [1, 3, 5, 7, 9, 11];
+        let vec = values.to_vec();
+        let deque: VecDeque<i32> = values.iter().copied().collect();
+
+        assert_eq!(LinearSearchable::linear_search(&values[..], &7), Some(3));
+        assert_eq!(BinarySearchable::binary_search(&vec, &9), Some(4));
+        assert_eq!(BinarySearchable::binary_search(&deque, &4), None);
+        assert_eq!(deque.jump_search(&11), Some(5));
+        assert_eq!(deque.linear_search_all(&1), vec![0]);
+    }
+
+    #[test]
+    fn test_sorts_on_slices() {
+        use crate::algorithms::Sortable;
+
+        let mut values = [5, 1, 4, 2, 3];
+        Sortable::bubble_sort(&mut values[..]);
+        assert_eq!(values, [1, 2, 3, 4, 5]);
+
+        let mut arr = Array::from_slice(&[3, 1, 2], 3).unwrap();
+        SequenceMut::as_mut_slice(&mut arr).reverse();
+        assert_eq!(arr.as_slice(), &[2, 1, 3]);
+    }
+
+    #[test]
+    fn test_utils_accept_any_sequence() {
+        use crate::algorithms::binary_search::utils::is_sorted;
+        use crate::algorithms::linear_search::utils::{count_occurrences, find_max};
+
+        let vec = vec![4, 1, 4, 2];
+        let deque: VecDeque<i32> = vec.iter().copied().collect();
+
+        assert_eq!(count_occurrences(&vec, &4), 2);
+        assert_eq!(find_max(&deque), Some((0, &4)));
+        assert!(!is_sorted(&vec[..]));
+        assert!(is_sorted(&[1, 2, 2, 3][..]));
+    }
+}